@@ -0,0 +1,469 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+#![deny(missing_docs)]
+
+
+//! # nvml-derive
+//!
+//! This crate provides custom derives for the `nvml` crate.
+//!
+//! Use it as:-
+//!
+//! ```ignore
+//! extern crate nvml;
+//! #[macro_use] extern crate nvml_derive;
+//!
+//! use ::nvml::cto_pool::CtoSafe;
+//!
+//! #[derive(CtoSafe)]
+//! struct MyRoot
+//! {
+//! 	counter: u64,
+//! 	names: ::nvml::cto_pool::collections::CtoVec<u8>,
+//! }
+//!
+//! #[derive(Persistable)]
+//! #[persistable(arguments = "u32")]
+//! #[repr(C)]
+//! struct MyListEntry
+//! {
+//! 	#[persistable(list_entry)] list_entry: ::nvml::object_pool::PersistentCircularDoublyLinkedListEntry<MyListEntry>,
+//! 	#[persistable(zero)] lock: ::nvml_sys::PMEMmutex,
+//! 	#[persistable(initialize = "*arguments")] data: u32,
+//! }
+//! ```
+//!
+
+
+extern crate proc_macro;
+#[macro_use] extern crate quote;
+#[macro_use] extern crate syn;
+
+
+use ::proc_macro::TokenStream;
+use ::quote::Tokens;
+use ::syn::Attribute;
+use ::syn::Data;
+use ::syn::DeriveInput;
+use ::syn::Expr;
+use ::syn::Field;
+use ::syn::Fields;
+use ::syn::GenericParam;
+use ::syn::Ident;
+use ::syn::Index;
+use ::syn::Lit;
+use ::syn::Meta;
+use ::syn::NestedMeta;
+use ::syn::Type;
+
+
+/// Derives `::nvml::cto_pool::CtoSafe` for a struct or enum.
+///
+/// `cto_pool_opened()` is called on every field (for an enum, on every field of the current variant), so every field must itself be `CtoSafe`.
+/// Every type parameter is required to be `CtoSafe`.
+/// Unions are not supported, as it is not possible to know which field is in use.
+#[proc_macro_derive(CtoSafe)]
+pub fn derive_cto_safe(input: TokenStream) -> TokenStream
+{
+	let mut derive_input: DeriveInput = ::syn::parse(input).expect("#[derive(CtoSafe)] could not parse its input");
+	
+	for generic_parameter in derive_input.generics.params.iter_mut()
+	{
+		if let GenericParam::Type(ref mut type_parameter) = *generic_parameter
+		{
+			type_parameter.bounds.push(parse_quote!(::nvml::cto_pool::CtoSafe));
+		}
+	}
+	
+	let name = &derive_input.ident;
+	
+	let body = match derive_input.data
+	{
+		Data::Struct(ref data_struct) => cto_pool_opened_for_struct_fields(&data_struct.fields),
+		
+		Data::Enum(ref data_enum) =>
+		{
+			let match_arms = data_enum.variants.iter().map(|variant|
+			{
+				let variant_name = &variant.ident;
+				let (pattern, calls) = cto_pool_opened_for_variant_fields(&variant.fields);
+				quote!
+				{
+					#name::#variant_name #pattern => { #calls }
+				}
+			});
+			
+			quote!
+			{
+				match *self
+				{
+					#(#match_arms)*
+				}
+			}
+		}
+		
+		Data::Union(_) => panic!("#[derive(CtoSafe)] does not support unions"),
+	};
+	
+	let (impl_generics, type_generics, where_clause) = derive_input.generics.split_for_impl();
+	
+	let implementation = quote!
+	{
+		impl #impl_generics ::nvml::cto_pool::CtoSafe for #name #type_generics #where_clause
+		{
+			#[allow(unused_variables)]
+			#[inline(always)]
+			fn cto_pool_opened(&mut self, cto_pool_arc: &::nvml::cto_pool::CtoPoolArc)
+			{
+				#body
+			}
+		}
+	};
+	
+	implementation.into()
+}
+
+#[inline(always)]
+fn cto_pool_opened_for_struct_fields(fields: &Fields) -> Tokens
+{
+	match *fields
+	{
+		Fields::Named(ref fields_named) =>
+		{
+			let field_names = fields_named.named.iter().map(|field| field.ident.as_ref().unwrap());
+			quote!
+			{
+				#(self.#field_names.cto_pool_opened(cto_pool_arc);)*
+			}
+		}
+		
+		Fields::Unnamed(ref fields_unnamed) =>
+		{
+			let field_indices = (0 .. fields_unnamed.unnamed.len()).map(Index::from);
+			quote!
+			{
+				#(self.#field_indices.cto_pool_opened(cto_pool_arc);)*
+			}
+		}
+		
+		Fields::Unit => Tokens::new(),
+	}
+}
+
+// Returns a pattern binding every field of the variant by mutable reference, and the calls to make on those bindings.
+#[inline(always)]
+fn cto_pool_opened_for_variant_fields(fields: &Fields) -> (Tokens, Tokens)
+{
+	match *fields
+	{
+		Fields::Named(ref fields_named) =>
+		{
+			let field_names: Vec<&Ident> = fields_named.named.iter().map(|field| field.ident.as_ref().unwrap()).collect();
+			let pattern_field_names = &field_names;
+			let call_field_names = &field_names;
+			(
+				quote!({ #(ref mut #pattern_field_names),* }),
+				quote!(#(#call_field_names.cto_pool_opened(cto_pool_arc);)*),
+			)
+		}
+		
+		Fields::Unnamed(ref fields_unnamed) =>
+		{
+			let bindings: Vec<Ident> = (0 .. fields_unnamed.unnamed.len()).map(|index| Ident::from(format!("field{}", index))).collect();
+			let pattern_bindings = &bindings;
+			let call_bindings = &bindings;
+			(
+				quote!(( #(ref mut #pattern_bindings),* )),
+				quote!(#(#call_bindings.cto_pool_opened(cto_pool_arc);)*),
+			)
+		}
+		
+		Fields::Unit => (Tokens::new(), Tokens::new()),
+	}
+}
+
+/// Derives `::nvml::object_pool::Persistable` for a struct, and, if it has a `#[persistable(list_entry)]` field, `::nvml::object_pool::ListEntryPersistable`.
+///
+/// On the struct:-
+///
+/// * `#[persistable(type_number = 42)]` uses an explicit `TypeNumber`;
+/// * `#[persistable(root)]` uses the root `TypeNumber`, zero;
+/// * otherwise the `TypeNumber` is a 64-bit FNV-1a hash of the struct's name, so it is stable across builds but not across renames;
+/// * a struct with type parameters must use an explicit `TypeNumber`, as a hash of its name would be shared by every instantiation.
+/// * `#[persistable(arguments = "(u8, u32)")]` sets `Persistable::Arguments`, which is otherwise `()`.
+///
+/// On each field, `initialize()` writes:-
+///
+/// * `#[persistable(initialize = "expression")]`, the expression, which may use `object_pool` and `arguments`;
+/// * `#[persistable(zero)]`, nothing; instead `Zero::zero()` is called (use for `PMEMmutex`, `PMEMrwlock` and `PMEMcond`);
+/// * `#[persistable(initializable)]`, nothing; instead `Initializable::initialize()` is called (use for list heads);
+/// * `#[persistable(list_entry)]`, as for `initializable`, and this field is then used for `ListEntryPersistable`;
+/// * otherwise, `Default::default()`.
+///
+/// A struct with a `list_entry` field must be `#[repr(C)]` and not `#[repr(packed)]`; the field's offset is then computed at compile time from the sizes and alignments of the preceding fields.
+/// Hashed type numbers can collide, and a derive can not see the module a struct is in, so same-named structs in different modules always collide; give one an explicit `TypeNumber`.
+/// Use `::nvml::object_pool::PersistableRegistry` at startup to detect collisions.
+#[proc_macro_derive(Persistable, attributes(persistable))]
+pub fn derive_persistable(input: TokenStream) -> TokenStream
+{
+	let derive_input: DeriveInput = ::syn::parse(input).expect("#[derive(Persistable)] could not parse its input");
+	
+	let name = &derive_input.ident;
+	
+	let mut type_number = None;
+	let mut arguments: Type = parse_quote!(());
+	for nested_meta in persistable_attribute_arguments(&derive_input.attrs)
+	{
+		match nested_meta
+		{
+			NestedMeta::Meta(Meta::Word(ref word)) if word == "root" => type_number = Some(0),
+			
+			NestedMeta::Meta(Meta::NameValue(ref name_value)) if name_value.ident == "type_number" => match name_value.lit
+			{
+				Lit::Int(ref lit_int) => type_number = Some(lit_int.value()),
+				_ => panic!("#[derive(Persistable)] requires type_number to be an integer"),
+			},
+			
+			NestedMeta::Meta(Meta::NameValue(ref name_value)) if name_value.ident == "arguments" => match name_value.lit
+			{
+				Lit::Str(ref lit_str) => arguments = ::syn::parse_str(&lit_str.value()).expect("#[derive(Persistable)] could not parse arguments as a type"),
+				_ => panic!("#[derive(Persistable)] requires arguments to be a string containing a type"),
+			},
+			
+			_ => panic!("#[derive(Persistable)] does not understand a #[persistable(...)] attribute on the struct"),
+		}
+	}
+	let type_number = type_number.unwrap_or_else(||
+	{
+		if derive_input.generics.params.iter().any(|generic_parameter| if let GenericParam::Type(_) = *generic_parameter { true } else { false })
+		{
+			panic!("#[derive(Persistable)] requires a struct with type parameters to have an explicit #[persistable(type_number = ...)]")
+		}
+		hashed_type_number(name.as_ref())
+	});
+	
+	let fields: Vec<&Field> = match derive_input.data
+	{
+		Data::Struct(ref data_struct) => data_struct.fields.iter().collect(),
+		
+		_ => panic!("#[derive(Persistable)] only supports structs"),
+	};
+	
+	let mut field_initializations = Vec::with_capacity(fields.len());
+	let mut list_entry_field_index = None;
+	for (index, field) in fields.iter().enumerate()
+	{
+		let member = member(field, index);
+		let field_initialization = match persistable_field_initialization(field)
+		{
+			PersistableFieldInitialization::Default => quote!(::std::ptr::write(&mut instance.#member, ::std::default::Default::default());),
+			
+			PersistableFieldInitialization::Expression(expression) => quote!(::std::ptr::write(&mut instance.#member, #expression);),
+			
+			PersistableFieldInitialization::Zero => quote!(::nvml::object_pool::Zero::zero(&mut instance.#member as *mut _, object_pool);),
+			
+			PersistableFieldInitialization::Initializable => quote!(::nvml::object_pool::Initializable::initialize(&mut instance.#member, object_pool);),
+			
+			PersistableFieldInitialization::ListEntry =>
+			{
+				if list_entry_field_index.is_some()
+				{
+					panic!("#[derive(Persistable)] only supports one #[persistable(list_entry)] field");
+				}
+				list_entry_field_index = Some(index);
+				quote!(::nvml::object_pool::Initializable::initialize(&mut instance.#member, object_pool);)
+			}
+		};
+		field_initializations.push(field_initialization);
+	}
+	
+	let (impl_generics, type_generics, where_clause) = derive_input.generics.split_for_impl();
+	
+	let list_entry_persistable_implementation = match list_entry_field_index
+	{
+		None => Tokens::new(),
+		
+		Some(list_entry_field_index) =>
+		{
+			if !has_repr(&derive_input.attrs, "C")
+			{
+				panic!("#[derive(Persistable)] requires a struct with a #[persistable(list_entry)] field to be #[repr(C)]");
+			}
+			
+			if has_repr(&derive_input.attrs, "packed")
+			{
+				panic!("#[derive(Persistable)] requires a struct with a #[persistable(list_entry)] field to not be #[repr(packed)]");
+			}
+			
+			let member = member(fields[list_entry_field_index], list_entry_field_index);
+			let field_offset = repr_c_field_offset(&fields, list_entry_field_index);
+			
+			quote!
+			{
+				impl #impl_generics ::nvml::object_pool::ListEntryPersistable for #name #type_generics #where_clause
+				{
+					const PersistentCircularDoublyLinkedListEntryFieldOffset: usize = #field_offset;
+					
+					#[inline(always)]
+					fn list_entry_field(&self) -> &::nvml::object_pool::PersistentCircularDoublyLinkedListEntry<Self>
+					{
+						&self.#member
+					}
+				}
+			}
+		}
+	};
+	
+	let implementation = quote!
+	{
+		impl #impl_generics ::nvml::object_pool::Persistable for #name #type_generics #where_clause
+		{
+			const TypeNumber: ::nvml::object_pool::TypeNumber = #type_number;
+			
+			type Arguments = #arguments;
+			
+			#[allow(unused_variables)]
+			#[inline(always)]
+			unsafe fn initialize(pointer_to_uninitialized_memory_to_use_for_fields: *mut Self, object_pool: &::nvml::object_pool::ObjectPool, arguments: &mut Self::Arguments)
+			{
+				debug_assert!(!pointer_to_uninitialized_memory_to_use_for_fields.is_null(), "pointer_to_uninitialized_memory_to_use_for_fields is null");
+				
+				let instance = &mut *pointer_to_uninitialized_memory_to_use_for_fields;
+				#(#field_initializations)*
+			}
+		}
+		
+		#list_entry_persistable_implementation
+	};
+	
+	implementation.into()
+}
+
+enum PersistableFieldInitialization
+{
+	Default,
+	Expression(Expr),
+	Zero,
+	Initializable,
+	ListEntry,
+}
+
+#[inline(always)]
+fn persistable_field_initialization(field: &Field) -> PersistableFieldInitialization
+{
+	use self::PersistableFieldInitialization::*;
+	
+	let mut nested_metas = persistable_attribute_arguments(&field.attrs);
+	if nested_metas.len() > 1
+	{
+		panic!("#[derive(Persistable)] only supports one #[persistable(...)] argument per field");
+	}
+	
+	match nested_metas.pop()
+	{
+		None => Default,
+		
+		Some(NestedMeta::Meta(Meta::Word(ref word))) if word == "zero" => Zero,
+		
+		Some(NestedMeta::Meta(Meta::Word(ref word))) if word == "initializable" => Initializable,
+		
+		Some(NestedMeta::Meta(Meta::Word(ref word))) if word == "list_entry" => ListEntry,
+		
+		Some(NestedMeta::Meta(Meta::NameValue(ref name_value))) if name_value.ident == "initialize" => match name_value.lit
+		{
+			Lit::Str(ref lit_str) => Expression(::syn::parse_str(&lit_str.value()).expect("#[derive(Persistable)] could not parse initialize as an expression")),
+			_ => panic!("#[derive(Persistable)] requires initialize to be a string containing an expression"),
+		},
+		
+		_ => panic!("#[derive(Persistable)] does not understand a #[persistable(...)] attribute on a field"),
+	}
+}
+
+// All the arguments of all `#[persistable(...)]` attributes.
+#[inline(always)]
+fn persistable_attribute_arguments(attributes: &[Attribute]) -> Vec<NestedMeta>
+{
+	let mut nested_metas = Vec::new();
+	for attribute in attributes.iter()
+	{
+		if let Some(Meta::List(meta_list)) = attribute.interpret_meta()
+		{
+			if meta_list.ident == "persistable"
+			{
+				nested_metas.extend(meta_list.nested.into_iter());
+			}
+		}
+	}
+	nested_metas
+}
+
+#[inline(always)]
+fn has_repr(attributes: &[Attribute], hint: &str) -> bool
+{
+	attributes.iter().any(|attribute|
+	{
+		match attribute.interpret_meta()
+		{
+			Some(Meta::List(meta_list)) => meta_list.ident == "repr" && meta_list.nested.iter().any(|nested_meta|
+			{
+				match *nested_meta
+				{
+					NestedMeta::Meta(Meta::Word(ref word)) => word == hint,
+					NestedMeta::Meta(Meta::List(ref meta_list)) => meta_list.ident == hint,
+					_ => false,
+				}
+			}),
+			
+			_ => false,
+		}
+	})
+}
+
+#[inline(always)]
+fn member(field: &Field, index: usize) -> Tokens
+{
+	match field.ident
+	{
+		Some(ref ident) => quote!(#ident),
+		
+		None =>
+		{
+			let index = Index::from(index);
+			quote!(#index)
+		}
+	}
+}
+
+// A constant expression for the offset of the field at `field_index` in a `#[repr(C)]` struct: each field starts at the end of the previous field, rounded up to its own alignment.
+#[inline(always)]
+fn repr_c_field_offset(fields: &[&Field], field_index: usize) -> Tokens
+{
+	let mut field_offset = quote!(0);
+	for index in 0 .. field_index
+	{
+		let previous_type = &fields[index].ty;
+		let next_type = &fields[index + 1].ty;
+		field_offset = quote!(((#field_offset + ::std::mem::size_of::<#previous_type>() + ::std::mem::align_of::<#next_type>() - 1) & !(::std::mem::align_of::<#next_type>() - 1)));
+	}
+	field_offset
+}
+
+// 64-bit FNV-1a; zero is avoided as it is the root object's type number.
+#[inline(always)]
+fn hashed_type_number(type_name: &str) -> u64
+{
+	let offset_basis = 0xcbf2_9ce4_8422_2325u64;
+	let prime = 0x0000_0100_0000_01B3u64;
+	
+	let hash = type_name.bytes().fold(offset_basis, |hash, byte| (hash ^ (byte as u64)).wrapping_mul(prime));
+	if hash == 0
+	{
+		1
+	}
+	else
+	{
+		hash
+	}
+}
@@ -15,6 +15,9 @@ pub struct Configuration
 	/// Configuration for block pools.
 	pub block_pools_configuration: BlockPoolsConfiguration,
 	
+	/// Configuration for CTO pools.
+	pub cto_pools_configuration: CtoPoolsConfiguration,
+	
 	/// Configuration for log pools.
 	pub log_pools_configuration: LogPoolsConfiguration,
 	
@@ -31,6 +34,7 @@ impl Default for Configuration
 		{
 			pools_folder_name: "pools".to_string(),
 			block_pools_configuration: Default::default(),
+			cto_pools_configuration: Default::default(),
 			log_pools_configuration: Default::default(),
 			object_pools_configuration: Default::default(),
 		}
@@ -43,20 +47,25 @@ impl Configuration
 	pub const DefaultPermissionsForPoolSets: mode_t = 0o600;
 	
 	/// Opens a configuration path. If the configuration's pools folder name does exist, returns a default for Pools (which contains no pools at all).
-	pub fn open(&self, configuration_folder_path: &Path) -> Pools
+	/// Fails on the first pool that can not be opened or created; any pools already opened are closed.
+	pub fn open(&self, configuration_folder_path: &Path) -> Result<Pools, PoolsOpenError>
 	{
 		let pools_folder_path = configuration_folder_path.join(&self.pools_folder_name);
 		
 		if unlikely(!pools_folder_path.exists())
 		{
-			return Default::default()
+			return Ok(Default::default())
 		}
 		
-		Pools
-		{
-			block_pools: self.block_pools_configuration.open(&pools_folder_path),
-			log_pools: self.log_pools_configuration.open(&pools_folder_path),
-			object_pools: self.object_pools_configuration.open(&pools_folder_path),
-		}
+		Ok
+		(
+			Pools
+			{
+				block_pools: self.block_pools_configuration.open(&pools_folder_path)?,
+				cto_pools: self.cto_pools_configuration.open(&pools_folder_path)?,
+				log_pools: self.log_pools_configuration.open(&pools_folder_path)?,
+				object_pools: self.object_pools_configuration.open(&pools_folder_path)?,
+			}
+		)
 	}
 }
@@ -0,0 +1,38 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// The kind of a persistent memory pool.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PoolKind
+{
+	/// A block pool.
+	Block,
+	
+	/// A CTO pool.
+	Cto,
+	
+	/// A log pool.
+	Log,
+	
+	/// An object pool.
+	Object,
+}
+
+impl Display for PoolKind
+{
+	#[inline(always)]
+	fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error>
+	{
+		use self::PoolKind::*;
+		
+		let name = match *self
+		{
+			Block => "block",
+			Cto => "cto",
+			Log => "log",
+			Object => "object",
+		};
+		formatter.write_str(name)
+	}
+}
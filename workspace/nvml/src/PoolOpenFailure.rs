@@ -0,0 +1,93 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// The step at which opening or creating a persistent memory pool failed.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PoolOpenFailure
+{
+	/// The pool sets folder exists but is not a folder.
+	PoolSetsFolderIsNotAFolder,
+	
+	/// The pool set file exists but is not a file.
+	PoolSetFileIsNotAFile,
+	
+	/// An existing pool failed validation with an error.
+	ValidationFailed(PmdkError),
+	
+	/// An existing pool is invalid or inconsistent.
+	Invalid,
+	
+	/// An existing pool could not be opened.
+	OpenFailed(PmdkError),
+	
+	/// A new pool could not be created.
+	CreateFailed(PmdkError),
+	
+	/// The configured pool size is smaller than the minimum for this kind of pool (eg `nvml_sys::PMEMBLK_MIN_POOL`).
+	PoolSizeTooSmall
+	{
+		/// Configured pool size.
+		pool_size: usize,
+		
+		/// Minimum pool size.
+		minimum_pool_size: usize,
+	},
+	
+	/// The layout name is too long or contains an embedded ASCII NUL.
+	InvalidLayoutName,
+	
+	/// No pools are configured but at least one is needed (eg for a `RotatingLog`).
+	NoPoolsConfigured,
+}
+
+impl Display for PoolOpenFailure
+{
+	#[inline(always)]
+	fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error>
+	{
+		use self::PoolOpenFailure::*;
+		
+		match *self
+		{
+			PoolSetsFolderIsNotAFolder => write!(formatter, "pool sets folder is not a folder"),
+			
+			PoolSetFileIsNotAFile => write!(formatter, "pool set file is not a file"),
+			
+			ValidationFailed(ref pmdk_error) => write!(formatter, "validation failed; {}", pmdk_error),
+			
+			Invalid => write!(formatter, "invalid or inconsistent"),
+			
+			OpenFailed(ref pmdk_error) => write!(formatter, "open failed; {}", pmdk_error),
+			
+			CreateFailed(ref pmdk_error) => write!(formatter, "create failed; {}", pmdk_error),
+			
+			PoolSizeTooSmall { pool_size, minimum_pool_size } => write!(formatter, "pool_size '{}' is smaller than minimum '{}'", pool_size, minimum_pool_size),
+			
+			InvalidLayoutName => write!(formatter, "layout name is too long or contains an embedded ASCII NUL"),
+			
+			NoPoolsConfigured => write!(formatter, "no pools are configured"),
+		}
+	}
+}
+
+impl PoolOpenFailure
+{
+	/// The underlying PMDK error, if any.
+	#[inline(always)]
+	pub fn pmdk_error(&self) -> Option<&PmdkError>
+	{
+		use self::PoolOpenFailure::*;
+		
+		match *self
+		{
+			ValidationFailed(ref pmdk_error) => Some(pmdk_error),
+			
+			OpenFailed(ref pmdk_error) => Some(pmdk_error),
+			
+			CreateFailed(ref pmdk_error) => Some(pmdk_error),
+			
+			_ => None,
+		}
+	}
+}
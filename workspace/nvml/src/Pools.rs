@@ -7,6 +7,7 @@
 pub struct Pools
 {
 	block_pools: HashMap<String, BlockPool>,
+	cto_pools: HashMap<String, CtoPoolArc>,
 	log_pools: HashMap<String, LogPool>,
 	object_pools: HashMap<String, ObjectPool>,
 }
@@ -20,6 +21,16 @@ impl Pools
 		self.block_pools.get(pool_name).map(|pool| pool.clone())
 	}
 	
+	/// Get a CTO pool.
+	/// The first time a particular CTO pool is got, its root is created using `root_value_initializer` if missing, or any volatile memory it contains is re-initialized.
+	/// Only one `CtoPool` for a particular `pool_name` may exist at a time; until it is dropped, further calls fail with `CtoPoolOpenError::RootAlreadyOwned`.
+	/// All calls for the same `pool_name` must use the same `RootValue`; otherwise they fail with `CtoPoolOpenError::RootTypeMismatch`.
+	#[inline(always)]
+	pub fn get_cto_pool<RootValue: CtoSafe + 'static, InitializationError: error::Error, RootValueInitializer: FnOnce(&mut RootValue, &CtoPoolArc) -> Result<(), InitializationError>>(&self, pool_name: &str, root_value_initializer: RootValueInitializer) -> Option<Result<CtoPool<RootValue>, CtoPoolOpenError<InitializationError>>>
+	{
+		self.cto_pools.get(pool_name).map(|cto_pool_arc| CtoPool::open_from_allocator(cto_pool_arc.clone(), root_value_initializer))
+	}
+	
 	/// Get a log pool.
 	#[inline(always)]
 	pub fn get_log_pool(&self, pool_name: &str) -> Option<LogPool>
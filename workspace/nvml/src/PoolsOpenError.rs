@@ -0,0 +1,88 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// Represents a failure to open (or create) one of the pools in a `Configuration`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PoolsOpenError
+{
+	/// Kind of pool.
+	pub pool_kind: PoolKind,
+	
+	/// Name of pool (its file name).
+	/// `None` if the failure relates to the pool sets folder rather than to a particular pool.
+	pub pool_name: Option<String>,
+	
+	/// Path to the pool set file (or to the pool sets folder, or pools folder, if `pool_name` is `None`).
+	pub path: PathBuf,
+	
+	/// Step that failed.
+	pub failure: PoolOpenFailure,
+}
+
+impl Display for PoolsOpenError
+{
+	#[inline(always)]
+	fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error>
+	{
+		match self.pool_name
+		{
+			None => write!(formatter, "Could not open {} pool sets folder '{:?}': {}", self.pool_kind, self.path, self.failure),
+			Some(ref pool_name) => write!(formatter, "Could not open {} pool '{}' at '{:?}': {}", self.pool_kind, pool_name, self.path, self.failure),
+		}
+	}
+}
+
+impl error::Error for PoolsOpenError
+{
+	#[inline(always)]
+	fn description(&self) -> &str
+	{
+		"Pools Open Error"
+	}
+	
+	#[inline(always)]
+	fn cause(&self) -> Option<&error::Error>
+	{
+		self.failure.pmdk_error().map(|pmdk_error| pmdk_error as &error::Error)
+	}
+}
+
+impl PoolsOpenError
+{
+	#[inline(always)]
+	pub(crate) fn pool(pool_kind: PoolKind, file_name: &str, pool_set_file_path: &Path, failure: PoolOpenFailure) -> Self
+	{
+		Self
+		{
+			pool_kind,
+			pool_name: Some(file_name.to_string()),
+			path: pool_set_file_path.to_path_buf(),
+			failure,
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn pool_sets_folder(pool_kind: PoolKind, pool_sets_folder_path: &Path) -> Self
+	{
+		Self
+		{
+			pool_kind,
+			pool_name: None,
+			path: pool_sets_folder_path.to_path_buf(),
+			failure: PoolOpenFailure::PoolSetsFolderIsNotAFolder,
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn no_pools_configured(pool_kind: PoolKind, pools_folder_path: &Path) -> Self
+	{
+		Self
+		{
+			pool_kind,
+			pool_name: None,
+			path: pools_folder_path.to_path_buf(),
+			failure: PoolOpenFailure::NoPoolsConfigured,
+		}
+	}
+}
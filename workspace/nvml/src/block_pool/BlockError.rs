@@ -0,0 +1,32 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+quick_error!
+{
+	/// Reason for failing to read from or write to a block.
+	#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+	pub enum BlockError
+	{
+		/// The zero-based block index is not less than the number of blocks in the block pool.
+		IndexOutOfRange(zero_based_block_index: usize, number_of_blocks: usize)
+		{
+			description("Block index is out of range")
+			display("Block index '{}' is not less than number of blocks '{}'", zero_based_block_index, number_of_blocks)
+		}
+		
+		/// The length of a buffer is not the block size (or, for bulk I/O, the block size multiplied by the number of blocks).
+		BufferLengthMismatch(buffer_length: usize, expected_buffer_length: usize)
+		{
+			description("Buffer length does not match block size")
+			display("Buffer length '{}' is not the expected length '{}'", buffer_length, expected_buffer_length)
+		}
+		
+		/// The block has previously had its error condition set (see `BlockPool.set_error()`).
+		BlockInErrorState(zero_based_block_index: usize)
+		{
+			description("Block is in an error state")
+			display("Block '{}' is in an error state", zero_based_block_index)
+		}
+	}
+}
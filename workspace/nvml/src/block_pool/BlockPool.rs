@@ -64,6 +64,7 @@ impl BlockPool
 	}
 	
 	/// Read from a block.
+	/// Prefer the use of `read_block()`, which checks the index and buffer length.
 	/// Returns false if the block has previously had its error condition set (see `set_error()`).
 	#[inline(always)]
 	pub fn read(self, to: *mut c_void, zero_based_block_index: usize) -> bool
@@ -72,6 +73,7 @@ impl BlockPool
 	}
 	
 	/// Write to a block.
+	/// Prefer the use of `write_block()`, which checks the index and buffer length.
 	#[inline(always)]
 	pub fn write(self, from: *const c_void, zero_based_block_index: usize)
 	{
@@ -92,6 +94,143 @@ impl BlockPool
 		self.0.set_error(zero_based_block_index)
 	}
 	
+	/// Read from a block into a buffer which must be exactly `block_size()` bytes long.
+	/// The block index is checked against the number of blocks in the block pool.
+	/// Returns `Err(BlockError::BlockInErrorState)` if the block has previously had its error condition set (see `set_error()`).
+	#[inline(always)]
+	pub fn read_block(&self, zero_based_block_index: usize, to: &mut [u8]) -> Result<(), BlockError>
+	{
+		self.check_index(zero_based_block_index)?;
+		self.check_buffer_length(to.len(), 1)?;
+		self.read_unchecked(to, zero_based_block_index)
+	}
+	
+	/// Write to a block from a buffer which must be exactly `block_size()` bytes long.
+	/// The block index is checked against the number of blocks in the block pool.
+	#[inline(always)]
+	pub fn write_block(&self, zero_based_block_index: usize, from: &[u8]) -> Result<(), BlockError>
+	{
+		self.check_index(zero_based_block_index)?;
+		self.check_buffer_length(from.len(), 1)?;
+		self.write_unchecked(from, zero_based_block_index);
+		Ok(())
+	}
+	
+	/// Read a contiguous range of blocks into a buffer which must be exactly `block_size()` multiplied by the number of blocks in `zero_based_block_indices` bytes long.
+	/// Stops at the first block which has previously had its error condition set, returning `Err(BlockError::BlockInErrorState)`; preceding blocks will have been read.
+	#[inline(always)]
+	pub fn read_blocks(&self, zero_based_block_indices: Range<usize>, to: &mut [u8]) -> Result<(), BlockError>
+	{
+		let block_size = self.check_range_and_buffer_length(&zero_based_block_indices, to.len())?;
+		
+		for (zero_based_block_index, to) in zero_based_block_indices.zip(to.chunks_mut(block_size))
+		{
+			self.read_unchecked(to, zero_based_block_index)?;
+		}
+		Ok(())
+	}
+	
+	/// Write a contiguous range of blocks from a buffer which must be exactly `block_size()` multiplied by the number of blocks in `zero_based_block_indices` bytes long.
+	/// Each block is written atomically, but the range as a whole is not.
+	#[inline(always)]
+	pub fn write_blocks(&self, zero_based_block_indices: Range<usize>, from: &[u8]) -> Result<(), BlockError>
+	{
+		let block_size = self.check_range_and_buffer_length(&zero_based_block_indices, from.len())?;
+		
+		for (zero_based_block_index, from) in zero_based_block_indices.zip(from.chunks(block_size))
+		{
+			self.write_unchecked(from, zero_based_block_index);
+		}
+		Ok(())
+	}
+	
+	/// Obtain a handle to a block.
+	/// The block index is checked against the number of blocks in the block pool.
+	#[inline(always)]
+	pub fn block(&self, zero_based_block_index: usize) -> Result<BlockPoolBlock, BlockError>
+	{
+		self.check_index(zero_based_block_index)?;
+		
+		Ok
+		(
+			BlockPoolBlock
+			{
+				block_pool: self,
+				zero_based_block_index,
+			}
+		)
+	}
+	
+	/// Iterate over all blocks in the block pool.
+	#[inline(always)]
+	pub fn blocks(&self) -> BlockPoolBlocks
+	{
+		BlockPoolBlocks
+		{
+			block_pool: self,
+			zero_based_block_indices: 0 .. self.0.number_of_blocks_available_in_block_pool(),
+		}
+	}
+	
+	#[inline(always)]
+	fn check_index(&self, zero_based_block_index: usize) -> Result<(), BlockError>
+	{
+		let number_of_blocks = self.0.number_of_blocks_available_in_block_pool();
+		if unlikely(zero_based_block_index >= number_of_blocks)
+		{
+			Err(BlockError::IndexOutOfRange(zero_based_block_index, number_of_blocks))
+		}
+		else
+		{
+			Ok(())
+		}
+	}
+	
+	#[inline(always)]
+	fn check_buffer_length(&self, buffer_length: usize, number_of_blocks: usize) -> Result<usize, BlockError>
+	{
+		let block_size = self.0.block_size();
+		let expected_buffer_length = block_size * number_of_blocks;
+		if unlikely(buffer_length != expected_buffer_length)
+		{
+			Err(BlockError::BufferLengthMismatch(buffer_length, expected_buffer_length))
+		}
+		else
+		{
+			Ok(block_size)
+		}
+	}
+	
+	#[inline(always)]
+	fn check_range_and_buffer_length(&self, zero_based_block_indices: &Range<usize>, buffer_length: usize) -> Result<usize, BlockError>
+	{
+		let number_of_blocks = zero_based_block_indices.end.saturating_sub(zero_based_block_indices.start);
+		if likely(number_of_blocks != 0)
+		{
+			self.check_index(zero_based_block_indices.end - 1)?;
+		}
+		self.check_buffer_length(buffer_length, number_of_blocks)
+	}
+	
+	#[inline(always)]
+	fn read_unchecked(&self, to: &mut [u8], zero_based_block_index: usize) -> Result<(), BlockError>
+	{
+		if likely(self.0.read_from(to.as_mut_ptr() as *mut c_void, zero_based_block_index))
+		{
+			Ok(())
+		}
+		else
+		{
+			Err(BlockError::BlockInErrorState(zero_based_block_index))
+		}
+	}
+	
+	#[inline(always)]
+	fn write_unchecked(&self, from: &[u8], zero_based_block_index: usize)
+	{
+		self.0.write_to(from.as_ptr() as *const c_void, zero_based_block_index)
+	}
+	
 	#[inline(always)]
 	fn from_handle(handle: *mut PMEMblkpool) -> Self
 	{
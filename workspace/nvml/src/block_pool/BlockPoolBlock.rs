@@ -0,0 +1,55 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// A handle to a block in a block pool whose index is known to be in range.
+/// Obtain using `BlockPool.block()` or by iterating using `BlockPool.blocks()`.
+#[derive(Debug, Copy, Clone)]
+pub struct BlockPoolBlock<'a>
+{
+	block_pool: &'a BlockPool,
+	zero_based_block_index: usize,
+}
+
+impl<'a> BlockPoolBlock<'a>
+{
+	/// Zero-based index of this block.
+	#[inline(always)]
+	pub fn index(&self) -> usize
+	{
+		self.zero_based_block_index
+	}
+	
+	/// Read from this block.
+	/// `to` must be exactly `block_size()` bytes long.
+	#[inline(always)]
+	pub fn read(&self, to: &mut [u8]) -> Result<(), BlockError>
+	{
+		self.block_pool.check_buffer_length(to.len(), 1)?;
+		self.block_pool.read_unchecked(to, self.zero_based_block_index)
+	}
+	
+	/// Write to this block.
+	/// `from` must be exactly `block_size()` bytes long.
+	#[inline(always)]
+	pub fn write(&self, from: &[u8]) -> Result<(), BlockError>
+	{
+		self.block_pool.check_buffer_length(from.len(), 1)?;
+		self.block_pool.write_unchecked(from, self.zero_based_block_index);
+		Ok(())
+	}
+	
+	/// Set this block to all zeros.
+	#[inline(always)]
+	pub fn set_zero(&self)
+	{
+		self.block_pool.0.set_zero(self.zero_based_block_index)
+	}
+	
+	/// Set this block to being in an error state (ie set its error condition).
+	#[inline(always)]
+	pub fn set_error(&self)
+	{
+		self.block_pool.0.set_error(self.zero_based_block_index)
+	}
+}
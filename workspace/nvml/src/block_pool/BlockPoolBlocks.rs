@@ -0,0 +1,48 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// An iterator over all the blocks in a block pool.
+/// Obtain using `BlockPool.blocks()`.
+#[derive(Debug, Clone)]
+pub struct BlockPoolBlocks<'a>
+{
+	block_pool: &'a BlockPool,
+	zero_based_block_indices: Range<usize>,
+}
+
+impl<'a> Iterator for BlockPoolBlocks<'a>
+{
+	type Item = BlockPoolBlock<'a>;
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let block_pool = self.block_pool;
+		self.zero_based_block_indices.next().map(|zero_based_block_index| BlockPoolBlock { block_pool, zero_based_block_index })
+	}
+	
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.zero_based_block_indices.size_hint()
+	}
+}
+
+impl<'a> DoubleEndedIterator for BlockPoolBlocks<'a>
+{
+	#[inline(always)]
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		let block_pool = self.block_pool;
+		self.zero_based_block_indices.next_back().map(|zero_based_block_index| BlockPoolBlock { block_pool, zero_based_block_index })
+	}
+}
+
+impl<'a> ExactSizeIterator for BlockPoolBlocks<'a>
+{
+}
+
+impl<'a> FusedIterator for BlockPoolBlocks<'a>
+{
+}
@@ -39,18 +39,29 @@ impl BlockPoolConfiguration
 {
 	/// Open or create (if necessary) a block pool.
 	/// Do not use this method directly unless only using one block pool.
-	pub fn open_or_create(&self, block_pool_sets_folder_path: &Path, file_name: &str) -> BlockPool
+	pub fn open_or_create(&self, block_pool_sets_folder_path: &Path, file_name: &str) -> Result<BlockPool, PoolsOpenError>
 	{
 		let pool_set_file_path = block_pool_sets_folder_path.join(file_name);
+		let error = |failure| PoolsOpenError::pool(PoolKind::Block, file_name, &pool_set_file_path, failure);
 		
 		if likely(pool_set_file_path.exists())
 		{
-			assert!(pool_set_file_path.is_file(), "pool_set_file_path '{:?}' is not a file", pool_set_file_path);
-			BlockPool::open(&pool_set_file_path, self.block_size).expect("Could not open BlockPool")
+			if unlikely(!pool_set_file_path.is_file())
+			{
+				return Err(error(PoolSetFileIsNotAFile))
+			}
+			
+			match BlockPool::validate(&pool_set_file_path, self.block_size.unwrap_or(0))
+			{
+				Err(pmdk_error) => return Err(error(ValidationFailed(pmdk_error))),
+				Ok(false) => return Err(error(Invalid)),
+				Ok(true) => (),
+			}
+			
+			BlockPool::open(&pool_set_file_path, self.block_size).map_err(|pmdk_error| error(OpenFailed(pmdk_error)))
 		}
 		else
 		{
-			
 			let block_size = match self.block_size
 			{
 				None => PMEMBLK_MIN_BLK,
@@ -62,11 +73,14 @@ impl BlockPoolConfiguration
 				None => 0,
 				Some(pool_size) =>
 				{
-					assert!(pool_size >= PMEMBLK_MIN_POOL, "pool_size '{}' is smaller than PMEMBLK_MIN_POOL '{}'", pool_size, PMEMBLK_MIN_POOL);
+					if unlikely(pool_size < PMEMBLK_MIN_POOL)
+					{
+						return Err(error(PoolSizeTooSmall { pool_size, minimum_pool_size: PMEMBLK_MIN_POOL }))
+					}
 					pool_size
 				},
 			};
-			BlockPool::create(&pool_set_file_path, block_size, pool_size, self.permissions).expect("Could not create BlockPool")
+			BlockPool::create(&pool_set_file_path, block_size, pool_size, self.permissions).map_err(|pmdk_error| error(CreateFailed(pmdk_error)))
 		}
 	}
 }
@@ -33,17 +33,20 @@ impl BlockPoolsConfiguration
 {
 	/// Opens a set of block pools.
 	/// Do not use this method directly unless only using block pools.
-	pub fn open(&self, pools_folder_path: &Path) -> HashMap<String, BlockPool>
+	pub fn open(&self, pools_folder_path: &Path) -> Result<HashMap<String, BlockPool>, PoolsOpenError>
 	{
 		let block_pool_sets_folder_path = pools_folder_path.join(&self.pool_sets_folder_name);
 		
 		if unlikely(!block_pool_sets_folder_path.exists())
 		{
-			return HashMap::new()
+			return Ok(HashMap::new())
 		}
 		
-		assert!(block_pool_sets_folder_path.is_dir(), "block_pool_sets_folder_path '{:?}' is not a folder", block_pool_sets_folder_path);
+		if unlikely(!block_pool_sets_folder_path.is_dir())
+		{
+			return Err(PoolsOpenError::pool_sets_folder(PoolKind::Block, &block_pool_sets_folder_path))
+		}
 		
-		self.block_pool_configurations.iter().map(|(file_name, block_pool_configuration)| (file_name.to_string(), block_pool_configuration.open_or_create(&block_pool_sets_folder_path, file_name)) ).collect()
+		self.block_pool_configurations.iter().map(|(file_name, block_pool_configuration)| block_pool_configuration.open_or_create(&block_pool_sets_folder_path, file_name).map(|block_pool| (file_name.to_string(), block_pool))).collect()
 	}
 }
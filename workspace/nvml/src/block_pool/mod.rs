@@ -11,18 +11,26 @@ use ::libc::c_void;
 use ::libc::mode_t;
 use ::libc::size_t;
 use ::Configuration;
+use ::PoolKind;
+use ::PoolOpenFailure::*;
+use ::PoolsOpenError;
 use ::nvml_sys::*;
 use ::rust_extra::likely;
 use ::rust_extra::unlikely;
 use ::std::collections::HashMap;
 use ::std::cmp::min;
+use ::std::iter::FusedIterator;
+use ::std::ops::Range;
 #[cfg(unix)] use ::std::os::unix::ffi::OsStrExt;
 use ::std::path::Path;
 use ::std::sync::Arc;
 use ::syscall_alt::constants::E;
 
 
+include!("BlockError.rs");
 include!("BlockPool.rs");
+include!("BlockPoolBlock.rs");
+include!("BlockPoolBlocks.rs");
 include!("BlockPoolConfiguration.rs");
 include!("BlockPoolDropWrapper.rs");
 include!("BlockPoolPathExt.rs");
@@ -10,6 +10,11 @@ trait Allocator
 	#[inline(always)]
 	fn aligned_allocate<T>(self) -> Result<*mut T, PmdkError>;
 	
+	// Allocates memory for a `Header` followed by a slice of all the `elements`, and moves the elements into it; the `Header` is left uninitialized.
+	// Returns a pointer to the start of the allocation (ie to the `Header`), not to the first element.
+	#[inline(always)]
+	fn aligned_allocate_with_trailing_slice<Header, Element, Elements: ExactSizeIterator<Item=Element>>(self, elements: Elements) -> Result<*mut Element, PmdkError>;
+	
 	#[inline(always)]
 	fn alloc_trait_allocate(self, layout: &Layout) -> Result<*mut u8, AllocErr>;
 	
@@ -76,6 +81,58 @@ impl Allocator for *mut PMEMctopool
 		self.aligned_alloc(alignment, size).map(|pointer| pointer as *mut T)
 	}
 	
+	#[inline(always)]
+	fn aligned_allocate_with_trailing_slice<Header, Element, Elements: ExactSizeIterator<Item=Element>>(self, elements: Elements) -> Result<*mut Element, PmdkError>
+	{
+		debug_assert!(self.is_not_null(), "self is null");
+		
+		// Drops the elements written so far and frees the allocation if producing an element panics.
+		struct PartiallyInitializedSlice<Element>
+		{
+			pool_pointer: *mut PMEMctopool,
+			allocation_pointer: *mut u8,
+			first_element_pointer: *mut Element,
+			initialized: usize,
+		}
+		
+		impl<Element> Drop for PartiallyInitializedSlice<Element>
+		{
+			#[inline(always)]
+			fn drop(&mut self)
+			{
+				unsafe { drop_in_place(from_raw_parts_mut(self.first_element_pointer, self.initialized)) };
+				
+				self.pool_pointer.free(self.allocation_pointer)
+			}
+		}
+		
+		let length = elements.len();
+		let slice_layout = Layout::array::<Element>(length).expect("slice is too large to allocate");
+		let (layout, slice_offset) = Layout::new::<Header>().extend(slice_layout).expect("slice is too large to allocate");
+		
+		let allocation_pointer = self.aligned_alloc(layout.align(), layout.size())? as *mut u8;
+		
+		let mut partially_initialized_slice = PartiallyInitializedSlice
+		{
+			pool_pointer: self,
+			allocation_pointer,
+			first_element_pointer: unsafe { allocation_pointer.offset(slice_offset as isize) } as *mut Element,
+			initialized: 0,
+		};
+		
+		for element in elements.take(length)
+		{
+			unsafe { write(partially_initialized_slice.first_element_pointer.offset(partially_initialized_slice.initialized as isize), element) };
+			partially_initialized_slice.initialized += 1;
+		}
+		
+		assert_eq!(partially_initialized_slice.initialized, length, "elements produced fewer elements than its length");
+		
+		forget(partially_initialized_slice);
+		
+		Ok(allocation_pointer as *mut Element)
+	}
+	
 	#[inline(always)]
 	fn alloc_trait_allocate(self, layout: &Layout) -> Result<*mut u8, AllocErr>
 	{
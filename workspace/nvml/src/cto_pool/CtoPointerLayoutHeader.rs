@@ -0,0 +1,65 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// Stored beside a root that does not implement `CtoRootVersion`, recording the `CtoPointerLayoutVersion` it was created with.
+/// A root that implements `CtoRootVersion` records this in its `CtoRootFingerprint` instead.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+struct CtoPointerLayoutHeader
+{
+	magic: u64,
+	pointer_layout_version: u64,
+	value_offset: u64,
+}
+
+impl CtoPointerLayoutHeader
+{
+	const Magic: u64 = 0x5452_4F4F_5254_4C50;
+	
+	/// A root created before pointer layout versions were recorded has neither this header nor a `CtoRootFingerprint`; it has the first layout.
+	const UnrecordedPointerLayoutVersion: u64 = 1;
+	
+	#[inline(always)]
+	fn pointer_layout_version(&self) -> u64
+	{
+		self.pointer_layout_version
+	}
+	
+	#[inline(always)]
+	fn value_offset(&self) -> usize
+	{
+		self.value_offset as usize
+	}
+	
+	#[inline(always)]
+	fn of(value_offset: usize) -> Self
+	{
+		Self
+		{
+			magic: Self::Magic,
+			pointer_layout_version: CtoPointerLayoutVersion,
+			value_offset: value_offset as u64,
+		}
+	}
+	
+	/// Returns `None` if `root` was not created with a header.
+	#[inline(always)]
+	fn from_root(root: *mut u8, pool_pointer: *mut PMEMctopool) -> Option<Self>
+	{
+		if pool_pointer.usable_size(root as *mut c_void) < size_of::<Self>()
+		{
+			return None
+		}
+		
+		let header = unsafe { read(root as *const Self) };
+		if header.magic == Self::Magic
+		{
+			Some(header)
+		}
+		else
+		{
+			None
+		}
+	}
+}
@@ -0,0 +1,14 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// The version of the layout of the allocations behind `CtoBox`, `CtoRc` and `CtoArc` (and their weak counterparts).
+/// It is recorded beside every root, in its `CtoRootFingerprint` if it implements `CtoRootVersion` and in a smaller header otherwise.
+/// A root recorded with a different version fails to open with `CtoPoolOpenError::PointerLayoutMismatch`; no `CtoRootMigrations` can be used, as a migration could not read the old allocations.
+///
+/// * `1`: the value was the first field of each allocation.
+/// * `2`: the value is the last field of each allocation, so that it can be a slice or `str`.
+///
+/// Version `1` was never recorded, so a root with no record of a version is treated as version `1`.
+/// A pool written with version `1` can not be read with version `2`: copy its contents out (eg by serializing them) using the version of this crate that created it, then copy them into a new pool.
+pub const CtoPointerLayoutVersion: u64 = 2;
@@ -6,10 +6,22 @@
 /// Unlike the system `malloc`, multiple instances of it can be created, one for each bank of Persistent memory.
 /// And, it has a graph 'root'.
 /// To access the 'root' of the graph, use `deref()` or `deref_mut()`.
+/// To replace the 'root' of the graph, use `replace_root()`.
+/// If `RootValue` implements `CtoRootVersion`, the root is stored with a fingerprint which is checked on open; use `open_with_migrations()` to upgrade older roots.
+/// Only one `CtoPool` owns the root of a particular pool at a time, so there is never more than one `&mut RootValue`; dropping it allows the root to be attached again.
 /// Persistence does not happen successfully until this object is closed (dropped).
 /// Dropping only occurs when there are not more instances of `CtoPoolArc`.
 pub struct CtoPool<RootValue: CtoSafe>(CtoPoolAlloc, PhantomData<RootValue>);
 
+impl<RootValue: CtoSafe> Drop for CtoPool<RootValue>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		self.allocator().root_attachment().release()
+	}
+}
+
 impl<RootValue: CtoSafe> PartialEq for CtoPool<RootValue>
 {
 	#[inline(always)]
@@ -54,7 +66,7 @@ impl<RootValue: CtoSafe> Deref for CtoPool<RootValue>
 		}
 		else
 		{
-			unsafe { & * (existing_root as *const RootValue) }
+			unsafe { & * Self::value_pointer(existing_root) }
 		}
 	}
 }
@@ -71,7 +83,7 @@ impl<RootValue: CtoSafe> DerefMut for CtoPool<RootValue>
 		}
 		else
 		{
-			unsafe { &mut * (existing_root as *mut RootValue) }
+			unsafe { &mut * Self::value_pointer(existing_root) }
 		}
 	}
 }
@@ -116,8 +128,19 @@ impl<RootValue: CtoSafe> CtoPool<RootValue>
 {
 	/// Opens a pool, creating it if necessary, and re-initializing any memory that is volatile (eg condition variables, mutex locks, etc).
 	/// If the pool does not contain a root, then it is initialized using `root_value_initializer`.
+	/// If `RootValue` implements `CtoRootVersion` and the existing root has a different fingerprint, fails with `CtoPoolOpenError::SchemaMismatch`.
+	/// If the existing root was created with a different `CtoPointerLayoutVersion`, fails with `CtoPoolOpenError::PointerLayoutMismatch`.
 	#[inline(always)]
 	pub fn open<InitializationError: error::Error, RootValueInitializer: FnOnce(&mut RootValue, &CtoPoolArc) -> Result<(), InitializationError>>(pool_set_file_path: &Path, layout_name: &str, pool_size: usize, mode: mode_t, root_value_initializer: RootValueInitializer) -> Result<Self, CtoPoolOpenError<InitializationError>>
+		where RootValue: 'static
+	{
+		Self::open_with_migrations(pool_set_file_path, layout_name, pool_size, mode, &CtoRootMigrations::new(), root_value_initializer)
+	}
+	
+	/// As for `open()`, but an existing root with a different fingerprint is upgraded using `cto_root_migrations` if possible.
+	#[inline(always)]
+	pub fn open_with_migrations<InitializationError: error::Error, RootValueInitializer: FnOnce(&mut RootValue, &CtoPoolArc) -> Result<(), InitializationError>>(pool_set_file_path: &Path, layout_name: &str, pool_size: usize, mode: mode_t, cto_root_migrations: &CtoRootMigrations<RootValue, InitializationError>, root_value_initializer: RootValueInitializer) -> Result<Self, CtoPoolOpenError<InitializationError>>
+		where RootValue: 'static
 	{
 		let layout_name = CString::new(layout_name).expect("Embedded NULs are not allowed in a layout name");
 		let length = layout_name.as_bytes().len();
@@ -155,25 +178,98 @@ impl<RootValue: CtoSafe> CtoPool<RootValue>
 			},
 		};
 		
-		let cto_pool_arc = CtoPoolArc::new(pool_pointer);
-		
-		let cto_pool_alloc: CtoPool<RootValue> = CtoPool(CtoPoolAlloc(cto_pool_arc), PhantomData);
+		Self::open_from_allocator_with_migrations(CtoPoolArc::new(pool_pointer), cto_root_migrations, root_value_initializer)
+	}
+	
+	/// Attaches the root of an already opened CTO pool, re-initializing any memory that is volatile (eg condition variables, mutex locks, etc).
+	/// If the pool does not contain a root, then it is initialized using `root_value_initializer`.
+	/// Volatile memory is only re-initialized (and the root only created) the first time a root is attached for a particular `cto_pool_arc`; subsequent calls just wrap the existing root.
+	/// Only one `CtoPool` may own the root at a time; fails with `CtoPoolOpenError::RootAlreadyOwned` until the previous one is dropped.
+	/// If `RootValue` implements `CtoRootVersion` and the root, whether attached now or previously, has a different fingerprint, fails with `CtoPoolOpenError::SchemaMismatch`.
+	/// If the existing root was created with a different `CtoPointerLayoutVersion`, fails with `CtoPoolOpenError::PointerLayoutMismatch`.
+	/// Fails with `CtoPoolOpenError::RootTypeMismatch` if the root was previously attached as a different `RootValue`.
+	/// Used by `Pools::get_cto_pool()`.
+	#[inline(always)]
+	pub fn open_from_allocator<InitializationError: error::Error, RootValueInitializer: FnOnce(&mut RootValue, &CtoPoolArc) -> Result<(), InitializationError>>(cto_pool_arc: CtoPoolArc, root_value_initializer: RootValueInitializer) -> Result<Self, CtoPoolOpenError<InitializationError>>
+		where RootValue: 'static
+	{
+		Self::open_from_allocator_with_migrations(cto_pool_arc, &CtoRootMigrations::new(), root_value_initializer)
+	}
+	
+	/// As for `open_from_allocator()`, but an existing root with a different fingerprint is upgraded using `cto_root_migrations` if possible.
+	#[inline(always)]
+	pub fn open_from_allocator_with_migrations<InitializationError: error::Error, RootValueInitializer: FnOnce(&mut RootValue, &CtoPoolArc) -> Result<(), InitializationError>>(cto_pool_arc: CtoPoolArc, cto_root_migrations: &CtoRootMigrations<RootValue, InitializationError>, root_value_initializer: RootValueInitializer) -> Result<Self, CtoPoolOpenError<InitializationError>>
+		where RootValue: 'static
+	{
+		{
+			let mut root_attachment = cto_pool_arc.root_attachment();
+			let previously_attached = root_attachment.check::<RootValue, InitializationError>()?;
+			if unlikely(!previously_attached)
+			{
+				let pool_pointer = cto_pool_arc.pool_pointer();
+				let existing_root = pool_pointer.get_root::<u8>();
+				if unlikely(existing_root.is_null())
+				{
+					let new_root = Self::allocate_root(pool_pointer).map_err(|pmdk_error| CtoPoolOpenError::RootCreation(CtoPoolAllocationError::Allocation(pmdk_error)))?;
+					let root = unsafe { &mut * Self::value_pointer(new_root) };
+					if let Err(initialization_error) = root_value_initializer(root, &cto_pool_arc)
+					{
+						pool_pointer.free(new_root);
+						return Err(CtoPoolOpenError::RootCreation(CtoPoolAllocationError::Initialization(initialization_error)))
+					}
+					pool_pointer.set_root(new_root);
+				}
+				else
+				{
+					RootValue::validate_existing_root(existing_root, &cto_pool_arc, cto_root_migrations)?;
+					
+					let root = unsafe { &mut * Self::value_pointer(pool_pointer.get_root()) };
+					root.cto_pool_opened(&cto_pool_arc);
+				}
+			}
+			
+			root_attachment.acquire::<RootValue>();
+		}
 		
-		let existing_root = pool_pointer.get_root();
+		Ok(CtoPool(CtoPoolAlloc(cto_pool_arc), PhantomData))
+	}
+	
+	/// Replaces the root, eg to upgrade its schema, such that either the existing root or the new root is reachable after a power failure.
+	///
+	/// `root_value_replacer` is passed the existing root and returns the new one.
+	/// The new root is moved into freshly allocated pool memory and persisted, then published with a single update of the root pointer; only then is the existing root dropped and freed.
+	/// If `root_value_replacer` fails, or memory can not be allocated, the existing root is left untouched.
+	/// This `CtoPool` is the only owner of the root, and `&mut self` ensures nothing borrowed from it is still alive, so no reference to the existing root can outlive it being freed; the root attachment is locked while it is swapped, so no other `CtoPool` can attach it meanwhile.
+	///
+	/// Only the root structure itself is persisted; any persistent memory it owns should already have been persisted.
+	/// libpmemcto does not expose the location of the root pointer, so the root pointer itself is persisted when the pool is closed.
+	#[inline(always)]
+	pub fn replace_root<ReplacementError: error::Error, RootValueReplacer: FnOnce(&RootValue, &CtoPoolArc) -> Result<RootValue, ReplacementError>>(&mut self, root_value_replacer: RootValueReplacer) -> Result<(), CtoPoolAllocationError<ReplacementError>>
+	{
+		let pool_pointer = self.pool_pointer();
+		let existing_root = pool_pointer.get_root::<u8>();
 		if unlikely(existing_root.is_null())
 		{
-			let new_root = cto_pool_alloc.pool_pointer().aligned_allocate::<RootValue>().map_err(|pmdk_error| CtoPoolOpenError::RootCreation(CtoPoolAllocationError::Allocation(pmdk_error)))?;
-			let root = unsafe { &mut * (new_root as *mut RootValue) };
-			root_value_initializer(root, cto_pool_alloc.allocator()).map_err(|initialization_error| CtoPoolOpenError::RootCreation(CtoPoolAllocationError::Initialization(initialization_error)))?;
-			pool_pointer.set_root(new_root);
+			panic!("No root object");
 		}
-		else
+		
+		let new_root_value = root_value_replacer(unsafe { & * Self::value_pointer(existing_root) }, self.allocator()).map_err(CtoPoolAllocationError::Initialization)?;
+		
+		let new_root = Self::allocate_root(pool_pointer).map_err(CtoPoolAllocationError::Allocation)?;
+		unsafe { write(Self::value_pointer(new_root), new_root_value) };
+		Self::persist_root(new_root);
+		
 		{
-			let root = unsafe { &mut * (existing_root as *mut RootValue) };
-			root.cto_pool_opened(cto_pool_alloc.allocator());
+			let root_attachment = self.allocator().root_attachment();
+			assert!(root_attachment.is_owned(), "root is not owned");
+			
+			pool_pointer.set_root(new_root);
+			
+			unsafe { drop_in_place(Self::value_pointer(existing_root)) };
+			pool_pointer.free(existing_root);
 		}
 		
-		Ok(cto_pool_alloc)
+		Ok(())
 	}
 	
 	/// Returns an object that can be used for allocations.
@@ -190,9 +286,52 @@ impl<RootValue: CtoSafe> CtoPool<RootValue>
 		&self.0
 	}
 	
+	/// Heap statistics for this pool, such as bytes allocated and mapped.
+	/// Returns `None` only if libpmemcto was built without statistics.
+	#[inline(always)]
+	pub fn statistics(&self) -> Option<CtoPoolStatistics>
+	{
+		self.pool_pointer().statistics()
+	}
+	
 	#[inline(always)]
 	fn pool_pointer(&self) -> *mut PMEMctopool
 	{
 		self.alloc().pool_pointer()
 	}
+	
+	#[inline(always)]
+	fn value_pointer(root: *mut u8) -> *mut RootValue
+	{
+		let (_layout, value_offset) = RootValue::root_layout();
+		(unsafe { root.offset(value_offset as isize) }) as *mut RootValue
+	}
+	
+	#[inline(always)]
+	fn allocate_root(pool_pointer: *mut PMEMctopool) -> Result<*mut u8, PmdkError>
+	{
+		let (layout, _value_offset) = RootValue::root_layout();
+		let root = pool_pointer.aligned_alloc(layout.align(), layout.size())? as *mut u8;
+		RootValue::initialize_root_header(root);
+		Ok(root)
+	}
+	
+	#[inline(always)]
+	fn persist_root(root: *mut u8)
+	{
+		let address = root as *mut c_void;
+		let (layout, _value_offset) = RootValue::root_layout();
+		let length = layout.size();
+		
+		if (address as *const c_void).is_persistent_memory_that_supports_flushing_with_persist(length)
+		{
+			PersistentMemoryPersistence::flush_memory(address, length);
+			PersistentMemoryPersistence::drain_memory();
+		}
+		else
+		{
+			MMapMemoryPersistence::flush_memory(address, length);
+			MMapMemoryPersistence::drain_memory();
+		}
+	}
 }
@@ -18,6 +18,15 @@ unsafe impl Sync for CtoPoolArc
 {
 }
 
+impl Debug for CtoPoolArc
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.write_str(&format!("CtoPoolArc({:?})", self.pool_pointer()))
+	}
+}
+
 impl Drop for CtoPoolArc
 {
 	#[inline(always)]
@@ -62,6 +71,14 @@ impl CtoPoolArc
 		unsafe { self.cto_pool_arc_inner.as_ref() }.pool_pointer
 	}
 	
+	/// Heap statistics for this pool, such as bytes allocated and mapped.
+	/// Returns `None` only if libpmemcto was built without statistics.
+	#[inline(always)]
+	pub fn statistics(&self) -> Option<CtoPoolStatistics>
+	{
+		self.pool_pointer().statistics()
+	}
+	
 	/// Allocate a CtoString, which is similar to a Rust String but uses the persistent memory pool instead of the system allocator.
 	/// Returns on success a CtoString.
 	#[inline(always)]
@@ -78,6 +95,46 @@ impl CtoPoolArc
 		CtoString::with_capacity(capacity, self.alloc())
 	}
 	
+	/// Allocate a CtoBinaryHeap, which is similar to a Rust BinaryHeap but uses the persistent memory pool instead of the system allocator.
+	/// Returns on success a CtoBinaryHeap.
+	#[inline(always)]
+	pub fn allocate_binary_heap<Value: CtoSafe + Ord>(&self) -> CtoBinaryHeap<Value>
+	{
+		CtoBinaryHeap::new(self.alloc())
+	}
+	
+	/// Allocate a CtoBTreeMap, which is similar to a Rust BTreeMap but uses the persistent memory pool instead of the system allocator.
+	/// Returns on success a CtoBTreeMap.
+	#[inline(always)]
+	pub fn allocate_btree_map<Key: CtoSafe + Ord, Value: CtoSafe>(&self) -> CtoBTreeMap<Key, Value>
+	{
+		CtoBTreeMap::new(self.alloc())
+	}
+	
+	/// Allocate a CtoBTreeSet, which is similar to a Rust BTreeSet but uses the persistent memory pool instead of the system allocator.
+	/// Returns on success a CtoBTreeSet.
+	#[inline(always)]
+	pub fn allocate_btree_set<Value: CtoSafe + Ord>(&self) -> CtoBTreeSet<Value>
+	{
+		CtoBTreeSet::new(self.alloc())
+	}
+	
+	/// Allocate a CtoHashMap, which is similar to a Rust HashMap but uses the persistent memory pool instead of the system allocator.
+	/// Returns on success a CtoHashMap.
+	#[inline(always)]
+	pub fn allocate_hash_map<Key: CtoSafe + Eq + Hash, Value: CtoSafe>(&self) -> CtoHashMap<Key, Value>
+	{
+		CtoHashMap::new(self.alloc())
+	}
+	
+	/// Allocate a CtoHashMap with capacity, which is similar to a Rust HashMap but uses the persistent memory pool instead of the system allocator.
+	/// Returns on success a CtoHashMap.
+	#[inline(always)]
+	pub fn allocate_hash_map_with_capacity<Key: CtoSafe + Eq + Hash, Value: CtoSafe>(&self, capacity: usize) -> CtoHashMap<Key, Value>
+	{
+		CtoHashMap::with_capacity(capacity, self.alloc())
+	}
+	
 	/// Allocate a CtoVec, which is similar to a Rust Vec but uses the persistent memory pool instead of the system allocator.
 	/// Returns on success a CtoVec.
 	#[inline(always)]
@@ -93,6 +150,14 @@ impl CtoPoolArc
 	{
 		CtoVec::with_capacity(capacity, self.alloc())
 	}
+	
+	/// Allocate a CtoVecDeque, which is similar to a Rust VecDeque but uses the persistent memory pool instead of the system allocator.
+	/// Returns on success a CtoVecDeque.
+	#[inline(always)]
+	pub fn allocate_vec_deque<Value: CtoSafe>(&self) -> CtoVecDeque<Value>
+	{
+		CtoVecDeque::new(self.alloc())
+	}
 
 	/// Allocate a CtoReadWriteLock, which is similar to a Rust Mutex but uses the persistent memory pool instead of the system allocator.
 	/// The reference passed to initializer() will be ALMOST uninitialized memory; it won't even be zeroed or have default values.
@@ -144,6 +209,18 @@ impl CtoPoolArc
 		self.allocate::<CtoMutexLock<Value>, InitializationError, Initializer>(initializer)
 	}
 	
+	/// Allocate a CtoRobustMutexLock, which is similar to a CtoMutexLock but reports the death of the thread or process holding it.
+	/// Set `process_shared` if the pool is mapped into more than one process at once.
+	/// The reference passed to initializer() will be ALMOST uninitialized memory; it won't even be zeroed or have default values.
+	/// Returns on success a CtoRobustMutexLock.
+	/// Do not use Heap-allocated objects for fields of T, ie only use CtoSafe fields.
+	#[cfg(target_os = "linux")]
+	#[inline(always)]
+	pub fn allocate_robust_mutex_lock<Value: CtoSafe, InitializationError, Initializer: FnOnce(*mut Value, &CtoPoolArc) -> Result<(), InitializationError>>(&self, process_shared: bool, initializer: Initializer) -> Result<CtoRobustMutexLock<Value>, CtoPoolAllocationError<InitializationError>>
+	{
+		CtoRobustMutexLock::allocate(process_shared, initializer, self)
+	}
+	
 	/// Allocate a CtoRc, which is similar to a Rust Rc but uses the persistent memory pool instead of the system allocator.
 	/// The reference passed to initializer() will be ALMOST uninitialized memory; it won't even be zeroed or have default values.
 	/// Returns on success a CtoRc.
@@ -180,18 +257,18 @@ impl CtoPoolArc
 		self.pool_pointer().allocate(initializer, self)
 	}
 	
-// const PageAlignment: usize = 4096;
-//
-//	#[inline(always)]
-//	fn page_aligned_allocate<T>(&self) -> Result<NonNull<T>, PmdkError>
-//	{
-//		match self.pool_pointer().aligned_alloc(Self::PageAlignment, size_of::<T>())
-//		{
-//			Ok(void_pointer) => Ok(unsafe { NonNull::new_unchecked(void_pointer as *mut T) }),
-//			Err(pmdk_error) => Err(pmdk_error)
-//		}
-//	}
-//
+	const PageAlignment: usize = 4096;
+	
+	#[inline(always)]
+	fn page_aligned_allocate<T>(&self) -> Result<NonNull<T>, PmdkError>
+	{
+		match self.pool_pointer().aligned_alloc(Self::PageAlignment, size_of::<T>())
+		{
+			Ok(void_pointer) => Ok(unsafe { NonNull::new_unchecked(void_pointer as *mut T) }),
+			Err(pmdk_error) => Err(pmdk_error)
+		}
+	}
+	
 //	#[inline(always)]
 //	fn free_non_null<T>(&self, non_null: NonNull<T>)
 //	{
@@ -218,6 +295,12 @@ impl CtoPoolArc
 		self.pool_pointer().free(non_null_pointer)
 	}
 	
+	#[inline(always)]
+	fn root_attachment(&self) -> MutexGuard<CtoPoolRootAttachment>
+	{
+		unsafe { self.cto_pool_arc_inner.as_ref() }.root_attachment.lock()
+	}
+	
 	#[inline(always)]
 	fn alloc(&self) -> CtoPoolAlloc
 	{
@@ -7,6 +7,7 @@ struct CtoPoolArcInner
 {
 	reference_counter: AtomicUsize,
 	pool_pointer: *mut PMEMctopool,
+	root_attachment: Mutex<CtoPoolRootAttachment>,
 }
 
 impl CtoPoolArcInner
@@ -20,6 +21,7 @@ impl CtoPoolArcInner
 		{
 			pool_pointer,
 			reference_counter: AtomicUsize::new(Self::MinimumReference),
+			root_attachment: Mutex::new(CtoPoolRootAttachment::default()),
 		}
 	}
 	
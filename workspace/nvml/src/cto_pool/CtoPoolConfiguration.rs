@@ -0,0 +1,100 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// Represents CTO pool configuration which can be persisted or deserialized using Serde.
+/// Use `CtoPoolsConfiguration` or `Configuration` to manage multiple pools.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct CtoPoolConfiguration
+{
+	/// Permissions for this pool. 0o600 is a good option.
+	pub permissions: mode_t,
+	
+	/// Pool size in bytes. Ought to be a power of 2.
+	/// Must be at least `nvml_sys::PMEMCTO_MIN_POOL` (As of February 2018, 1024 * 1024 * 16 bytes)
+	pub pool_size: Option<usize>,
+	
+	/// Name of layout.
+	/// Defaults to the pool's file name if not specified.
+	pub layout_name: Option<String>,
+}
+
+impl Default for CtoPoolConfiguration
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			permissions: Configuration::DefaultPermissionsForPoolSets,
+			pool_size: None,
+			layout_name: None,
+		}
+	}
+}
+
+impl CtoPoolConfiguration
+{
+	/// Open or create (if necessary) a CTO pool.
+	/// Do not use this method directly unless only using one CTO pool.
+	/// The root of the pool is not attached; use `CtoPool::open_from_allocator()` (or `Pools::get_cto_pool()`) to do that.
+	pub fn open_or_create(&self, cto_pool_sets_folder_path: &Path, file_name: &str) -> Result<CtoPoolArc, PoolsOpenError>
+	{
+		let pool_set_file_path = cto_pool_sets_folder_path.join(file_name);
+		let error = |failure| PoolsOpenError::pool(PoolKind::Cto, file_name, &pool_set_file_path, failure);
+		
+		let layout_name = match self.layout_name
+		{
+			None => file_name,
+			Some(ref layout_name) => layout_name,
+		};
+		
+		let layout_name = match CString::new(layout_name)
+		{
+			Err(_) => return Err(error(InvalidLayoutName)),
+			Ok(layout_name) => layout_name,
+		};
+		if unlikely(layout_name.as_bytes().len() > PMEMCTO_MAX_LAYOUT)
+		{
+			return Err(error(InvalidLayoutName))
+		}
+		let layout_name = layout_name.as_c_str();
+		
+		let pool_pointer = if likely(pool_set_file_path.exists())
+		{
+			if unlikely(!pool_set_file_path.is_file())
+			{
+				return Err(error(PoolSetFileIsNotAFile))
+			}
+			
+			match pool_set_file_path.validate_cto_pool_is_consistent(layout_name)
+			{
+				Err(pmdk_error) => return Err(error(ValidationFailed(pmdk_error))),
+				Ok(false) => return Err(error(Invalid)),
+				Ok(true) => (),
+			}
+			
+			pool_set_file_path.open_cto_pool(layout_name).map_err(|pmdk_error| error(OpenFailed(pmdk_error)))?
+		}
+		else
+		{
+			let pool_size = match self.pool_size
+			{
+				None => 0,
+				Some(pool_size) =>
+				{
+					if unlikely(pool_size < PMEMCTO_MIN_POOL)
+					{
+						return Err(error(PoolSizeTooSmall { pool_size, minimum_pool_size: PMEMCTO_MIN_POOL }))
+					}
+					pool_size
+				},
+			};
+			pool_set_file_path.create_cto_pool(layout_name, pool_size, self.permissions).map_err(|pmdk_error| error(CreateFailed(pmdk_error)))?
+		};
+		
+		Ok(CtoPoolArc::new(pool_pointer))
+	}
+}
@@ -19,7 +19,36 @@ pub enum CtoPoolOpenError<InitializationError: error::Error>
 	Invalid,
 	
 	/// After creating or opening a CTO pool, a root object was missing and creation of it was tried. Creation then failed.
+	/// Also occurs if migrating an existing root failed.
 	RootCreation(CtoPoolAllocationError<InitializationError>),
+	
+	/// The existing root was created for a different `CtoRootVersion` (or for none) and no migration from it was registered.
+	SchemaMismatch
+	{
+		/// The fingerprint of `RootValue`.
+		expected: CtoRootFingerprint,
+		
+		/// The fingerprint stored beside the existing root; `None` if it was created without a `CtoRootVersion`.
+		found: Option<CtoRootFingerprint>,
+	},
+	
+	/// The existing root was created with a different `CtoPointerLayoutVersion`, so the `CtoBox`, `CtoRc` and `CtoArc` pointers reachable from it can not be read.
+	/// A root created before the version was recorded has version `1`.
+	/// No migration is possible.
+	PointerLayoutMismatch
+	{
+		/// `CtoPointerLayoutVersion`.
+		expected: u64,
+		
+		/// The version recorded beside the existing root.
+		found: u64,
+	},
+	
+	/// The root has already been attached as a different `RootValue`, or was created for a `RootValue` that implements `CtoRootVersion` but is being opened as one that does not.
+	RootTypeMismatch,
+	
+	/// The root is already owned by another `CtoPool`; drop it first.
+	RootAlreadyOwned,
 }
 
 impl<InitializationError: error::Error> Display for CtoPoolOpenError<InitializationError>
@@ -40,6 +69,14 @@ impl<InitializationError: error::Error> Display for CtoPoolOpenError<Initializat
 			Invalid => write!(formatter, "Invalid"),
 			
 			RootCreation(ref cto_pool_allocation_error) => Display::fmt(cto_pool_allocation_error, formatter),
+			
+			SchemaMismatch { ref expected, ref found } => write!(formatter, "Schema mismatch: expected {:?} but found {:?}", expected, found),
+			
+			PointerLayoutMismatch { expected, found } => write!(formatter, "Pointer layout mismatch: expected version {} but found version {}", expected, found),
+			
+			RootTypeMismatch => write!(formatter, "Root type mismatch"),
+			
+			RootAlreadyOwned => write!(formatter, "Root already owned"),
 		}
 	}
 }
@@ -68,6 +105,14 @@ impl<InitializationError: error::Error> error::Error for CtoPoolOpenError<Initia
 			Invalid => None,
 			
 			RootCreation(ref cto_pool_allocation_error) => Some(cto_pool_allocation_error),
+			
+			SchemaMismatch { .. } => None,
+			
+			PointerLayoutMismatch { .. } => None,
+			
+			RootTypeMismatch => None,
+			
+			RootAlreadyOwned => None,
 		}
 	}
 }
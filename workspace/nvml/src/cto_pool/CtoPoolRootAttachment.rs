@@ -0,0 +1,75 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// Records the type and fingerprint of the root attached to a CTO pool, and whether a `CtoPool` currently owns it.
+/// This ensures a root is only ever reinterpreted as one `RootValue`, and that there is never more than one `CtoPool` (and so never more than one `&mut RootValue`) at a time.
+#[derive(Debug, Default)]
+struct CtoPoolRootAttachment
+{
+	root_type: Option<TypeId>,
+	root_fingerprint: Option<CtoRootFingerprint>,
+	owned: bool,
+}
+
+impl CtoPoolRootAttachment
+{
+	/// Returns `true` if a root has been attached before, in which case it does not need to be created or re-initialized.
+	/// The fingerprint of the attached root is compared on every attach, not just when the root is validated by the first one.
+	#[inline(always)]
+	fn check<RootValue: CtoRootLayout + 'static, InitializationError: error::Error>(&self) -> Result<bool, CtoPoolOpenError<InitializationError>>
+	{
+		match self.root_type
+		{
+			None => Ok(false),
+			
+			Some(root_type) =>
+			{
+				if let Some(expected) = RootValue::root_fingerprint()
+				{
+					if unlikely(self.root_fingerprint != Some(expected))
+					{
+						return Err(CtoPoolOpenError::SchemaMismatch { expected, found: self.root_fingerprint })
+					}
+				}
+				
+				if unlikely(root_type != TypeId::of::<RootValue>())
+				{
+					Err(CtoPoolOpenError::RootTypeMismatch)
+				}
+				else if unlikely(self.owned)
+				{
+					Err(CtoPoolOpenError::RootAlreadyOwned)
+				}
+				else
+				{
+					Ok(true)
+				}
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn acquire<RootValue: CtoRootLayout + 'static>(&mut self)
+	{
+		debug_assert!(!self.owned, "root is already owned");
+		
+		self.root_type = Some(TypeId::of::<RootValue>());
+		self.root_fingerprint = RootValue::root_fingerprint();
+		self.owned = true;
+	}
+	
+	#[inline(always)]
+	fn is_owned(&self) -> bool
+	{
+		self.owned
+	}
+	
+	#[inline(always)]
+	fn release(&mut self)
+	{
+		debug_assert!(self.owned, "root is not owned");
+		
+		self.owned = false;
+	}
+}
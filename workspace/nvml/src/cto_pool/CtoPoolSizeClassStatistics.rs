@@ -0,0 +1,39 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// Statistics for one size class in a CTO pool's heap.
+/// Small size classes are jemalloc bins; large size classes are runs of whole pages.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Deserialize, Serialize)]
+pub struct CtoPoolSizeClassStatistics
+{
+	/// Size in bytes of allocations in this size class.
+	pub size: usize,
+	
+	/// Total number of allocations ever made in this size class (`nmalloc`).
+	pub number_of_allocations: u64,
+	
+	/// Total number of deallocations ever made in this size class (`ndalloc`).
+	pub number_of_deallocations: u64,
+	
+	/// Total number of allocation requests satisfied by this size class, including those served from a thread cache (`nrequests`).
+	pub number_of_requests: u64,
+}
+
+impl CtoPoolSizeClassStatistics
+{
+	/// Number of allocations currently live in this size class.
+	#[inline(always)]
+	pub fn current_allocations(&self) -> u64
+	{
+		self.number_of_allocations.saturating_sub(self.number_of_deallocations)
+	}
+	
+	/// Number of bytes currently allocated in this size class.
+	#[inline(always)]
+	pub fn current_bytes(&self) -> u64
+	{
+		self.current_allocations().saturating_mul(self.size as u64)
+	}
+}
@@ -0,0 +1,149 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// Heap statistics for a CTO pool, parsed from libpmemcto's (jemalloc's) statistics output.
+/// Obtain using `CtoPool::statistics()` or `CtoPoolArc::statistics()`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Deserialize, Serialize)]
+pub struct CtoPoolStatistics
+{
+	/// Total number of bytes allocated by the application.
+	pub allocated: usize,
+	
+	/// Total number of bytes in active pages allocated by the application; a multiple of the page size and greater than or equal to `allocated`.
+	pub active: usize,
+	
+	/// Total number of bytes in chunks mapped on behalf of the application; greater than or equal to `active`.
+	/// Compare with the pool size to alert on pool exhaustion.
+	pub mapped: usize,
+	
+	/// Total number of bytes in physically resident data pages.
+	/// `None` if the version of jemalloc bundled with libpmemcto does not report it.
+	pub resident: Option<usize>,
+	
+	/// Per-size-class statistics for small allocations (jemalloc bins), in ascending order of size.
+	pub small_size_classes: Vec<CtoPoolSizeClassStatistics>,
+	
+	/// Per-size-class statistics for large allocations, in ascending order of size.
+	pub large_size_classes: Vec<CtoPoolSizeClassStatistics>,
+}
+
+impl CtoPoolStatistics
+{
+	/// Options passed to `pmemcto_stats_print()`: omit general information (`g`) and per-arena statistics (`a`), leaving merged statistics.
+	const StatisticsPrintOptions: &'static [u8] = b"ga\0";
+	
+	#[inline(always)]
+	fn statistics_print_options() -> &'static CStr
+	{
+		unsafe { CStr::from_bytes_with_nul_unchecked(Self::StatisticsPrintOptions) }
+	}
+	
+	/// Parses the output of `pmemcto_stats_print()`.
+	/// Returns `None` if the output does not contain the summary line (eg libpmemcto was built without statistics).
+	pub fn parse(output: &str) -> Option<Self>
+	{
+		let mut allocated = None;
+		let mut active = None;
+		let mut mapped = None;
+		let mut resident = None;
+		let mut small_size_classes = Vec::new();
+		let mut large_size_classes = Vec::new();
+		
+		// (is_small, column names) of the table currently being read, if any.
+		let mut table: Option<(bool, Vec<&str>)> = None;
+		
+		for line in output.lines()
+		{
+			let line = line.trim();
+			
+			if line.starts_with("Allocated:")
+			{
+				table = None;
+				
+				for pair in line.split(',')
+				{
+					let mut key_and_value = pair.splitn(2, ':');
+					let key = key_and_value.next().unwrap().trim();
+					let value = key_and_value.next().and_then(|value| value.trim().parse::<usize>().ok());
+					match key
+					{
+						"Allocated" => allocated = value,
+						"active" => active = value,
+						"mapped" => mapped = value,
+						"resident" => resident = value,
+						_ => (),
+					}
+				}
+				continue;
+			}
+			
+			let first_token = match line.split_whitespace().next()
+			{
+				None => continue,
+				Some(first_token) => first_token,
+			};
+			
+			// Section headers (`bins:`, `large:`, `huge:`, `small:`, etc) start with a label; rows never do.
+			if first_token.ends_with(':')
+			{
+				let column_names: Vec<&str> = line[first_token.len()..].split_whitespace().collect();
+				let is_a_table_header = column_names.iter().any(|column_name| *column_name == "size");
+				table = match first_token
+				{
+					"bins:" if is_a_table_header => Some((true, column_names)),
+					"large:" if is_a_table_header => Some((false, column_names)),
+					_ => None,
+				};
+				continue;
+			}
+			
+			if let Some((is_small, ref column_names)) = table
+			{
+				let values: Vec<&str> = line.split_whitespace().collect();
+				
+				// Skips gap markers such as `---` and `[3]`, and anything else that is not a row of this table.
+				if values.len() != column_names.len()
+				{
+					continue;
+				}
+				
+				let column = |name: &str| column_names.iter().position(|column_name| *column_name == name).and_then(|index| values[index].parse::<u64>().ok());
+				
+				if let Some(size) = column("size")
+				{
+					let size_class_statistics = CtoPoolSizeClassStatistics
+					{
+						size: size as usize,
+						number_of_allocations: column("nmalloc").unwrap_or(0),
+						number_of_deallocations: column("ndalloc").unwrap_or(0),
+						number_of_requests: column("nrequests").unwrap_or(0),
+					};
+					
+					if is_small
+					{
+						small_size_classes.push(size_class_statistics)
+					}
+					else
+					{
+						large_size_classes.push(size_class_statistics)
+					}
+				}
+			}
+		}
+		
+		Some
+		(
+			Self
+			{
+				allocated: allocated?,
+				active: active?,
+				mapped: mapped?,
+				resident,
+				small_size_classes,
+				large_size_classes,
+			}
+		)
+	}
+}
@@ -0,0 +1,52 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// A simple way to manage CTO pools configuration using Serde if desired.
+/// Prefer the use of `Configuration` unless only working with CTO pools.
+#[derive(Debug, Clone)]
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct CtoPoolsConfiguration
+{
+	/// Folder name for the pool sets for CTO pools.
+	pub pool_sets_folder_name: String,
+	
+	/// Configurations.
+	pub cto_pool_configurations: HashMap<String, CtoPoolConfiguration>
+}
+
+impl Default for CtoPoolsConfiguration
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			pool_sets_folder_name: "cto".to_string(),
+			cto_pool_configurations: HashMap::new(),
+		}
+	}
+}
+
+impl CtoPoolsConfiguration
+{
+	/// Opens a set of CTO pools.
+	/// Do not use this method directly unless only using CTO pools.
+	pub fn open(&self, pools_folder_path: &Path) -> Result<HashMap<String, CtoPoolArc>, PoolsOpenError>
+	{
+		let cto_pool_sets_folder_path = pools_folder_path.join(&self.pool_sets_folder_name);
+		
+		if unlikely(!cto_pool_sets_folder_path.exists())
+		{
+			return Ok(HashMap::new())
+		}
+		
+		if unlikely(!cto_pool_sets_folder_path.is_dir())
+		{
+			return Err(PoolsOpenError::pool_sets_folder(PoolKind::Cto, &cto_pool_sets_folder_path))
+		}
+		
+		self.cto_pool_configurations.iter().map(|(file_name, cto_pool_configuration)| cto_pool_configuration.open_or_create(&cto_pool_sets_folder_path, file_name).map(|cto_pool_arc| (file_name.to_string(), cto_pool_arc))).collect()
+	}
+}
@@ -0,0 +1,89 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// The version, size and alignment of a root, and the `CtoPointerLayoutVersion`, stored beside it in a `CtoPool`.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct CtoRootFingerprint
+{
+	magic: u64,
+	version: u64,
+	size: u64,
+	alignment: u64,
+	pointer_layout_version: u64,
+}
+
+impl CtoRootFingerprint
+{
+	const Magic: u64 = 0x5652_544F_4F52_4F54;
+	
+	/// Version.
+	#[inline(always)]
+	pub fn version(&self) -> u64
+	{
+		self.version
+	}
+	
+	/// Size, in bytes.
+	#[inline(always)]
+	pub fn size(&self) -> u64
+	{
+		self.size
+	}
+	
+	/// Alignment, in bytes.
+	#[inline(always)]
+	pub fn alignment(&self) -> u64
+	{
+		self.alignment
+	}
+	
+	/// The `CtoPointerLayoutVersion` the root was created with.
+	#[inline(always)]
+	pub fn pointer_layout_version(&self) -> u64
+	{
+		self.pointer_layout_version
+	}
+	
+	#[inline(always)]
+	fn of<RootValue: CtoRootVersion>() -> Self
+	{
+		Self
+		{
+			magic: Self::Magic,
+			version: RootValue::Version,
+			size: size_of::<RootValue>() as u64,
+			alignment: align_of::<RootValue>() as u64,
+			pointer_layout_version: CtoPointerLayoutVersion,
+		}
+	}
+	
+	/// Returns `None` if `root` was not created with a fingerprint.
+	#[inline(always)]
+	fn from_root(root: *mut u8, pool_pointer: *mut PMEMctopool) -> Option<Self>
+	{
+		if pool_pointer.usable_size(root as *mut c_void) < size_of::<Self>()
+		{
+			return None
+		}
+		
+		let fingerprint = unsafe { read(root as *const Self) };
+		if fingerprint.magic == Self::Magic
+		{
+			Some(fingerprint)
+		}
+		else
+		{
+			None
+		}
+	}
+	
+	/// The offset of the root value that follows this fingerprint; the layout is the same as that produced by `CtoRootLayout::root_layout()`.
+	#[inline(always)]
+	fn value_offset(&self) -> Option<usize>
+	{
+		let value_layout = Layout::from_size_align(self.size as usize, self.alignment as usize)?;
+		Layout::new::<Self>().extend(value_layout).map(|(_layout, offset)| offset)
+	}
+}
@@ -0,0 +1,141 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// How a root is laid out in its allocation.
+/// A root that implements `CtoRootVersion` is preceded by a `CtoRootFingerprint`; any other root is preceded by a `CtoPointerLayoutHeader`.
+/// Either records the `CtoPointerLayoutVersion`, so that an existing root with a different one (or with none, which means version `1`) is refused.
+trait CtoRootLayout: CtoSafe + Sized
+{
+	/// The layout of the allocation and the offset of the root value within it.
+	#[inline(always)]
+	fn root_layout() -> (Layout, usize);
+	
+	#[inline(always)]
+	fn initialize_root_header(root: *mut u8);
+	
+	/// The fingerprint stored beside a root of `Self`, if any.
+	#[inline(always)]
+	fn root_fingerprint() -> Option<CtoRootFingerprint>;
+	
+	/// Checks that an existing root has the layout of `Self`, migrating it if necessary.
+	#[inline(always)]
+	fn validate_existing_root<InitializationError: error::Error>(root: *mut u8, cto_pool_arc: &CtoPoolArc, cto_root_migrations: &CtoRootMigrations<Self, InitializationError>) -> Result<(), CtoPoolOpenError<InitializationError>>;
+}
+
+impl<RootValue: CtoSafe> CtoRootLayout for RootValue
+{
+	#[inline(always)]
+	default fn root_layout() -> (Layout, usize)
+	{
+		Layout::new::<CtoPointerLayoutHeader>().extend(Layout::new::<Self>()).expect("root is too large to allocate")
+	}
+	
+	#[inline(always)]
+	default fn initialize_root_header(root: *mut u8)
+	{
+		let (_layout, value_offset) = Self::root_layout();
+		unsafe { write(root as *mut CtoPointerLayoutHeader, CtoPointerLayoutHeader::of(value_offset)) }
+	}
+	
+	#[inline(always)]
+	default fn root_fingerprint() -> Option<CtoRootFingerprint>
+	{
+		None
+	}
+	
+	#[inline(always)]
+	default fn validate_existing_root<InitializationError: error::Error>(root: *mut u8, cto_pool_arc: &CtoPoolArc, _cto_root_migrations: &CtoRootMigrations<Self, InitializationError>) -> Result<(), CtoPoolOpenError<InitializationError>>
+	{
+		use self::CtoPoolOpenError::*;
+		
+		let pool_pointer = cto_pool_arc.pool_pointer();
+		
+		match CtoPointerLayoutHeader::from_root(root, pool_pointer)
+		{
+			Some(header) if header.pointer_layout_version() == CtoPointerLayoutVersion => Ok(()),
+			
+			Some(header) => Err(PointerLayoutMismatch { expected: CtoPointerLayoutVersion, found: header.pointer_layout_version() }),
+			
+			None => match CtoRootFingerprint::from_root(root, pool_pointer)
+			{
+				// The root was created for a `RootValue` that implements `CtoRootVersion`.
+				Some(_) => Err(RootTypeMismatch),
+				
+				None => Err(PointerLayoutMismatch { expected: CtoPointerLayoutVersion, found: CtoPointerLayoutHeader::UnrecordedPointerLayoutVersion }),
+			},
+		}
+	}
+}
+
+impl<RootValue: CtoRootVersion> CtoRootLayout for RootValue
+{
+	#[inline(always)]
+	fn root_layout() -> (Layout, usize)
+	{
+		Layout::new::<CtoRootFingerprint>().extend(Layout::new::<Self>()).expect("root is too large to allocate")
+	}
+	
+	#[inline(always)]
+	fn initialize_root_header(root: *mut u8)
+	{
+		unsafe { write(root as *mut CtoRootFingerprint, CtoRootFingerprint::of::<Self>()) }
+	}
+	
+	#[inline(always)]
+	fn root_fingerprint() -> Option<CtoRootFingerprint>
+	{
+		Some(CtoRootFingerprint::of::<Self>())
+	}
+	
+	#[inline(always)]
+	fn validate_existing_root<InitializationError: error::Error>(root: *mut u8, cto_pool_arc: &CtoPoolArc, cto_root_migrations: &CtoRootMigrations<Self, InitializationError>) -> Result<(), CtoPoolOpenError<InitializationError>>
+	{
+		use self::CtoPoolAllocationError::*;
+		use self::CtoPoolOpenError::*;
+		
+		let pool_pointer = cto_pool_arc.pool_pointer();
+		
+		let expected = CtoRootFingerprint::of::<Self>();
+		let found = CtoRootFingerprint::from_root(root, pool_pointer);
+		
+		let (migration, old_value_offset) = match found
+		{
+			Some(found_fingerprint) if found_fingerprint == expected => return Ok(()),
+			
+			Some(found_fingerprint) if found_fingerprint.pointer_layout_version() != expected.pointer_layout_version() => return Err(PointerLayoutMismatch { expected: expected.pointer_layout_version(), found: found_fingerprint.pointer_layout_version() }),
+			
+			Some(found_fingerprint) if found_fingerprint.version() != expected.version() => match (cto_root_migrations.migration(Some(found_fingerprint.version())), found_fingerprint.value_offset())
+			{
+				(Some(migration), Some(old_value_offset)) => (migration, old_value_offset),
+				_ => return Err(SchemaMismatch { expected, found }),
+			},
+			
+			Some(_) => return Err(SchemaMismatch { expected, found }),
+			
+			None => match CtoPointerLayoutHeader::from_root(root, pool_pointer)
+			{
+				Some(header) if header.pointer_layout_version() == expected.pointer_layout_version() => match cto_root_migrations.migration(None)
+				{
+					Some(migration) => (migration, header.value_offset()),
+					None => return Err(SchemaMismatch { expected, found }),
+				},
+				
+				Some(header) => return Err(PointerLayoutMismatch { expected: expected.pointer_layout_version(), found: header.pointer_layout_version() }),
+				
+				None => return Err(PointerLayoutMismatch { expected: expected.pointer_layout_version(), found: CtoPointerLayoutHeader::UnrecordedPointerLayoutVersion }),
+			},
+		};
+		
+		let new_root_value = migration(unsafe { root.offset(old_value_offset as isize) }, cto_pool_arc).map_err(|initialization_error| RootCreation(Initialization(initialization_error)))?;
+		
+		let new_root = CtoPool::<Self>::allocate_root(pool_pointer).map_err(|pmdk_error| RootCreation(Allocation(pmdk_error)))?;
+		unsafe { write(CtoPool::<Self>::value_pointer(new_root), new_root_value) };
+		CtoPool::<Self>::persist_root(new_root);
+		
+		pool_pointer.set_root(new_root);
+		pool_pointer.free(root);
+		
+		Ok(())
+	}
+}
@@ -0,0 +1,67 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// Migrations that upgrade an older root to `RootValue` when a `CtoPool` is opened.
+///
+/// A migration is passed a pointer to the old root value and must take ownership of everything it keeps from it (eg using `ptr::read()`); the old root is then freed without being dropped.
+/// The new root is persisted before it replaces the old one, and `cto_pool_opened()` is then called on it.
+/// Only used if `RootValue` implements `CtoRootVersion`.
+pub struct CtoRootMigrations<RootValue: CtoSafe, InitializationError: error::Error>
+{
+	migrations: HashMap<Option<u64>, Box<Fn(*mut u8, &CtoPoolArc) -> Result<RootValue, InitializationError>>>,
+}
+
+impl<RootValue: CtoSafe, InitializationError: error::Error> Debug for CtoRootMigrations<RootValue, InitializationError>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.pad("CtoRootMigrations { .. }")
+	}
+}
+
+impl<RootValue: CtoSafe, InitializationError: error::Error> Default for CtoRootMigrations<RootValue, InitializationError>
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+impl<RootValue: CtoSafe, InitializationError: error::Error> CtoRootMigrations<RootValue, InitializationError>
+{
+	/// Creates a new instance with no migrations.
+	#[inline(always)]
+	pub fn new() -> Self
+	{
+		Self
+		{
+			migrations: HashMap::new(),
+		}
+	}
+	
+	/// Registers a migration from a root with `CtoRootVersion::Version` of `from_version`.
+	/// Replaces any migration previously registered for `from_version`.
+	#[inline(always)]
+	pub fn register<Migration: 'static + Fn(*mut u8, &CtoPoolArc) -> Result<RootValue, InitializationError>>(&mut self, from_version: u64, migration: Migration)
+	{
+		self.migrations.insert(Some(from_version), Box::new(migration));
+	}
+	
+	/// Registers a migration from a root that was created without a `CtoRootVersion` implementation.
+	/// Only used if the root was created with the current `CtoPointerLayoutVersion`.
+	/// Replaces any migration previously registered for such a root.
+	#[inline(always)]
+	pub fn register_from_unversioned<Migration: 'static + Fn(*mut u8, &CtoPoolArc) -> Result<RootValue, InitializationError>>(&mut self, migration: Migration)
+	{
+		self.migrations.insert(None, Box::new(migration));
+	}
+	
+	#[inline(always)]
+	fn migration(&self, from_version: Option<u64>) -> Option<&Box<Fn(*mut u8, &CtoPoolArc) -> Result<RootValue, InitializationError>>>
+	{
+		self.migrations.get(&from_version)
+	}
+}
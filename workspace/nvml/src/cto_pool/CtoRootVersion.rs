@@ -0,0 +1,15 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// Implement this for the `RootValue` of a `CtoPool` to record a version and a size and alignment fingerprint beside the root.
+///
+/// When the pool is opened, the recorded fingerprint is compared to that of `Self`.
+/// A mismatch causes `CtoPoolOpenError::SchemaMismatch`, unless a migration for the recorded version is registered with `CtoRootMigrations`.
+///
+/// A root created without a `CtoRootVersion` implementation has no fingerprint, only a record of its `CtoPointerLayoutVersion`; use `CtoRootMigrations::register_from_unversioned()` to migrate it.
+pub trait CtoRootVersion: CtoSafe
+{
+	/// Increment this whenever the layout or meaning of `Self` changes.
+	const Version: u64;
+}
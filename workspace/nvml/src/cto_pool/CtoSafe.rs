@@ -3,7 +3,7 @@
 
 
 /// Structs that are safe to store in persistent memory.
-pub trait CtoSafe: Sized
+pub trait CtoSafe
 {
 	#[doc(hidden)]
 	#[inline(always)]
@@ -135,3 +135,162 @@ impl<Value: CtoSafe> CtoSafe for Option<Value>
 		}
 	}
 }
+
+impl CtoSafe for char
+{
+	#[inline(always)]
+	fn cto_pool_opened(&mut self, _cto_pool_arc: &CtoPoolArc)
+	{
+	}
+}
+
+impl CtoSafe for ()
+{
+	#[inline(always)]
+	fn cto_pool_opened(&mut self, _cto_pool_arc: &CtoPoolArc)
+	{
+	}
+}
+
+impl<T: ?Sized> CtoSafe for PhantomData<T>
+{
+	#[inline(always)]
+	fn cto_pool_opened(&mut self, _cto_pool_arc: &CtoPoolArc)
+	{
+	}
+}
+
+impl CtoSafe for NonZeroU8
+{
+	#[inline(always)]
+	fn cto_pool_opened(&mut self, _cto_pool_arc: &CtoPoolArc)
+	{
+	}
+}
+
+impl CtoSafe for NonZeroU16
+{
+	#[inline(always)]
+	fn cto_pool_opened(&mut self, _cto_pool_arc: &CtoPoolArc)
+	{
+	}
+}
+
+impl CtoSafe for NonZeroU32
+{
+	#[inline(always)]
+	fn cto_pool_opened(&mut self, _cto_pool_arc: &CtoPoolArc)
+	{
+	}
+}
+
+impl CtoSafe for NonZeroU64
+{
+	#[inline(always)]
+	fn cto_pool_opened(&mut self, _cto_pool_arc: &CtoPoolArc)
+	{
+	}
+}
+
+impl CtoSafe for NonZeroUsize
+{
+	#[inline(always)]
+	fn cto_pool_opened(&mut self, _cto_pool_arc: &CtoPoolArc)
+	{
+	}
+}
+
+impl CtoSafe for Duration
+{
+	#[inline(always)]
+	fn cto_pool_opened(&mut self, _cto_pool_arc: &CtoPoolArc)
+	{
+	}
+}
+
+impl CtoSafe for Ordering
+{
+	#[inline(always)]
+	fn cto_pool_opened(&mut self, _cto_pool_arc: &CtoPoolArc)
+	{
+	}
+}
+
+macro_rules! cto_safe_tuple
+{
+	($($name: ident),+) =>
+	{
+		impl<$($name: CtoSafe),+> CtoSafe for ($($name,)+)
+		{
+			#[allow(non_snake_case)]
+			#[inline(always)]
+			fn cto_pool_opened(&mut self, cto_pool_arc: &CtoPoolArc)
+			{
+				let ($(ref mut $name,)+) = *self;
+				$($name.cto_pool_opened(cto_pool_arc);)+
+			}
+		}
+	}
+}
+
+cto_safe_tuple!(A);
+cto_safe_tuple!(A, B);
+cto_safe_tuple!(A, B, C);
+cto_safe_tuple!(A, B, C, D);
+cto_safe_tuple!(A, B, C, D, E);
+cto_safe_tuple!(A, B, C, D, E, F);
+cto_safe_tuple!(A, B, C, D, E, F, G);
+cto_safe_tuple!(A, B, C, D, E, F, G, H);
+cto_safe_tuple!(A, B, C, D, E, F, G, H, I);
+cto_safe_tuple!(A, B, C, D, E, F, G, H, I, J);
+cto_safe_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+cto_safe_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+// As for the standard library, implemented for arrays of length 0 to 32 inclusive.
+macro_rules! cto_safe_array
+{
+	($($length: expr)+) =>
+	{
+		$(
+			impl<Value: CtoSafe> CtoSafe for [Value; $length]
+			{
+				#[inline(always)]
+				fn cto_pool_opened(&mut self, cto_pool_arc: &CtoPoolArc)
+				{
+					for value in self.iter_mut()
+					{
+						value.cto_pool_opened(cto_pool_arc)
+					}
+				}
+			}
+		)+
+	}
+}
+
+cto_safe_array!
+{
+	0 1 2 3 4 5 6 7 8 9
+	10 11 12 13 14 15 16 17 18 19
+	20 21 22 23 24 25 26 27 28 29
+	30 31 32
+}
+
+impl<Value: CtoSafe> CtoSafe for [Value]
+{
+	#[inline(always)]
+	fn cto_pool_opened(&mut self, cto_pool_arc: &CtoPoolArc)
+	{
+		for value in self.iter_mut()
+		{
+			value.cto_pool_opened(cto_pool_arc)
+		}
+	}
+}
+
+impl CtoSafe for str
+{
+	#[inline(always)]
+	fn cto_pool_opened(&mut self, _cto_pool_arc: &CtoPoolArc)
+	{
+	}
+}
@@ -10,6 +10,12 @@ trait PMEMctopoolExt
 	#[inline(always)]
 	fn print_statistics(self);
 	
+	/// Captures the statistics that would be printed by `print_statistics()` and parses them.
+	/// Returns `None` if libpmemcto was built without statistics.
+	/// self can not be null.
+	#[inline(always)]
+	fn statistics(self) -> Option<CtoPoolStatistics>;
+	
 	/// Close the CTO pool.
 	/// self can not be null.
 	#[inline(always)]
@@ -20,7 +26,7 @@ trait PMEMctopoolExt
 	#[inline(always)]
 	fn get_root<T>(self) -> *mut T;
 	
-	/// Set the root pointer. Should only be used if the current root pointer is null. Root pointer to set should not be null.
+	/// Set the root pointer. Should only be used if the current root pointer is null, or by `CtoPool::replace_root()`. Root pointer to set should not be null.
 	/// Should be a pointer to an object previously created with one of the following methods on *mut PMEMctopool:-
 	/// * `malloc()`
 	/// * `aligned_alloc()`
@@ -76,7 +82,7 @@ trait PMEMctopoolExt
 	/// Pointer must not be null.
 	/// self can not be null.
 	#[inline(always)]
-	fn free<T>(self, pointer: *mut T);
+	fn free<T: ?Sized>(self, pointer: *mut T);
 }
 
 impl PMEMctopoolExt for *mut PMEMctopool
@@ -89,6 +95,14 @@ impl PMEMctopoolExt for *mut PMEMctopool
 		unsafe { pmemcto_stats_print(self, null()) }
 	}
 	
+	#[inline(always)]
+	fn statistics(self) -> Option<CtoPoolStatistics>
+	{
+		debug_assert!(self.is_not_null(), "self can not be null");
+		
+		CtoPoolStatistics::parse(&capture_statistics(self, CtoPoolStatistics::statistics_print_options()))
+	}
+	
 	#[inline(always)]
 	fn close(self)
 	{
@@ -240,11 +254,13 @@ impl PMEMctopoolExt for *mut PMEMctopool
 	}
 	
 	#[inline(always)]
-	fn free<T>(self, pointer: *mut T)
+	fn free<T: ?Sized>(self, pointer: *mut T)
 	{
+		let pointer = pointer as *mut c_void;
+		
 		debug_assert!(self.is_not_null(), "self can not be null");
 		debug_assert!(pointer.is_not_null(), "pointer can not be null");
 		
-		unsafe { pmemcto_free(self, pointer as *mut _) }
+		unsafe { pmemcto_free(self, pointer) }
 	}
 }
@@ -5,7 +5,7 @@
 /// A thread-safe reference-counting pointer.
 /// 'Arc' stands for 'Atomically Reference Counted'.
 /// See Rust stdlib documentation.
-pub struct CtoArc<Value: CtoSafe>
+pub struct CtoArc<Value: ?Sized + CtoSafe>
 {
 	persistent_memory_pointer: NonNull<CtoArcInner<Value>>,
 }
@@ -35,7 +35,7 @@ impl<Value: CtoSafe> PersistentMemoryWrapper for CtoArc<Value>
 	}
 }
 
-impl<Value: CtoSafe> CtoSafe for CtoArc<Value>
+impl<Value: ?Sized + CtoSafe> CtoSafe for CtoArc<Value>
 {
 	#[inline(always)]
 	fn cto_pool_opened(&mut self, cto_pool_arc: &CtoPoolArc)
@@ -44,15 +44,15 @@ impl<Value: CtoSafe> CtoSafe for CtoArc<Value>
 	}
 }
 
-unsafe impl<Value: CtoSafe + Sync + Send> Send for CtoArc<Value>
+unsafe impl<Value: ?Sized + CtoSafe + Sync + Send> Send for CtoArc<Value>
 {
 }
 
-unsafe impl<Value: CtoSafe + Sync + Send> Sync for CtoArc<Value>
+unsafe impl<Value: ?Sized + CtoSafe + Sync + Send> Sync for CtoArc<Value>
 {
 }
 
-impl<Value: CtoSafe> Drop for CtoArc<Value>
+impl<Value: ?Sized + CtoSafe> Drop for CtoArc<Value>
 {
 	#[inline(always)]
 	fn drop(&mut self)
@@ -86,7 +86,7 @@ impl<Value: CtoSafe> Drop for CtoArc<Value>
 	}
 }
 
-impl<Value: CtoSafe + PartialEq> PartialEq for CtoArc<Value>
+impl<Value: ?Sized + CtoSafe + PartialEq> PartialEq for CtoArc<Value>
 {
 	#[inline(always)]
 	fn eq(&self, other: &Self) -> bool
@@ -101,7 +101,7 @@ impl<Value: CtoSafe + PartialEq> PartialEq for CtoArc<Value>
 	}
 }
 
-impl<Value: CtoSafe + PartialOrd> PartialOrd for CtoArc<Value>
+impl<Value: ?Sized + CtoSafe + PartialOrd> PartialOrd for CtoArc<Value>
 {
 	#[inline(always)]
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
@@ -134,7 +134,7 @@ impl<Value: CtoSafe + PartialOrd> PartialOrd for CtoArc<Value>
 	}
 }
 
-impl<Value: CtoSafe + Ord> Ord for CtoArc<Value>
+impl<Value: ?Sized + CtoSafe + Ord> Ord for CtoArc<Value>
 {
 	#[inline(always)]
 	fn cmp(&self, other: &Self) -> Ordering
@@ -143,11 +143,11 @@ impl<Value: CtoSafe + Ord> Ord for CtoArc<Value>
 	}
 }
 
-impl<Value: CtoSafe + Eq> Eq for CtoArc<Value>
+impl<Value: ?Sized + CtoSafe + Eq> Eq for CtoArc<Value>
 {
 }
 
-impl<Value: CtoSafe + Hash> Hash for CtoArc<Value>
+impl<Value: ?Sized + CtoSafe + Hash> Hash for CtoArc<Value>
 {
 	#[inline(always)]
 	fn hash<H: Hasher>(&self, state: &mut H)
@@ -156,7 +156,7 @@ impl<Value: CtoSafe + Hash> Hash for CtoArc<Value>
 	}
 }
 
-impl<Value: CtoSafe + Display> Display for CtoArc<Value>
+impl<Value: ?Sized + CtoSafe + Display> Display for CtoArc<Value>
 {
 	#[inline(always)]
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result
@@ -165,7 +165,7 @@ impl<Value: CtoSafe + Display> Display for CtoArc<Value>
 	}
 }
 
-impl<Value: CtoSafe + Debug> Debug for CtoArc<Value>
+impl<Value: ?Sized + CtoSafe + Debug> Debug for CtoArc<Value>
 {
 	#[inline(always)]
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result
@@ -174,7 +174,7 @@ impl<Value: CtoSafe + Debug> Debug for CtoArc<Value>
 	}
 }
 
-impl<Value: CtoSafe> Pointer for CtoArc<Value>
+impl<Value: ?Sized + CtoSafe> Pointer for CtoArc<Value>
 {
 	#[inline(always)]
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result
@@ -183,7 +183,7 @@ impl<Value: CtoSafe> Pointer for CtoArc<Value>
 	}
 }
 
-impl<Value: CtoSafe> Deref for CtoArc<Value>
+impl<Value: ?Sized + CtoSafe> Deref for CtoArc<Value>
 {
 	type Target = Value;
 	
@@ -194,7 +194,7 @@ impl<Value: CtoSafe> Deref for CtoArc<Value>
 	}
 }
 
-impl<Value: CtoSafe> Borrow<Value> for CtoArc<Value>
+impl<Value: ?Sized + CtoSafe> Borrow<Value> for CtoArc<Value>
 {
 	#[inline(always)]
 	fn borrow(&self) -> &Value
@@ -203,7 +203,7 @@ impl<Value: CtoSafe> Borrow<Value> for CtoArc<Value>
 	}
 }
 
-impl<Value: CtoSafe> AsRef<Value> for CtoArc<Value>
+impl<Value: ?Sized + CtoSafe> AsRef<Value> for CtoArc<Value>
 {
 	#[inline(always)]
 	fn as_ref(&self) -> &Value
@@ -212,7 +212,7 @@ impl<Value: CtoSafe> AsRef<Value> for CtoArc<Value>
 	}
 }
 
-impl<Value: CtoSafe> Clone for CtoArc<Value>
+impl<Value: ?Sized + CtoSafe> Clone for CtoArc<Value>
 {
 	#[inline(always)]
 	fn clone(&self) -> Self
@@ -254,25 +254,8 @@ impl<Value: CtoSafe + Clone> CtoArc<Value>
 	}
 }
 
-impl<Value: CtoSafe> CtoArc<Value>
+impl<Value: ?Sized + CtoSafe> CtoArc<Value>
 {
-	/// Produces a clone of the data, customized.
-	#[inline(always)]
-	pub(crate) fn deep_clone_customized<CallbackError, DeepCloneCallback: FnOnce(*mut Value, &CtoPoolArc, &Value) -> Result<(), CallbackError>>(&self, deep_clone_initializer: DeepCloneCallback) -> Result<Self, CallbackError>
-	{
-		let cto_arc_inner = self.persistent_memory();
-		let cto_pool_arc = &cto_arc_inner.cto_pool_arc;
-		let persistent_memory_pointer = cto_pool_arc.pool_pointer().aligned_allocate().unwrap();
-		
-		unsafe
-		{
-			Self::initialize_persistent_memory(persistent_memory_pointer, cto_pool_arc, |value_mut_pointer, cto_pool_arc|
-			{
-				deep_clone_initializer(value_mut_pointer, cto_pool_arc, &cto_arc_inner.value)
-			})
-		}
-	}
-	
 	/// A pointer to use with C. Use wisely; dropping this object may cause the pointer to go out of scope.
 	#[inline(always)]
 	pub fn as_ptr(this: &Self) -> *const Value
@@ -280,25 +263,6 @@ impl<Value: CtoSafe> CtoArc<Value>
 		this.deref() as *const Value
 	}
 	
-	/// Gets a raw pointer to Value, suitable for use with FFI.
-	/// Must be eventually passed to `from_raw()`, or a very serious (possibly irrecoverable even with reboots) memory leak will occur.
-	#[inline(always)]
-	pub fn into_raw(mut this: Self) -> *mut Value
-	{
-		this.persistent_memory_mut().into_raw_value_pointer()
-	}
-	
-	/// Gets a CtoRc from a raw pointer to Value, typically passed back from FFI.
-	/// Must be a pointer originally created using `into_raw()`.
-	#[inline(always)]
-	pub unsafe fn from_raw(raw_value_pointer: *mut Value) -> Self
-	{
-		Self
-		{
-			persistent_memory_pointer: NonNull::new_unchecked(CtoArcInner::from_raw_value_pointer(raw_value_pointer)),
-		}
-	}
-	
 	/// Creates a new [`WeakCtoArc`][weak] pointer to this value.
 	///
 	/// [weak]: struct.WeakCtoArc.html
@@ -459,3 +423,119 @@ impl<Value: CtoSafe> CtoArc<Value>
 		self.persistent_memory_pointer.as_ptr()
 	}
 }
+
+impl<Value: CtoSafe> CtoArc<Value>
+{
+	/// Moves an already initialized value into a new CtoArc allocated in the persistent memory pool.
+	/// Do not use Heap-allocated objects for fields of Value, ie only use CtoSafe fields.
+	#[inline(always)]
+	pub fn from_value(value: Value, cto_pool_arc: &CtoPoolArc) -> Result<Self, PmdkError>
+	{
+		let persistent_memory_pointer = cto_pool_arc.pool_pointer().aligned_allocate()?;
+		
+		let cto_arc = unsafe
+		{
+			Self::initialize_persistent_memory::<(), _>(persistent_memory_pointer, cto_pool_arc, |value_mut_pointer, _cto_pool_arc|
+			{
+				write(value_mut_pointer, value);
+				Ok(())
+			}).unwrap()
+		};
+		
+		Ok(cto_arc)
+	}
+	
+	/// Produces a clone of the data, customized.
+	#[inline(always)]
+	pub(crate) fn deep_clone_customized<CallbackError, DeepCloneCallback: FnOnce(*mut Value, &CtoPoolArc, &Value) -> Result<(), CallbackError>>(&self, deep_clone_initializer: DeepCloneCallback) -> Result<Self, CallbackError>
+	{
+		let cto_arc_inner = self.persistent_memory();
+		let cto_pool_arc = &cto_arc_inner.cto_pool_arc;
+		let persistent_memory_pointer = cto_pool_arc.pool_pointer().aligned_allocate().unwrap();
+		
+		unsafe
+		{
+			Self::initialize_persistent_memory(persistent_memory_pointer, cto_pool_arc, |value_mut_pointer, cto_pool_arc|
+			{
+				deep_clone_initializer(value_mut_pointer, cto_pool_arc, &cto_arc_inner.value)
+			})
+		}
+	}
+	
+	/// Gets a raw pointer to Value, suitable for use with FFI.
+	/// Must be eventually passed to `from_raw()`, or a very serious (possibly irrecoverable even with reboots) memory leak will occur.
+	#[inline(always)]
+	pub fn into_raw(mut this: Self) -> *mut Value
+	{
+		this.persistent_memory_mut().into_raw_value_pointer()
+	}
+	
+	/// Gets a CtoRc from a raw pointer to Value, typically passed back from FFI.
+	/// Must be a pointer originally created using `into_raw()`.
+	#[inline(always)]
+	pub unsafe fn from_raw(raw_value_pointer: *mut Value) -> Self
+	{
+		Self
+		{
+			persistent_memory_pointer: NonNull::new_unchecked(CtoArcInner::from_raw_value_pointer(raw_value_pointer)),
+		}
+	}
+}
+
+impl<Element: CtoSafe + Clone> CtoArc<[Element]>
+{
+	/// Clones the elements of a slice into a new CtoArc allocated in the persistent memory pool.
+	#[inline(always)]
+	pub fn from_slice(slice: &[Element], cto_pool_arc: &CtoPoolArc) -> Result<Self, PmdkError>
+	{
+		Self::from_exact_size_iterator(slice.iter().cloned(), cto_pool_arc)
+	}
+}
+
+impl<Element: CtoSafe> CtoArc<[Element]>
+{
+	/// Moves the elements produced by an iterator into a new CtoArc allocated in the persistent memory pool.
+	/// The elements are first collected using the system allocator, as the length needs to be known before allocating.
+	#[inline(always)]
+	pub fn from_iterator<I: IntoIterator<Item=Element>>(iterable: I, cto_pool_arc: &CtoPoolArc) -> Result<Self, PmdkError>
+	{
+		let elements: Vec<Element> = iterable.into_iter().collect();
+		Self::from_exact_size_iterator(elements.into_iter(), cto_pool_arc)
+	}
+	
+	#[inline(always)]
+	fn from_exact_size_iterator<Elements: ExactSizeIterator<Item=Element>>(elements: Elements, cto_pool_arc: &CtoPoolArc) -> Result<Self, PmdkError>
+	{
+		let persistent_memory_pointer = CtoArcInner::allocate_slice(elements, cto_pool_arc)?;
+		
+		Ok
+		(
+			Self
+			{
+				persistent_memory_pointer: unsafe { NonNull::new_unchecked(persistent_memory_pointer) },
+			}
+		)
+	}
+}
+
+impl CtoArc<str>
+{
+	/// Copies a string slice into a new CtoArc allocated in the persistent memory pool.
+	#[inline(always)]
+	pub fn from_str(value: &str, cto_pool_arc: &CtoPoolArc) -> Result<Self, PmdkError>
+	{
+		let bytes = CtoArc::<[u8]>::from_slice(value.as_bytes(), cto_pool_arc)?;
+		
+		// Works because `str` and `[u8]` have the same layout and `value` was valid UTF-8.
+		let persistent_memory_pointer = bytes.persistent_memory_pointer.as_ptr() as *mut CtoArcInner<str>;
+		forget(bytes);
+		
+		Ok
+		(
+			Self
+			{
+				persistent_memory_pointer: unsafe { NonNull::new_unchecked(persistent_memory_pointer) },
+			}
+		)
+	}
+}
@@ -2,26 +2,26 @@
 // Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
 
 
-// #[repr(C)] is required otherwise `from_raw_value_pointer()` will be very broken indeed.
+// #[repr(C)] is required otherwise `from_raw_value_pointer()` and `allocate_slice()` will be very broken indeed.
 #[repr(C)]
-pub(crate) struct CtoArcInner<Value: CtoSafe>
+pub(crate) struct CtoArcInner<Value: ?Sized + CtoSafe>
 {
-	// Field order matters. `value: Value` must be first otherwise `from_raw_value_pointer()` will be very broken indeed.
-	value: Value,
+	// Field order matters. `value: Value` must be last so that it can be unsized; `from_raw_value_pointer()` and `allocate_slice()` rely on this. Changing it requires incrementing `CtoPointerLayoutVersion`.
 	strong_counter: AtomicUsize,
 	weak_counter: AtomicUsize,
 	cto_pool_arc: CtoPoolArc,
+	value: Value,
 }
 
-unsafe impl<Value: CtoSafe + Sync + Send> Send for CtoArcInner<Value>
+unsafe impl<Value: ?Sized + CtoSafe + Sync + Send> Send for CtoArcInner<Value>
 {
 }
 
-unsafe impl<Value: CtoSafe + Sync + Send> Sync for CtoArcInner<Value>
+unsafe impl<Value: ?Sized + CtoSafe + Sync + Send> Sync for CtoArcInner<Value>
 {
 }
 
-impl<Value: CtoSafe> Deref for CtoArcInner<Value>
+impl<Value: ?Sized + CtoSafe> Deref for CtoArcInner<Value>
 {
 	type Target = Value;
 	
@@ -32,7 +32,7 @@ impl<Value: CtoSafe> Deref for CtoArcInner<Value>
 	}
 }
 
-impl<Value: CtoSafe> DerefMut for CtoArcInner<Value>
+impl<Value: ?Sized + CtoSafe> DerefMut for CtoArcInner<Value>
 {
 	#[inline(always)]
 	fn deref_mut(&mut self) -> &mut Self::Target
@@ -41,7 +41,7 @@ impl<Value: CtoSafe> DerefMut for CtoArcInner<Value>
 	}
 }
 
-impl<Value: CtoSafe> CtoArcInner<Value>
+impl<Value: ?Sized + CtoSafe> CtoArcInner<Value>
 {
 	#[inline(always)]
 	fn common_initialization(&mut self, cto_pool_arc: &CtoPoolArc)
@@ -50,7 +50,7 @@ impl<Value: CtoSafe> CtoArcInner<Value>
 	}
 	
 	#[inline(always)]
-	fn allocated<InitializationError, Initializer: FnOnce(*mut Value, &CtoPoolArc) -> Result<(), InitializationError>>(&mut self, cto_pool_arc: &CtoPoolArc, initializer: Initializer) -> Result<(), InitializationError>
+	fn header_initialization(&mut self, cto_pool_arc: &CtoPoolArc)
 	{
 		unsafe { write(&mut self.strong_counter, AtomicUsize::new(1)) };
 		
@@ -58,8 +58,6 @@ impl<Value: CtoSafe> CtoArcInner<Value>
 		unsafe { write(&mut self.weak_counter, AtomicUsize::new(Self::WeakCountJustBeforeLock)) };
 		
 		self.common_initialization(cto_pool_arc);
-		
-		initializer(&mut self.value, cto_pool_arc)
 	}
 	
 	#[inline(always)]
@@ -70,19 +68,6 @@ impl<Value: CtoSafe> CtoArcInner<Value>
 		self.value.cto_pool_opened(cto_pool_arc)
 	}
 	
-	#[inline(always)]
-	fn into_raw_value_pointer(&mut self) -> *mut Value
-	{
-		self.deref_mut()
-	}
-	
-	#[inline(always)]
-	fn from_raw_value_pointer(raw_value_pointer: *mut Value) -> *mut Self
-	{
-		// Works because Value is the first field and we use #[repr(C)]
-		raw_value_pointer as *mut Self
-	}
-	
 	// A soft limit on the amount of references that may be made to a `CtoArc`.
 	// Going above this limit will abort your program (although not necessarily) at _exactly_ `MaximumNumberOfReferences + 1` references.
 	// `MaximumNumberOfReferences` is less than `WeakCounterLockSentinel` to allow `WeakCounterLockSentinel` to be used as a lock sentinel.
@@ -195,3 +180,47 @@ impl<Value: CtoSafe> CtoArcInner<Value>
 		self.strong_counter.fetch_sub(1, Release)
 	}
 }
+
+impl<Value: CtoSafe> CtoArcInner<Value>
+{
+	#[inline(always)]
+	fn allocated<InitializationError, Initializer: FnOnce(*mut Value, &CtoPoolArc) -> Result<(), InitializationError>>(&mut self, cto_pool_arc: &CtoPoolArc, initializer: Initializer) -> Result<(), InitializationError>
+	{
+		self.header_initialization(cto_pool_arc);
+		
+		initializer(&mut self.value, cto_pool_arc)
+	}
+	
+	#[inline(always)]
+	fn into_raw_value_pointer(&mut self) -> *mut Value
+	{
+		self.deref_mut()
+	}
+	
+	#[inline(always)]
+	fn from_raw_value_pointer(raw_value_pointer: *mut Value) -> *mut Self
+	{
+		// Works because Value is the last field and we use #[repr(C)]
+		let (_layout, value_offset) = Layout::new::<CtoArcInner<()>>().extend(Layout::new::<Value>()).unwrap();
+		
+		unsafe { (raw_value_pointer as *mut u8).offset(-(value_offset as isize)) as *mut Self }
+	}
+}
+
+impl<Element: CtoSafe> CtoArcInner<[Element]>
+{
+	#[inline(always)]
+	fn allocate_slice<Elements: ExactSizeIterator<Item=Element>>(elements: Elements, cto_pool_arc: &CtoPoolArc) -> Result<*mut Self, PmdkError>
+	{
+		let length = elements.len();
+		
+		let allocation_pointer = cto_pool_arc.pool_pointer().aligned_allocate_with_trailing_slice::<CtoArcInner<()>, Element, Elements>(elements)?;
+		
+		// Works because `[Element]` is the last field and we use #[repr(C)]; the cast keeps the slice's length as the pointer's metadata.
+		let persistent_memory_pointer = from_raw_parts_mut(allocation_pointer, length) as *mut [Element] as *mut Self;
+		
+		unsafe { (*persistent_memory_pointer).header_initialization(cto_pool_arc) };
+		
+		Ok(persistent_memory_pointer)
+	}
+}
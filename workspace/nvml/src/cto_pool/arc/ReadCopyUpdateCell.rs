@@ -0,0 +1,250 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// A lock-free, epoch-based read-copy-update cell holding a `CtoArc<Value>`.
+///
+/// Readers never block and never touch the reference count of the current version.
+/// Writers produce a new version from the current one and install it with a compare-and-swap, retrying if another writer won the race; there is no writer mutex.
+/// Replaced versions are retired to a list in persistent memory and dropped once no reader can still be referencing them.
+///
+/// The only persistent state is the pointer to the current version and the list of retired versions.
+/// A crash part way through an update therefore leaves exactly one version reachable; any retired versions are reclaimed when the pool is next opened.
+pub struct ReadCopyUpdateCell<Value: CtoSafe>
+{
+	current: AtomicPtr<Value>,
+	retired: AtomicPtr<RetiredCtoArc<Value>>,
+	epochs: Box<ReadCopyUpdateEpochs>,
+	cto_pool_arc: CtoPoolArc,
+}
+
+impl<Value: CtoSafe> Drop for ReadCopyUpdateCell<Value>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		drop(unsafe { CtoArc::from_raw(*self.current.get_mut()) });
+		
+		self.reclaim_all_retired(|_retired_cto_arc| {});
+	}
+}
+
+impl<Value: CtoSafe> CtoSafe for ReadCopyUpdateCell<Value>
+{
+	#[inline(always)]
+	fn cto_pool_opened(&mut self, cto_pool_arc: &CtoPoolArc)
+	{
+		unsafe { write(&mut self.epochs, ReadCopyUpdateEpochs::new()) }
+		
+		cto_pool_arc.write(&mut self.cto_pool_arc);
+		
+		let mut current = unsafe { CtoArc::from_raw(*self.current.get_mut()) };
+		current.cto_pool_opened(cto_pool_arc);
+		forget(current);
+		
+		// No reader can exist before the pool has been opened, so every retired version can be reclaimed now.
+		self.reclaim_all_retired(|retired_cto_arc| retired_cto_arc.cto_pool_opened(cto_pool_arc));
+	}
+}
+
+unsafe impl<Value: CtoSafe + Sync + Send> Send for ReadCopyUpdateCell<Value>
+{
+}
+
+unsafe impl<Value: CtoSafe + Sync + Send> Sync for ReadCopyUpdateCell<Value>
+{
+}
+
+impl<Value: CtoSafe> Debug for ReadCopyUpdateCell<Value>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.pad("ReadCopyUpdateCell { .. }")
+	}
+}
+
+impl<Value: CtoSafe> ReadCopyUpdateCell<Value>
+{
+	/// Creates a new `ReadCopyUpdateCell` with `cto_arc` as the current version.
+	/// `cto_pool_arc` is used to allocate new versions and retirement records.
+	#[inline(always)]
+	pub fn new(cto_arc: CtoArc<Value>, cto_pool_arc: &CtoPoolArc) -> Self
+	{
+		Self
+		{
+			current: AtomicPtr::new(CtoArc::into_raw(cto_arc)),
+			retired: AtomicPtr::new(null_mut()),
+			epochs: ReadCopyUpdateEpochs::new(),
+			cto_pool_arc: cto_pool_arc.clone(),
+		}
+	}
+	
+	/// Reads the current version.
+	/// Never blocks, but the version read is not reclaimed until the returned guard is dropped.
+	#[inline(always)]
+	pub fn read(&self) -> ReadCopyUpdateCellReadGuard<Value>
+	{
+		let participant = self.epochs.pin();
+		let value = unsafe { &*self.current.load(Acquire) };
+		
+		ReadCopyUpdateCellReadGuard
+		{
+			participant,
+			value,
+		}
+	}
+	
+	/// Returns a cheap copy of the current version, which can outlive any subsequent updates.
+	#[inline(always)]
+	pub fn get(&self) -> CtoArc<Value>
+	{
+		let guard = self.read();
+		
+		let cto_arc = unsafe { CtoArc::from_raw(guard.raw_value_pointer()) };
+		let out = cto_arc.clone();
+		forget(cto_arc);
+		out
+	}
+	
+	/// Replaces the current version with `updater(current)`.
+	///
+	/// If another writer installs a version first, `updater` is called again with that version; it may therefore be called more than once.
+	/// Returns an error if the new version could not be allocated.
+	#[inline(always)]
+	pub fn update<Updater: FnMut(&Value) -> Value>(&self, mut updater: Updater) -> Result<(), PmdkError>
+	{
+		loop
+		{
+			let guard = self.read();
+			
+			let new_cto_arc = CtoArc::from_value(updater(&*guard), &self.cto_pool_arc)?;
+			
+			let current = guard.raw_value_pointer();
+			let new = CtoArc::as_ptr(&new_cto_arc) as *mut Value;
+			if self.current.compare_exchange(current, new, AcqRel, Acquire).is_ok()
+			{
+				forget(new_cto_arc);
+				drop(guard);
+				self.retire(unsafe { CtoArc::from_raw(current) });
+				return Ok(())
+			}
+		}
+	}
+	
+	/// Replaces the current version with `cto_arc`, retiring the previous version.
+	#[inline(always)]
+	pub fn set(&self, cto_arc: CtoArc<Value>)
+	{
+		let previous = self.current.swap(CtoArc::into_raw(cto_arc), AcqRel);
+		self.retire(unsafe { CtoArc::from_raw(previous) });
+	}
+	
+	#[inline(always)]
+	fn retire(&self, cto_arc: CtoArc<Value>)
+	{
+		match self.cto_pool_arc.pool_pointer().aligned_allocate::<RetiredCtoArc<Value>>()
+		{
+			Ok(retired) =>
+			{
+				unsafe
+				{
+					write(retired, RetiredCtoArc
+					{
+						next: null_mut(),
+						retired_in_epoch: self.epochs.current_epoch(),
+						cto_arc,
+					})
+				}
+				self.push_retired(retired, retired);
+			}
+			
+			// The pool is exhausted, so wait until no reader can still be referencing the retired version and drop it immediately.
+			// This will spin forever if the calling thread itself holds a `ReadCopyUpdateCellReadGuard` for this cell.
+			Err(_) =>
+			{
+				self.synchronize();
+				drop(cto_arc);
+			}
+		}
+		
+		self.reclaim_retired();
+	}
+	
+	#[inline(always)]
+	fn synchronize(&self)
+	{
+		let retired_in_epoch = self.epochs.current_epoch();
+		while !ReadCopyUpdateEpochs::is_reclaimable(retired_in_epoch, self.epochs.try_to_advance())
+		{
+			spin_loop_hint();
+		}
+	}
+	
+	#[inline(always)]
+	fn push_retired(&self, head: *mut RetiredCtoArc<Value>, tail: *mut RetiredCtoArc<Value>)
+	{
+		let mut retired = self.retired.load(Relaxed);
+		loop
+		{
+			unsafe { (*tail).next = retired };
+			match self.retired.compare_exchange_weak(retired, head, Release, Relaxed)
+			{
+				Ok(_) => return,
+				Err(new_retired) => retired = new_retired,
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn reclaim_retired(&self)
+	{
+		let global_epoch = self.epochs.try_to_advance();
+		
+		let mut survivors_head: *mut RetiredCtoArc<Value> = null_mut();
+		let mut survivors_tail: *mut RetiredCtoArc<Value> = null_mut();
+		
+		let mut retired = self.retired.swap(null_mut(), Acquire);
+		while retired.is_not_null()
+		{
+			let next = unsafe { (*retired).next };
+			
+			if ReadCopyUpdateEpochs::is_reclaimable(unsafe { (*retired).retired_in_epoch }, global_epoch)
+			{
+				unsafe { RetiredCtoArc::reclaim(retired, &self.cto_pool_arc) }
+			}
+			else
+			{
+				unsafe { (*retired).next = survivors_head };
+				if survivors_tail.is_null()
+				{
+					survivors_tail = retired;
+				}
+				survivors_head = retired;
+			}
+			
+			retired = next;
+		}
+		
+		if survivors_head.is_not_null()
+		{
+			self.push_retired(survivors_head, survivors_tail);
+		}
+	}
+	
+	#[inline(always)]
+	fn reclaim_all_retired<BeforeReclaim: Fn(&mut CtoArc<Value>)>(&mut self, before_reclaim: BeforeReclaim)
+	{
+		let mut retired = *self.retired.get_mut();
+		*self.retired.get_mut() = null_mut();
+		
+		while retired.is_not_null()
+		{
+			let next = unsafe { (*retired).next };
+			
+			before_reclaim(unsafe { &mut (*retired).cto_arc });
+			unsafe { RetiredCtoArc::reclaim(retired, &self.cto_pool_arc) }
+			
+			retired = next;
+		}
+	}
+}
@@ -0,0 +1,49 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// A guard that keeps the version of a `ReadCopyUpdateCell` it was created from alive.
+/// Whilst any guard is held, retired versions can not be reclaimed, so guards should be short-lived.
+pub struct ReadCopyUpdateCellReadGuard<'cell, Value: 'cell + CtoSafe>
+{
+	participant: &'cell ReadCopyUpdateEpochParticipant,
+	value: &'cell Value,
+}
+
+impl<'cell, Value: CtoSafe> Drop for ReadCopyUpdateCellReadGuard<'cell, Value>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		self.participant.unpin_and_release()
+	}
+}
+
+impl<'cell, Value: CtoSafe> Deref for ReadCopyUpdateCellReadGuard<'cell, Value>
+{
+	type Target = Value;
+	
+	#[inline(always)]
+	fn deref(&self) -> &Value
+	{
+		self.value
+	}
+}
+
+impl<'cell, Value: CtoSafe + Debug> Debug for ReadCopyUpdateCellReadGuard<'cell, Value>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_struct("ReadCopyUpdateCellReadGuard").field("value", self.value).finish()
+	}
+}
+
+impl<'cell, Value: CtoSafe> ReadCopyUpdateCellReadGuard<'cell, Value>
+{
+	#[inline(always)]
+	fn raw_value_pointer(&self) -> *mut Value
+	{
+		self.value as *const Value as *mut Value
+	}
+}
@@ -0,0 +1,70 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// A volatile record of a reader (or writer) that may be holding a pointer to a version in a `ReadCopyUpdateCell`.
+/// Records are never freed whilst their `ReadCopyUpdateEpochs` lives; instead they are re-used by later readers.
+struct ReadCopyUpdateEpochParticipant
+{
+	next: *mut ReadCopyUpdateEpochParticipant,
+	in_use: AtomicBool,
+	pinned_epoch: AtomicUsize,
+}
+
+impl ReadCopyUpdateEpochParticipant
+{
+	const NotPinned: usize = 0;
+	
+	#[inline(always)]
+	fn new_in_use() -> Box<Self>
+	{
+		Box::new
+		(
+			Self
+			{
+				next: null_mut(),
+				in_use: AtomicBool::new(true),
+				pinned_epoch: AtomicUsize::new(Self::NotPinned),
+			}
+		)
+	}
+	
+	#[inline(always)]
+	fn try_to_acquire(&self) -> bool
+	{
+		!self.in_use.load(Relaxed) && self.in_use.compare_exchange(false, true, Acquire, Relaxed).is_ok()
+	}
+	
+	#[inline(always)]
+	fn pin(&self, global_epoch: &AtomicUsize)
+	{
+		let epoch = global_epoch.load(Relaxed);
+		self.pinned_epoch.store(Self::encode(epoch), Relaxed);
+		
+		// Makes the pinned epoch visible before any subsequent load of the cell's current version.
+		fence(SeqCst);
+	}
+	
+	#[inline(always)]
+	fn unpin_and_release(&self)
+	{
+		self.pinned_epoch.store(Self::NotPinned, Release);
+		self.in_use.store(false, Release);
+	}
+	
+	#[inline(always)]
+	fn is_pinned_in_an_epoch_other_than(&self, epoch: usize) -> bool
+	{
+		match self.pinned_epoch.load(Relaxed)
+		{
+			Self::NotPinned => false,
+			pinned_epoch => pinned_epoch != Self::encode(epoch),
+		}
+	}
+	
+	#[inline(always)]
+	fn encode(epoch: usize) -> usize
+	{
+		(epoch << 1) | 1
+	}
+}
@@ -0,0 +1,119 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// Volatile epoch state used to defer the reclamation of retired versions held by a `ReadCopyUpdateCell`.
+/// None of this state is meaningful after a CTO pool is re-opened, so it lives on the ordinary heap and is re-created by `cto_pool_opened()`.
+struct ReadCopyUpdateEpochs
+{
+	global_epoch: AtomicUsize,
+	participants: AtomicPtr<ReadCopyUpdateEpochParticipant>,
+}
+
+impl Drop for ReadCopyUpdateEpochs
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		let mut participant = *self.participants.get_mut();
+		while participant.is_not_null()
+		{
+			let boxed_participant = unsafe { Box::from_raw(participant) };
+			participant = boxed_participant.next;
+		}
+	}
+}
+
+impl ReadCopyUpdateEpochs
+{
+	/// A version retired in epoch `E` can not be referenced by any pinned participant once the global epoch has reached `E + 2`.
+	const EpochsBeforeReclamation: usize = 2;
+	
+	#[inline(always)]
+	fn new() -> Box<Self>
+	{
+		Box::new
+		(
+			Self
+			{
+				global_epoch: AtomicUsize::new(0),
+				participants: AtomicPtr::new(null_mut()),
+			}
+		)
+	}
+	
+	#[inline(always)]
+	fn pin(&self) -> &ReadCopyUpdateEpochParticipant
+	{
+		let participant = self.acquire_participant();
+		participant.pin(&self.global_epoch);
+		participant
+	}
+	
+	#[inline(always)]
+	fn current_epoch(&self) -> usize
+	{
+		self.global_epoch.load(SeqCst)
+	}
+	
+	#[inline(always)]
+	fn is_reclaimable(retired_in_epoch: usize, global_epoch: usize) -> bool
+	{
+		retired_in_epoch.saturating_add(Self::EpochsBeforeReclamation) <= global_epoch
+	}
+	
+	/// Advances the global epoch if every pinned participant has observed the current global epoch.
+	/// Returns the global epoch after the attempt.
+	#[inline(always)]
+	fn try_to_advance(&self) -> usize
+	{
+		let epoch = self.global_epoch.load(Relaxed);
+		fence(SeqCst);
+		
+		let mut participant = self.participants.load(Acquire);
+		while participant.is_not_null()
+		{
+			let participant_reference = unsafe { &*participant };
+			if participant_reference.is_pinned_in_an_epoch_other_than(epoch)
+			{
+				return epoch;
+			}
+			participant = participant_reference.next;
+		}
+		fence(Acquire);
+		
+		let next_epoch = epoch + 1;
+		match self.global_epoch.compare_exchange(epoch, next_epoch, Release, Relaxed)
+		{
+			Ok(_) => next_epoch,
+			Err(epoch_advanced_by_another_thread) => epoch_advanced_by_another_thread,
+		}
+	}
+	
+	#[inline(always)]
+	fn acquire_participant(&self) -> &ReadCopyUpdateEpochParticipant
+	{
+		let mut participant = self.participants.load(Acquire);
+		while participant.is_not_null()
+		{
+			let participant_reference = unsafe { &*participant };
+			if participant_reference.try_to_acquire()
+			{
+				return participant_reference;
+			}
+			participant = participant_reference.next;
+		}
+		
+		let new_participant = Box::into_raw(ReadCopyUpdateEpochParticipant::new_in_use());
+		let mut head = self.participants.load(Relaxed);
+		loop
+		{
+			unsafe { (*new_participant).next = head };
+			match self.participants.compare_exchange_weak(head, new_participant, Release, Relaxed)
+			{
+				Ok(_) => return unsafe { &*new_participant },
+				Err(new_head) => head = new_head,
+			}
+		}
+	}
+}
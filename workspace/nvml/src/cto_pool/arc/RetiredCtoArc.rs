@@ -0,0 +1,23 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// A version of a `ReadCopyUpdateCell` that has been replaced but may still be referenced by a reader.
+/// Lives in persistent memory so that, after a pool is re-opened, retired versions can be reclaimed rather than leaked.
+struct RetiredCtoArc<Value: CtoSafe>
+{
+	next: *mut RetiredCtoArc<Value>,
+	retired_in_epoch: usize,
+	cto_arc: CtoArc<Value>,
+}
+
+impl<Value: CtoSafe> RetiredCtoArc<Value>
+{
+	/// Drops the retired version and frees this node.
+	#[inline(always)]
+	unsafe fn reclaim(this: *mut Self, cto_pool_arc: &CtoPoolArc)
+	{
+		drop_in_place(&mut (*this).cto_arc);
+		cto_pool_arc.pool_pointer().free(this);
+	}
+}
@@ -16,20 +16,20 @@
 /// [`CtoArc`]: struct.Arc.html
 /// [`CtoArc::downgrade`]: struct.Arc.html#method.downgrade
 /// [`upgrade`]: struct.Weak.html#method.upgrade
-pub struct WeakCtoArc<Value: CtoSafe>
+pub struct WeakCtoArc<Value: ?Sized + CtoSafe>
 {
 	persistent_memory_pointer: Option<NonNull<CtoArcInner<Value>>>,
 }
 
-unsafe impl<Value: CtoSafe + Sync + Send> Send for WeakCtoArc<Value>
+unsafe impl<Value: ?Sized + CtoSafe + Sync + Send> Send for WeakCtoArc<Value>
 {
 }
 
-unsafe impl<Value: CtoSafe + Sync + Send> Sync for WeakCtoArc<Value>
+unsafe impl<Value: ?Sized + CtoSafe + Sync + Send> Sync for WeakCtoArc<Value>
 {
 }
 
-impl<Value: CtoSafe + Debug> Debug for WeakCtoArc<Value>
+impl<Value: ?Sized + CtoSafe + Debug> Debug for WeakCtoArc<Value>
 {
 	#[inline(always)]
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result
@@ -38,7 +38,7 @@ impl<Value: CtoSafe + Debug> Debug for WeakCtoArc<Value>
 	}
 }
 
-impl<Value: CtoSafe> Clone for WeakCtoArc<Value>
+impl<Value: ?Sized + CtoSafe> Clone for WeakCtoArc<Value>
 {
 	/// Makes a clone of the `WeakCtoArc` pointer that points to the same value.
 	#[inline(always)]
@@ -71,7 +71,7 @@ impl<Value: CtoSafe> Clone for WeakCtoArc<Value>
 	}
 }
 
-impl<Value: CtoSafe> Drop for WeakCtoArc<Value>
+impl<Value: ?Sized + CtoSafe> Drop for WeakCtoArc<Value>
 {
 	#[inline(always)]
 	fn drop(&mut self)
@@ -98,7 +98,7 @@ impl<Value: CtoSafe> Drop for WeakCtoArc<Value>
 	}
 }
 
-impl<Value: CtoSafe> Default for WeakCtoArc<Value>
+impl<Value: ?Sized + CtoSafe> Default for WeakCtoArc<Value>
 {
 	/// Constructs a new `WeakCtoArc<Value>`.
 	/// Calling [`upgrade`] on the return value always gives `None`.
@@ -111,7 +111,7 @@ impl<Value: CtoSafe> Default for WeakCtoArc<Value>
 	}
 }
 
-impl<Value: CtoSafe> WeakCtoArc<Value>
+impl<Value: ?Sized + CtoSafe> WeakCtoArc<Value>
 {
 	/// Constructs a new `WeakCtoArc<Value>`.
 	/// Calling [`upgrade`] on the return value always gives `None`.
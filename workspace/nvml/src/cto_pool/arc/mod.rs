@@ -9,8 +9,11 @@ use ::std::marker::PhantomData;
 use ::std::mem::forget;
 use ::std::mem::transmute;
 use ::std::process::abort;
+use ::std::ptr::null_mut;
+use ::std::sync::atomic::AtomicPtr;
 use ::std::sync::atomic::AtomicUsize;
 use ::std::sync::atomic::fence;
+use ::std::sync::atomic::Ordering::AcqRel;
 use ::std::sync::atomic::Ordering::Acquire;
 use ::std::sync::atomic::Ordering::Relaxed;
 use ::std::sync::atomic::Ordering::Release;
@@ -22,5 +25,10 @@ include!("CtoArcCell.rs");
 include!("CtoArcInner.rs");
 include!("CtoStrongArc.rs");
 include!("CtoStrongArcInner.rs");
+include!("ReadCopyUpdateCell.rs");
+include!("ReadCopyUpdateCellReadGuard.rs");
+include!("ReadCopyUpdateEpochParticipant.rs");
+include!("ReadCopyUpdateEpochs.rs");
+include!("RetiredCtoArc.rs");
 include!("WeakCtoArc.rs");
 
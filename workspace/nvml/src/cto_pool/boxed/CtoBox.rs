@@ -3,7 +3,7 @@
 
 
 /// Identical in concept to a regular Rust Box but exists in a persistent object pool.
-pub struct CtoBox<Value: CtoSafe>
+pub struct CtoBox<Value: ?Sized + CtoSafe>
 {
 	persistent_memory_pointer: NonNull<CtoBoxInner<Value>>,
 }
@@ -33,7 +33,7 @@ impl<Value: CtoSafe> PersistentMemoryWrapper for CtoBox<Value>
 	}
 }
 
-impl<Value: CtoSafe> CtoSafe for CtoBox<Value>
+impl<Value: ?Sized + CtoSafe> CtoSafe for CtoBox<Value>
 {
 	#[inline(always)]
 	fn cto_pool_opened(&mut self, cto_pool_arc: &CtoPoolArc)
@@ -42,7 +42,7 @@ impl<Value: CtoSafe> CtoSafe for CtoBox<Value>
 	}
 }
 
-impl<Value: CtoSafe> Drop for CtoBox<Value>
+impl<Value: ?Sized + CtoSafe> Drop for CtoBox<Value>
 {
 	#[inline(always)]
 	fn drop(&mut self)
@@ -57,7 +57,7 @@ impl<Value: CtoSafe> Drop for CtoBox<Value>
 	}
 }
 
-impl<Value: CtoSafe + PartialEq> PartialEq for CtoBox<Value>
+impl<Value: ?Sized + CtoSafe + PartialEq> PartialEq for CtoBox<Value>
 {
 	#[inline(always)]
 	fn eq(&self, other: &Self) -> bool
@@ -72,11 +72,11 @@ impl<Value: CtoSafe + PartialEq> PartialEq for CtoBox<Value>
 	}
 }
 
-impl<Value: CtoSafe + Eq> Eq for CtoBox<Value>
+impl<Value: ?Sized + CtoSafe + Eq> Eq for CtoBox<Value>
 {
 }
 
-impl<Value: CtoSafe + PartialOrd> PartialOrd for CtoBox<Value>
+impl<Value: ?Sized + CtoSafe + PartialOrd> PartialOrd for CtoBox<Value>
 {
 	#[inline(always)]
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
@@ -109,7 +109,7 @@ impl<Value: CtoSafe + PartialOrd> PartialOrd for CtoBox<Value>
 	}
 }
 
-impl<Value: CtoSafe + Ord> Ord for CtoBox<Value>
+impl<Value: ?Sized + CtoSafe + Ord> Ord for CtoBox<Value>
 {
 	#[inline(always)]
 	fn cmp(&self, other: &Self) -> Ordering
@@ -118,7 +118,7 @@ impl<Value: CtoSafe + Ord> Ord for CtoBox<Value>
 	}
 }
 
-impl<Value: CtoSafe + Hash> Hash for CtoBox<Value>
+impl<Value: ?Sized + CtoSafe + Hash> Hash for CtoBox<Value>
 {
 	#[inline(always)]
 	fn hash<H: Hasher>(&self, state: &mut H)
@@ -127,7 +127,7 @@ impl<Value: CtoSafe + Hash> Hash for CtoBox<Value>
 	}
 }
 
-impl<Value: CtoSafe + Hasher> Hasher for CtoBox<Value>
+impl<Value: ?Sized + CtoSafe + Hasher> Hasher for CtoBox<Value>
 {
 	#[inline(always)]
 	fn finish(&self) -> u64
@@ -214,7 +214,7 @@ impl<Value: CtoSafe + Hasher> Hasher for CtoBox<Value>
 	}
 }
 
-impl<Value: CtoSafe + Display> Display for CtoBox<Value>
+impl<Value: ?Sized + CtoSafe + Display> Display for CtoBox<Value>
 {
 	#[inline(always)]
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result
@@ -223,7 +223,7 @@ impl<Value: CtoSafe + Display> Display for CtoBox<Value>
 	}
 }
 
-impl<Value: CtoSafe + Debug> Debug for CtoBox<Value>
+impl<Value: ?Sized + CtoSafe + Debug> Debug for CtoBox<Value>
 {
 	#[inline(always)]
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result
@@ -232,7 +232,7 @@ impl<Value: CtoSafe + Debug> Debug for CtoBox<Value>
 	}
 }
 
-impl<Value: CtoSafe> Pointer for CtoBox<Value>
+impl<Value: ?Sized + CtoSafe> Pointer for CtoBox<Value>
 {
 	#[inline(always)]
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result
@@ -241,7 +241,7 @@ impl<Value: CtoSafe> Pointer for CtoBox<Value>
 	}
 }
 
-impl<Value: CtoSafe> Deref for CtoBox<Value>
+impl<Value: ?Sized + CtoSafe> Deref for CtoBox<Value>
 {
 	type Target = Value;
 	
@@ -252,7 +252,7 @@ impl<Value: CtoSafe> Deref for CtoBox<Value>
 	}
 }
 
-impl<Value: CtoSafe> DerefMut for CtoBox<Value>
+impl<Value: ?Sized + CtoSafe> DerefMut for CtoBox<Value>
 {
 	#[inline(always)]
 	fn deref_mut(&mut self) -> &mut Self::Target
@@ -261,7 +261,7 @@ impl<Value: CtoSafe> DerefMut for CtoBox<Value>
 	}
 }
 
-impl<Value: CtoSafe> Borrow<Value> for CtoBox<Value>
+impl<Value: ?Sized + CtoSafe> Borrow<Value> for CtoBox<Value>
 {
 	#[inline(always)]
 	fn borrow(&self) -> &Value
@@ -270,7 +270,7 @@ impl<Value: CtoSafe> Borrow<Value> for CtoBox<Value>
 	}
 }
 
-impl<Value: CtoSafe> BorrowMut<Value> for CtoBox<Value>
+impl<Value: ?Sized + CtoSafe> BorrowMut<Value> for CtoBox<Value>
 {
 	#[inline(always)]
 	fn borrow_mut(&mut self) -> &mut Value
@@ -279,7 +279,7 @@ impl<Value: CtoSafe> BorrowMut<Value> for CtoBox<Value>
 	}
 }
 
-impl<Value: CtoSafe> AsRef<Value> for CtoBox<Value>
+impl<Value: ?Sized + CtoSafe> AsRef<Value> for CtoBox<Value>
 {
 	#[inline(always)]
 	fn as_ref(&self) -> &Value
@@ -288,7 +288,7 @@ impl<Value: CtoSafe> AsRef<Value> for CtoBox<Value>
 	}
 }
 
-impl<Value: CtoSafe> AsMut<Value> for CtoBox<Value>
+impl<Value: ?Sized + CtoSafe> AsMut<Value> for CtoBox<Value>
 {
 	#[inline(always)]
 	fn as_mut(&mut self) -> &mut Value
@@ -297,7 +297,7 @@ impl<Value: CtoSafe> AsMut<Value> for CtoBox<Value>
 	}
 }
 
-impl<Value: CtoSafe> CtoBox<Value>
+impl<Value: ?Sized + CtoSafe> CtoBox<Value>
 {
 	/// A pointer to use with C. Use wisely; dropping this object may cause the pointer to go out of scope.
 	#[inline(always)]
@@ -313,6 +313,48 @@ impl<Value: CtoSafe> CtoBox<Value>
 		this.deref_mut() as *mut Value
 	}
 	
+	/// Returns the number of bytes actually allocated in the CTO pool for this box, which may be more than the size of the Value.
+	/// This is an associated function as `CtoBox` implements `Deref`.
+	#[inline(always)]
+	pub fn usable_size(this: &Self) -> usize
+	{
+		this.persistent_memory().cto_pool_arc.pool_pointer().usable_size(this.persistent_memory_pointer.as_ptr() as *mut c_void)
+	}
+	
+	#[inline(always)]
+	fn persistent_memory(&self) -> &CtoBoxInner<Value>
+	{
+		unsafe { self.persistent_memory_pointer.as_ref() }
+	}
+	
+	#[inline(always)]
+	fn persistent_memory_mut(&mut self) -> &mut CtoBoxInner<Value>
+	{
+		unsafe { self.persistent_memory_pointer.as_mut() }
+	}
+}
+
+impl<Value: CtoSafe> CtoBox<Value>
+{
+	/// Moves an already initialized value into a new CtoBox allocated in the persistent memory pool.
+	/// Do not use Heap-allocated objects for fields of Value, ie only use CtoSafe fields.
+	#[inline(always)]
+	pub fn from_value(value: Value, cto_pool_arc: &CtoPoolArc) -> Result<Self, PmdkError>
+	{
+		let persistent_memory_pointer = cto_pool_arc.pool_pointer().aligned_allocate()?;
+		
+		let cto_box = unsafe
+		{
+			Self::initialize_persistent_memory::<(), _>(persistent_memory_pointer, cto_pool_arc, |value_mut_pointer, _cto_pool_arc|
+			{
+				write(value_mut_pointer, value);
+				Ok(())
+			}).unwrap()
+		};
+		
+		Ok(cto_box)
+	}
+	
 	/// Gets a raw pointer to Value, suitable for use with FFI.
 	/// Must be eventually passed to `from_raw()`, or a very serious (possibly irrecoverable even with reboots) memory leak will occur.
 	#[inline(always)]
@@ -331,16 +373,62 @@ impl<Value: CtoSafe> CtoBox<Value>
 			persistent_memory_pointer: NonNull::new_unchecked(CtoBoxInner::from_raw_value_pointer(raw_value_pointer)),
 		}
 	}
-	
+}
+
+impl<Element: CtoSafe + Clone> CtoBox<[Element]>
+{
+	/// Clones the elements of a slice into a new CtoBox allocated in the persistent memory pool.
 	#[inline(always)]
-	fn persistent_memory(&self) -> &CtoBoxInner<Value>
+	pub fn from_slice(slice: &[Element], cto_pool_arc: &CtoPoolArc) -> Result<Self, PmdkError>
 	{
-		unsafe { self.persistent_memory_pointer.as_ref() }
+		Self::from_exact_size_iterator(slice.iter().cloned(), cto_pool_arc)
+	}
+}
+
+impl<Element: CtoSafe> CtoBox<[Element]>
+{
+	/// Moves the elements produced by an iterator into a new CtoBox allocated in the persistent memory pool.
+	/// The elements are first collected using the system allocator, as the length needs to be known before allocating.
+	#[inline(always)]
+	pub fn from_iterator<I: IntoIterator<Item=Element>>(iterable: I, cto_pool_arc: &CtoPoolArc) -> Result<Self, PmdkError>
+	{
+		let elements: Vec<Element> = iterable.into_iter().collect();
+		Self::from_exact_size_iterator(elements.into_iter(), cto_pool_arc)
 	}
 	
 	#[inline(always)]
-	fn persistent_memory_mut(&mut self) -> &mut CtoBoxInner<Value>
+	fn from_exact_size_iterator<Elements: ExactSizeIterator<Item=Element>>(elements: Elements, cto_pool_arc: &CtoPoolArc) -> Result<Self, PmdkError>
 	{
-		unsafe { self.persistent_memory_pointer.as_mut() }
+		let persistent_memory_pointer = CtoBoxInner::allocate_slice(elements, cto_pool_arc)?;
+		
+		Ok
+		(
+			Self
+			{
+				persistent_memory_pointer: unsafe { NonNull::new_unchecked(persistent_memory_pointer) },
+			}
+		)
+	}
+}
+
+impl CtoBox<str>
+{
+	/// Copies a string slice into a new CtoBox allocated in the persistent memory pool.
+	#[inline(always)]
+	pub fn from_str(value: &str, cto_pool_arc: &CtoPoolArc) -> Result<Self, PmdkError>
+	{
+		let bytes = CtoBox::<[u8]>::from_slice(value.as_bytes(), cto_pool_arc)?;
+		
+		// Works because `str` and `[u8]` have the same layout and `value` was valid UTF-8.
+		let persistent_memory_pointer = bytes.persistent_memory_pointer.as_ptr() as *mut CtoBoxInner<str>;
+		forget(bytes);
+		
+		Ok
+		(
+			Self
+			{
+				persistent_memory_pointer: unsafe { NonNull::new_unchecked(persistent_memory_pointer) },
+			}
+		)
 	}
 }
@@ -2,16 +2,16 @@
 // Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
 
 
-// #[repr(C)] is required otherwise `from_raw_value_pointer()` will be very broken indeed.
+// #[repr(C)] is required otherwise `from_raw_value_pointer()` and `allocate_slice()` will be very broken indeed.
 #[repr(C)]
-pub(crate) struct CtoBoxInner<Value: CtoSafe>
+pub(crate) struct CtoBoxInner<Value: ?Sized + CtoSafe>
 {
-	// Field order matters. `value: Value` must be first otherwise `from_raw_value_pointer()` will be very broken indeed.
-	value: Value,
+	// Field order matters. `value: Value` must be last so that it can be unsized; `from_raw_value_pointer()` and `allocate_slice()` rely on this. Changing it requires incrementing `CtoPointerLayoutVersion`.
 	cto_pool_arc: CtoPoolArc,
+	value: Value,
 }
 
-impl<Value: CtoSafe> Deref for CtoBoxInner<Value>
+impl<Value: ?Sized + CtoSafe> Deref for CtoBoxInner<Value>
 {
 	type Target = Value;
 	
@@ -21,7 +21,7 @@ impl<Value: CtoSafe> Deref for CtoBoxInner<Value>
 	}
 }
 
-impl<Value: CtoSafe> DerefMut for CtoBoxInner<Value>
+impl<Value: ?Sized + CtoSafe> DerefMut for CtoBoxInner<Value>
 {
 	fn deref_mut(&mut self) -> &mut Value
 	{
@@ -29,7 +29,7 @@ impl<Value: CtoSafe> DerefMut for CtoBoxInner<Value>
 	}
 }
 
-impl<Value: CtoSafe> CtoBoxInner<Value>
+impl<Value: ?Sized + CtoSafe> CtoBoxInner<Value>
 {
 	#[inline(always)]
 	fn common_initialization(&mut self, cto_pool_arc: &CtoPoolArc)
@@ -38,19 +38,22 @@ impl<Value: CtoSafe> CtoBoxInner<Value>
 	}
 	
 	#[inline(always)]
-	fn allocated<InitializationError, Initializer: FnOnce(*mut Value, &CtoPoolArc) -> Result<(), InitializationError>>(&mut self, cto_pool_arc: &CtoPoolArc, initializer: Initializer) -> Result<(), InitializationError>
+	fn cto_pool_opened(&mut self, cto_pool_arc: &CtoPoolArc)
 	{
 		self.common_initialization(cto_pool_arc);
 		
-		initializer(&mut self.value, cto_pool_arc)
+		self.value.cto_pool_opened(cto_pool_arc)
 	}
-	
+}
+
+impl<Value: CtoSafe> CtoBoxInner<Value>
+{
 	#[inline(always)]
-	fn cto_pool_opened(&mut self, cto_pool_arc: &CtoPoolArc)
+	fn allocated<InitializationError, Initializer: FnOnce(*mut Value, &CtoPoolArc) -> Result<(), InitializationError>>(&mut self, cto_pool_arc: &CtoPoolArc, initializer: Initializer) -> Result<(), InitializationError>
 	{
 		self.common_initialization(cto_pool_arc);
 		
-		self.value.cto_pool_opened(cto_pool_arc)
+		initializer(&mut self.value, cto_pool_arc)
 	}
 	
 	#[inline(always)]
@@ -62,7 +65,27 @@ impl<Value: CtoSafe> CtoBoxInner<Value>
 	#[inline(always)]
 	fn from_raw_value_pointer(raw_value_pointer: *mut Value) -> *mut Self
 	{
-		// Works because Value is the first field and we use #[repr(C)]
-		raw_value_pointer as *mut Self
+		// Works because Value is the last field and we use #[repr(C)]
+		let (_layout, value_offset) = Layout::new::<CtoBoxInner<()>>().extend(Layout::new::<Value>()).unwrap();
+		
+		unsafe { (raw_value_pointer as *mut u8).offset(-(value_offset as isize)) as *mut Self }
+	}
+}
+
+impl<Element: CtoSafe> CtoBoxInner<[Element]>
+{
+	#[inline(always)]
+	fn allocate_slice<Elements: ExactSizeIterator<Item=Element>>(elements: Elements, cto_pool_arc: &CtoPoolArc) -> Result<*mut Self, PmdkError>
+	{
+		let length = elements.len();
+		
+		let allocation_pointer = cto_pool_arc.pool_pointer().aligned_allocate_with_trailing_slice::<CtoBoxInner<()>, Element, Elements>(elements)?;
+		
+		// Works because `[Element]` is the last field and we use #[repr(C)]; the cast keeps the slice's length as the pointer's metadata.
+		let persistent_memory_pointer = from_raw_parts_mut(allocation_pointer, length) as *mut [Element] as *mut Self;
+		
+		unsafe { (*persistent_memory_pointer).common_initialization(cto_pool_arc) };
+		
+		Ok(persistent_memory_pointer)
 	}
 }
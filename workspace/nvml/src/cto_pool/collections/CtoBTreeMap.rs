@@ -0,0 +1,765 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// CTO pool equivalent to a Rust BTreeMap.
+/// Nodes are allocated from the CTO pool using `CtoPoolAlloc`.
+pub struct CtoBTreeMap<K: CtoSafe, V: CtoSafe>
+{
+	root: *mut CtoBTreeMapNode<K, V>,
+	len: usize,
+	cto_pool_alloc: CtoPoolAlloc,
+}
+
+impl<K: CtoSafe, V: CtoSafe> Drop for CtoBTreeMap<K, V>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		self.clear()
+	}
+}
+
+impl<K: CtoSafe, V: CtoSafe> CtoSafe for CtoBTreeMap<K, V>
+{
+	#[inline(always)]
+	fn cto_pool_opened(&mut self, cto_pool_arc: &CtoPoolArc)
+	{
+		self.cto_pool_alloc.cto_pool_opened(cto_pool_arc);
+		
+		if self.root.is_not_null()
+		{
+			unsafe { CtoBTreeMapNode::cto_pool_opened_subtree(self.root, cto_pool_arc) }
+		}
+	}
+}
+
+unsafe impl<K: CtoSafe + Send, V: CtoSafe + Send> Send for CtoBTreeMap<K, V>
+{
+}
+
+unsafe impl<K: CtoSafe + Sync, V: CtoSafe + Sync> Sync for CtoBTreeMap<K, V>
+{
+}
+
+impl<K: CtoSafe + Ord + Clone, V: CtoSafe + Clone> Clone for CtoBTreeMap<K, V>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		let mut clone = Self::new(self.cto_pool_alloc.clone());
+		for (key, value) in self.iter()
+		{
+			clone.insert(key.clone(), value.clone());
+		}
+		clone
+	}
+}
+
+impl<K: CtoSafe + PartialEq, V: CtoSafe + PartialEq> PartialEq for CtoBTreeMap<K, V>
+{
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool
+	{
+		self.len == other.len && self.iter().zip(other.iter()).all(|(left, right)| left == right)
+	}
+}
+
+impl<K: CtoSafe + Eq, V: CtoSafe + Eq> Eq for CtoBTreeMap<K, V>
+{
+}
+
+impl<K: CtoSafe + Hash, V: CtoSafe + Hash> Hash for CtoBTreeMap<K, V>
+{
+	#[inline(always)]
+	fn hash<H: Hasher>(&self, state: &mut H)
+	{
+		for pair in self.iter()
+		{
+			pair.hash(state);
+		}
+	}
+}
+
+impl<K: CtoSafe + Debug, V: CtoSafe + Debug> Debug for CtoBTreeMap<K, V>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_map().entries(self.iter()).finish()
+	}
+}
+
+impl<'a, K: CtoSafe + Ord + Borrow<Q>, Q: ?Sized + Ord, V: CtoSafe> Index<&'a Q> for CtoBTreeMap<K, V>
+{
+	type Output = V;
+	
+	#[inline(always)]
+	fn index(&self, key: &Q) -> &V
+	{
+		self.get(key).expect("no entry found for key")
+	}
+}
+
+impl<K: CtoSafe + Ord, V: CtoSafe> Extend<(K, V)> for CtoBTreeMap<K, V>
+{
+	#[inline(always)]
+	fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I)
+	{
+		for (key, value) in iter
+		{
+			self.insert(key, value);
+		}
+	}
+}
+
+impl<'a, K: 'a + CtoSafe + Ord + Copy, V: 'a + CtoSafe + Copy> Extend<(&'a K, &'a V)> for CtoBTreeMap<K, V>
+{
+	#[inline(always)]
+	fn extend<I: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: I)
+	{
+		self.extend(iter.into_iter().map(|(&key, &value)| (key, value)))
+	}
+}
+
+impl<K: CtoSafe, V: CtoSafe> IntoIterator for CtoBTreeMap<K, V>
+{
+	type Item = (K, V);
+	
+	type IntoIter = CtoBTreeMapIntoIter<K, V>;
+	
+	#[inline(always)]
+	fn into_iter(self) -> Self::IntoIter
+	{
+		CtoBTreeMapIntoIter
+		{
+			map: self,
+		}
+	}
+}
+
+impl<'a, K: CtoSafe, V: CtoSafe> IntoIterator for &'a CtoBTreeMap<K, V>
+{
+	type Item = (&'a K, &'a V);
+	
+	type IntoIter = CtoBTreeMapIter<'a, K, V>;
+	
+	#[inline(always)]
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter()
+	}
+}
+
+impl<'a, K: CtoSafe, V: CtoSafe> IntoIterator for &'a mut CtoBTreeMap<K, V>
+{
+	type Item = (&'a K, &'a mut V);
+	
+	type IntoIter = CtoBTreeMapIterMut<'a, K, V>;
+	
+	#[inline(always)]
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter_mut()
+	}
+}
+
+impl<K: CtoSafe, V: CtoSafe> CtoBTreeMap<K, V>
+{
+	/// Makes a new empty `CtoBTreeMap`.
+	/// Does not allocate anything on its own.
+	#[inline(always)]
+	pub fn new(cto_pool_alloc: CtoPoolAlloc) -> Self
+	{
+		Self
+		{
+			root: null_mut(),
+			len: 0,
+			cto_pool_alloc,
+		}
+	}
+	
+	/// Returns the number of elements in the map.
+	#[inline(always)]
+	pub fn len(&self) -> usize
+	{
+		self.len
+	}
+	
+	/// Returns `true` if the map contains no elements.
+	#[inline(always)]
+	pub fn is_empty(&self) -> bool
+	{
+		self.len == 0
+	}
+	
+	/// Clears the map, removing all values and freeing all nodes.
+	#[inline(always)]
+	pub fn clear(&mut self)
+	{
+		if self.root.is_not_null()
+		{
+			let root = self.root;
+			self.root = null_mut();
+			self.len = 0;
+			unsafe { CtoBTreeMapNode::drop_subtree(&mut self.cto_pool_alloc, root) }
+		}
+	}
+	
+	/// Gets an iterator over the entries of the map, sorted by key.
+	#[inline(always)]
+	pub fn iter(&self) -> CtoBTreeMapIter<K, V>
+	{
+		CtoBTreeMapIter
+		{
+			raw_range: CtoBTreeMapRawRange::all(self.root),
+			length: self.len,
+			marker: PhantomData,
+		}
+	}
+	
+	/// Gets a mutable iterator over the entries of the map, sorted by key.
+	#[inline(always)]
+	pub fn iter_mut(&mut self) -> CtoBTreeMapIterMut<K, V>
+	{
+		CtoBTreeMapIterMut
+		{
+			raw_range: CtoBTreeMapRawRange::all(self.root),
+			length: self.len,
+			marker: PhantomData,
+		}
+	}
+	
+	/// Gets an iterator over the keys of the map, in sorted order.
+	#[inline(always)]
+	pub fn keys(&self) -> CtoBTreeMapKeys<K, V>
+	{
+		CtoBTreeMapKeys
+		{
+			iter: self.iter(),
+		}
+	}
+	
+	/// Gets an iterator over the values of the map, in order by key.
+	#[inline(always)]
+	pub fn values(&self) -> CtoBTreeMapValues<K, V>
+	{
+		CtoBTreeMapValues
+		{
+			iter: self.iter(),
+		}
+	}
+	
+	/// Gets a mutable iterator over the values of the map, in order by key.
+	#[inline(always)]
+	pub fn values_mut(&mut self) -> CtoBTreeMapValuesMut<K, V>
+	{
+		CtoBTreeMapValuesMut
+		{
+			iter_mut: self.iter_mut(),
+		}
+	}
+	
+	/// Returns the first (smallest) key and its value, if any.
+	#[inline(always)]
+	pub fn first(&self) -> Option<(&K, &V)>
+	{
+		if self.root.is_null()
+		{
+			return None;
+		}
+		
+		unsafe { CtoBTreeMapCursor::leftmost(self.root).current_forwards().map(|(key, value)| (&*key, &*value)) }
+	}
+	
+	/// Returns the last (largest) key and its value, if any.
+	#[inline(always)]
+	pub fn last(&self) -> Option<(&K, &V)>
+	{
+		if self.root.is_null()
+		{
+			return None;
+		}
+		
+		unsafe { CtoBTreeMapCursor::rightmost(self.root).current_backwards().map(|(key, value)| (&*key, &*value)) }
+	}
+	
+	/// Removes and returns the first (smallest) key and its value, if any.
+	#[inline(always)]
+	pub fn pop_first(&mut self) -> Option<(K, V)>
+	{
+		if self.root.is_null()
+		{
+			return None;
+		}
+		
+		let pair = unsafe { (&mut *self.root).remove_first(&mut self.cto_pool_alloc) };
+		self.len -= 1;
+		self.shrink_root();
+		Some(pair)
+	}
+	
+	/// Removes and returns the last (largest) key and its value, if any.
+	#[inline(always)]
+	pub fn pop_last(&mut self) -> Option<(K, V)>
+	{
+		if self.root.is_null()
+		{
+			return None;
+		}
+		
+		let pair = unsafe { (&mut *self.root).remove_last(&mut self.cto_pool_alloc) };
+		self.len -= 1;
+		self.shrink_root();
+		Some(pair)
+	}
+	
+	/// Removal can leave the root without keys; if so, replace it with its only child (or nothing).
+	#[inline(always)]
+	fn shrink_root(&mut self)
+	{
+		let root = self.root;
+		if root.is_null()
+		{
+			return;
+		}
+		
+		unsafe
+		{
+			let root_reference = &mut *root;
+			if root_reference.len == 0
+			{
+				self.root = if root_reference.is_leaf
+				{
+					null_mut()
+				}
+				else
+				{
+					CtoBTreeMapNode::first_child(root)
+				};
+				CtoBTreeMapNode::free(&mut self.cto_pool_alloc, root)
+			}
+		}
+	}
+	
+	/// Appends an entry whose key is greater than every key in the map.
+	/// `right_border` holds the nodes along the right border of the tree, from the root down; the newest nodes on it may be underfull until `fix_border(true)` is called.
+	unsafe fn push_last(&mut self, right_border: &mut Vec<*mut CtoBTreeMapNode<K, V>>, key: K, value: V)
+	{
+		// Find the lowest node on the right border with room for another key.
+		let mut level = right_border.len();
+		while level > 0 && (&*right_border[level - 1]).is_full()
+		{
+			level -= 1;
+		}
+		
+		if level == 0
+		{
+			let new_root = CtoBTreeMapNode::allocate(&mut self.cto_pool_alloc, false);
+			(&mut *new_root).set_child(0, self.root);
+			self.root = new_root;
+			right_border.insert(0, new_root);
+			level = 1;
+		}
+		
+		let parent = right_border[level - 1];
+		let mut node = &mut *parent;
+		let len = node.len;
+		node.insert_at(len, key, value);
+		
+		// Below a full node, start a new, empty, right-most subtree.
+		let height = right_border.len();
+		while level < height
+		{
+			let child = CtoBTreeMapNode::allocate(&mut self.cto_pool_alloc, level == height - 1);
+			let len = node.len;
+			node.set_child(len, child);
+			right_border[level] = child;
+			node = &mut *child;
+			level += 1;
+		}
+		
+		self.len += 1;
+	}
+	
+	/// After splitting or bulk building, nodes along one border of the tree may be empty or underfull.
+	/// Removes empty roots, then, from the top down, tops up each border child from its sibling (or merges it with its sibling) as removal does.
+	fn fix_border(&mut self, right_border: bool)
+	{
+		unsafe
+		{
+			while self.root.is_not_null() && (&*self.root).len == 0
+			{
+				self.shrink_root();
+			}
+			
+			let mut node = self.root;
+			while node.is_not_null() && !(&*node).is_leaf
+			{
+				let node_reference = &mut *node;
+				
+				let mut index = if right_border
+				{
+					node_reference.len
+				}
+				else
+				{
+					0
+				};
+				while !node_reference.child_reference(index).can_lend()
+				{
+					index = node_reference.prepare_child_for_removal(&mut self.cto_pool_alloc, index);
+				}
+				
+				// Merging the only two children of the root leaves the root empty.
+				let child = node_reference.child(index);
+				self.shrink_root();
+				node = child;
+			}
+		}
+	}
+}
+
+impl<K: CtoSafe + Ord, V: CtoSafe> CtoBTreeMap<K, V>
+{
+	/// Returns a reference to the value corresponding to the key.
+	#[inline(always)]
+	pub fn get<Q: ?Sized + Ord>(&self, key: &Q) -> Option<&V>
+	where K: Borrow<Q>
+	{
+		self.find(key).map(|(_, value)| unsafe { &*value })
+	}
+	
+	/// Returns a mutable reference to the value corresponding to the key.
+	#[inline(always)]
+	pub fn get_mut<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<&mut V>
+	where K: Borrow<Q>
+	{
+		self.find(key).map(|(_, value)| unsafe { &mut *value })
+	}
+	
+	/// Returns `true` if the map contains a value for the specified key.
+	#[inline(always)]
+	pub fn contains_key<Q: ?Sized + Ord>(&self, key: &Q) -> bool
+	where K: Borrow<Q>
+	{
+		self.find(key).is_some()
+	}
+	
+	/// Inserts a key-value pair into the map.
+	/// If the map did not have this key present, `None` is returned.
+	/// If the map did have this key present, the value is updated, and the old value is returned; the key is not updated.
+	pub fn insert(&mut self, key: K, value: V) -> Option<V>
+	{
+		unsafe
+		{
+			if self.root.is_null()
+			{
+				self.root = CtoBTreeMapNode::allocate(&mut self.cto_pool_alloc, true);
+			}
+			
+			if (&*self.root).is_full()
+			{
+				let new_root = CtoBTreeMapNode::allocate(&mut self.cto_pool_alloc, false);
+				(&mut *new_root).set_child(0, self.root);
+				(&mut *new_root).split_child(&mut self.cto_pool_alloc, 0);
+				self.root = new_root;
+			}
+			
+			// Full nodes are split on the way down, so there is always room to insert into a leaf or to accept a median from a child.
+			let mut node = &mut *self.root;
+			loop
+			{
+				match node.search(&key)
+				{
+					Ok(index) => return Some(replace(&mut *node.value(index), value)),
+					
+					Err(mut index) =>
+					{
+						if node.is_leaf
+						{
+							node.insert_at(index, key, value);
+							self.len += 1;
+							return None;
+						}
+						
+						if node.child_reference(index).is_full()
+						{
+							node.split_child(&mut self.cto_pool_alloc, index);
+							match key.cmp(&*node.key(index))
+							{
+								Ordering::Equal => return Some(replace(&mut *node.value(index), value)),
+								Ordering::Greater => index += 1,
+								Ordering::Less => (),
+							}
+						}
+						
+						node = node.child_reference(index);
+					}
+				}
+			}
+		}
+	}
+	
+	/// Removes a key from the map, returning the value at the key if the key was previously in the map.
+	#[inline(always)]
+	pub fn remove<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<V>
+	where K: Borrow<Q>
+	{
+		self.remove_entry(key).map(|(_, value)| value)
+	}
+	
+	/// Removes a key from the map, returning the stored key and value if the key was previously in the map.
+	#[inline(always)]
+	pub fn remove_entry<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<(K, V)>
+	where K: Borrow<Q>
+	{
+		if self.root.is_null()
+		{
+			return None;
+		}
+		
+		let pair = unsafe { (&mut *self.root).remove(&mut self.cto_pool_alloc, key) };
+		if pair.is_some()
+		{
+			self.len -= 1;
+		}
+		// Nodes may have been merged on the way down even if the key was not present.
+		self.shrink_root();
+		pair
+	}
+	
+	/// Constructs a double-ended iterator over a sub-range of elements in the map.
+	/// Panics if the range start is greater than the range end, or if the range start and end are equal and both excluded.
+	#[inline(always)]
+	pub fn range<T: ?Sized + Ord, R: RangeArgument<T>>(&self, range: R) -> CtoBTreeMapRange<K, V>
+	where K: Borrow<T>
+	{
+		Self::validate_range(&range);
+		
+		CtoBTreeMapRange
+		{
+			raw_range: unsafe { CtoBTreeMapRawRange::new(self.root, range.start(), range.end()) },
+			marker: PhantomData,
+		}
+	}
+	
+	/// Constructs a mutable double-ended iterator over a sub-range of elements in the map.
+	/// Panics if the range start is greater than the range end, or if the range start and end are equal and both excluded.
+	#[inline(always)]
+	pub fn range_mut<T: ?Sized + Ord, R: RangeArgument<T>>(&mut self, range: R) -> CtoBTreeMapRangeMut<K, V>
+	where K: Borrow<T>
+	{
+		Self::validate_range(&range);
+		
+		CtoBTreeMapRangeMut
+		{
+			raw_range: unsafe { CtoBTreeMapRawRange::new(self.root, range.start(), range.end()) },
+			marker: PhantomData,
+		}
+	}
+	
+	/// Splits the collection into two at the given key.
+	/// Returns everything after the given key, including the key.
+	/// The nodes along the search path for the key are split in two, so the split itself is O(log n); the smaller of the two halves is then counted to establish the lengths.
+	pub fn split_off<Q: ?Sized + Ord>(&mut self, key: &Q) -> Self
+	where K: Borrow<Q>
+	{
+		let mut other = Self::new(self.cto_pool_alloc.clone());
+		
+		let everything_moves = match self.first()
+		{
+			None => return other,
+			Some((first_key, _)) => first_key.borrow() >= key,
+		};
+		
+		if everything_moves
+		{
+			swap(self, &mut other);
+			return other;
+		}
+		
+		let nothing_moves = match self.last()
+		{
+			None => true,
+			Some((last_key, _)) => last_key.borrow() < key,
+		};
+		
+		if nothing_moves
+		{
+			return other;
+		}
+		
+		unsafe
+		{
+			let mut left = self.root;
+			let mut right = CtoBTreeMapNode::allocate(&mut other.cto_pool_alloc, (&*left).is_leaf);
+			other.root = right;
+			loop
+			{
+				let left_reference = &mut *left;
+				let right_reference = &mut *right;
+				
+				let index = left_reference.count_below(key, false);
+				left_reference.move_suffix(index, right_reference);
+				if left_reference.is_leaf
+				{
+					break;
+				}
+				
+				left = left_reference.child(index);
+				right = CtoBTreeMapNode::allocate(&mut other.cto_pool_alloc, (&*left).is_leaf);
+				right_reference.set_child(0, right);
+			}
+			
+			self.fix_border(true);
+			other.fix_border(false);
+			
+			let total = self.len;
+			if CtoBTreeMapNode::height(self.root) < CtoBTreeMapNode::height(other.root)
+			{
+				self.len = CtoBTreeMapNode::count_subtree(self.root);
+				other.len = total - self.len;
+			}
+			else
+			{
+				other.len = CtoBTreeMapNode::count_subtree(other.root);
+				self.len = total - other.len;
+			}
+		}
+		
+		other
+	}
+	
+	/// Moves all elements from `other` into `self`, leaving `other` empty.
+	/// Where a key is in both, the value from `other` replaces that in `self`.
+	/// The entries of both maps are merged in order and the tree is rebuilt from them, so this is O(n + m).
+	pub fn append(&mut self, other: &mut Self)
+	{
+		if other.is_empty()
+		{
+			return;
+		}
+		
+		if self.is_empty()
+		{
+			swap(self, other);
+			return;
+		}
+		
+		let left_root = replace(&mut self.root, null_mut());
+		self.len = 0;
+		let right_root = replace(&mut other.root, null_mut());
+		other.len = 0;
+		
+		unsafe
+		{
+			// Entries are moved out of the old nodes as they are read; should `Ord` panic, the remaining entries and the old nodes are leaked rather than dropped twice.
+			let mut left = CtoBTreeMapRawRange::all(left_root);
+			let mut right = CtoBTreeMapRawRange::all(right_root);
+			let mut left_next = Self::take_next(&mut left);
+			let mut right_next = Self::take_next(&mut right);
+			
+			self.root = CtoBTreeMapNode::allocate(&mut self.cto_pool_alloc, true);
+			let mut right_border = vec![self.root];
+			loop
+			{
+				let (key, value) = match (left_next.take(), right_next.take())
+				{
+					(None, None) => break,
+					
+					(Some(left_pair), None) =>
+					{
+						left_next = Self::take_next(&mut left);
+						left_pair
+					}
+					
+					(None, Some(right_pair)) =>
+					{
+						right_next = Self::take_next(&mut right);
+						right_pair
+					}
+					
+					(Some(left_pair), Some(right_pair)) => match left_pair.0.cmp(&right_pair.0)
+					{
+						Ordering::Less =>
+						{
+							left_next = Self::take_next(&mut left);
+							right_next = Some(right_pair);
+							left_pair
+						}
+						
+						Ordering::Greater =>
+						{
+							left_next = Some(left_pair);
+							right_next = Self::take_next(&mut right);
+							right_pair
+						}
+						
+						Ordering::Equal =>
+						{
+							left_next = Self::take_next(&mut left);
+							right_next = Self::take_next(&mut right);
+							(left_pair.0, right_pair.1)
+						}
+					},
+				};
+				
+				self.push_last(&mut right_border, key, value);
+			}
+			
+			CtoBTreeMapNode::free_subtree(&mut self.cto_pool_alloc, left_root);
+			CtoBTreeMapNode::free_subtree(&mut other.cto_pool_alloc, right_root);
+		}
+		
+		self.fix_border(true)
+	}
+	
+	#[inline(always)]
+	unsafe fn take_next(raw_range: &mut CtoBTreeMapRawRange<K, V>) -> Option<(K, V)>
+	{
+		raw_range.next().map(|(key, value)| (read(key), read(value)))
+	}
+	
+	#[inline(always)]
+	fn find<Q: ?Sized + Ord>(&self, key: &Q) -> Option<(*mut K, *mut V)>
+	where K: Borrow<Q>
+	{
+		let mut node = self.root;
+		if node.is_null()
+		{
+			return None;
+		}
+		
+		loop
+		{
+			let node_reference = unsafe { &mut *node };
+			match node_reference.search(key)
+			{
+				Ok(index) => return Some(unsafe { (node_reference.key(index), node_reference.value(index)) }),
+				
+				Err(index) =>
+				{
+					if node_reference.is_leaf
+					{
+						return None;
+					}
+					node = unsafe { node_reference.child(index) };
+				}
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn validate_range<T: ?Sized + Ord, R: RangeArgument<T>>(range: &R)
+	{
+		match (range.start(), range.end())
+		{
+			(Excluded(start), Excluded(end)) if start == end => panic!("range start and end are equal and excluded in CtoBTreeMap"),
+			
+			(Included(start), Included(end)) | (Included(start), Excluded(end)) | (Excluded(start), Included(end)) | (Excluded(start), Excluded(end)) if start > end => panic!("range start is greater than range end in CtoBTreeMap"),
+			
+			_ => (),
+		}
+	}
+}
@@ -0,0 +1,229 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// A cursor into a `CtoBTreeMap`, moving either forwards or backwards.
+/// The stack holds the path from the root; for the top entry, `index` identifies the current key; for ancestors, it identifies the key to visit after the subtree being explored.
+/// When moving forwards `index` is the index of that key; when moving backwards it is one more than the index of that key.
+struct CtoBTreeMapCursor<K: CtoSafe, V: CtoSafe>
+{
+	stack: Vec<(*mut CtoBTreeMapNode<K, V>, usize)>,
+}
+
+impl<K: CtoSafe, V: CtoSafe> Clone for CtoBTreeMapCursor<K, V>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		Self
+		{
+			stack: self.stack.clone(),
+		}
+	}
+}
+
+impl<K: CtoSafe, V: CtoSafe> CtoBTreeMapCursor<K, V>
+{
+	#[inline(always)]
+	fn empty() -> Self
+	{
+		Self
+		{
+			stack: Vec::new(),
+		}
+	}
+	
+	/// Positions at the first key; `root` must not be null or empty.
+	unsafe fn leftmost(root: *mut CtoBTreeMapNode<K, V>) -> Self
+	{
+		let mut cursor = Self::empty();
+		let mut node = root;
+		loop
+		{
+			cursor.stack.push((node, 0));
+			if (&*node).is_leaf
+			{
+				break;
+			}
+			node = CtoBTreeMapNode::first_child(node);
+		}
+		cursor
+	}
+	
+	/// Positions at the last key; `root` must not be null or empty.
+	unsafe fn rightmost(root: *mut CtoBTreeMapNode<K, V>) -> Self
+	{
+		let mut cursor = Self::empty();
+		let mut node = root;
+		loop
+		{
+			cursor.stack.push((node, (&*node).len));
+			if (&*node).is_leaf
+			{
+				break;
+			}
+			node = CtoBTreeMapNode::last_child(node);
+		}
+		cursor
+	}
+	
+	/// Positions at the first key which is at or after `bound`.
+	unsafe fn lower_bound<Q: ?Sized + Ord>(root: *mut CtoBTreeMapNode<K, V>, bound: Bound<&Q>) -> Self
+	where K: Borrow<Q>
+	{
+		let mut cursor = Self::empty();
+		if root.is_null()
+		{
+			return cursor;
+		}
+		
+		let mut node = root;
+		loop
+		{
+			let node_reference = &mut *node;
+			let index = match bound
+			{
+				Included(bound) => node_reference.count_below(bound, false),
+				Excluded(bound) => node_reference.count_below(bound, true),
+				Unbounded => 0,
+			};
+			cursor.stack.push((node, index));
+			if node_reference.is_leaf
+			{
+				break;
+			}
+			node = node_reference.child(index);
+		}
+		
+		cursor.pop_exhausted_forwards();
+		cursor
+	}
+	
+	/// Positions at the last key which is at or before `bound`.
+	unsafe fn upper_bound<Q: ?Sized + Ord>(root: *mut CtoBTreeMapNode<K, V>, bound: Bound<&Q>) -> Self
+	where K: Borrow<Q>
+	{
+		let mut cursor = Self::empty();
+		if root.is_null()
+		{
+			return cursor;
+		}
+		
+		let mut node = root;
+		loop
+		{
+			let node_reference = &mut *node;
+			let index = match bound
+			{
+				Included(bound) => node_reference.count_below(bound, true),
+				Excluded(bound) => node_reference.count_below(bound, false),
+				Unbounded => node_reference.len,
+			};
+			cursor.stack.push((node, index));
+			if node_reference.is_leaf
+			{
+				break;
+			}
+			node = node_reference.child(index);
+		}
+		
+		cursor.pop_exhausted_backwards();
+		cursor
+	}
+	
+	#[inline(always)]
+	fn is_exhausted(&self) -> bool
+	{
+		self.stack.is_empty()
+	}
+	
+	/// Pointers to the current key and value, if any, when moving forwards.
+	#[inline(always)]
+	unsafe fn current_forwards(&self) -> Option<(*mut K, *mut V)>
+	{
+		self.stack.last().map(|&(node, index)| ((&mut *node).key(index), (&mut *node).value(index)))
+	}
+	
+	/// Pointers to the current key and value, if any, when moving backwards.
+	#[inline(always)]
+	unsafe fn current_backwards(&self) -> Option<(*mut K, *mut V)>
+	{
+		self.stack.last().map(|&(node, index)| ((&mut *node).key(index - 1), (&mut *node).value(index - 1)))
+	}
+	
+	unsafe fn advance_forwards(&mut self)
+	{
+		let (node, index) = *self.stack.last().unwrap();
+		let node_reference = &mut *node;
+		
+		if node_reference.is_leaf
+		{
+			self.stack.last_mut().unwrap().1 = index + 1;
+		}
+		else
+		{
+			self.stack.last_mut().unwrap().1 = index + 1;
+			let mut child = node_reference.child(index + 1);
+			loop
+			{
+				self.stack.push((child, 0));
+				if (&*child).is_leaf
+				{
+					break;
+				}
+				child = CtoBTreeMapNode::first_child(child);
+			}
+		}
+		
+		self.pop_exhausted_forwards()
+	}
+	
+	unsafe fn advance_backwards(&mut self)
+	{
+		let (node, index) = *self.stack.last().unwrap();
+		let node_reference = &mut *node;
+		
+		self.stack.last_mut().unwrap().1 = index - 1;
+		if !node_reference.is_leaf
+		{
+			let mut child = node_reference.child(index - 1);
+			loop
+			{
+				self.stack.push((child, (&*child).len));
+				if (&*child).is_leaf
+				{
+					break;
+				}
+				child = CtoBTreeMapNode::last_child(child);
+			}
+		}
+		
+		self.pop_exhausted_backwards()
+	}
+	
+	#[inline(always)]
+	unsafe fn pop_exhausted_forwards(&mut self)
+	{
+		while let Some(&(node, index)) = self.stack.last()
+		{
+			if index < (&*node).len
+			{
+				break;
+			}
+			self.stack.pop();
+		}
+	}
+	
+	#[inline(always)]
+	unsafe fn pop_exhausted_backwards(&mut self)
+	{
+		while let Some(&(_, index)) = self.stack.last()
+		{
+			if index > 0
+			{
+				break;
+			}
+			self.stack.pop();
+		}
+	}
+}
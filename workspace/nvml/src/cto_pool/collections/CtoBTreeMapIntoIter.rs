@@ -0,0 +1,51 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// An owning iterator over the entries of a `CtoBTreeMap`, sorted by key.
+pub struct CtoBTreeMapIntoIter<K: CtoSafe, V: CtoSafe>
+{
+	map: CtoBTreeMap<K, V>,
+}
+
+impl<K: CtoSafe + Debug, V: CtoSafe + Debug> Debug for CtoBTreeMapIntoIter<K, V>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_tuple("CtoBTreeMapIntoIter").field(&self.map).finish()
+	}
+}
+
+impl<K: CtoSafe, V: CtoSafe> Iterator for CtoBTreeMapIntoIter<K, V>
+{
+	type Item = (K, V);
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.map.pop_first()
+	}
+	
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(self.map.len, Some(self.map.len))
+	}
+}
+
+impl<K: CtoSafe, V: CtoSafe> DoubleEndedIterator for CtoBTreeMapIntoIter<K, V>
+{
+	#[inline(always)]
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		self.map.pop_last()
+	}
+}
+
+impl<K: CtoSafe, V: CtoSafe> ExactSizeIterator for CtoBTreeMapIntoIter<K, V>
+{
+}
+
+impl<K: CtoSafe, V: CtoSafe> FusedIterator for CtoBTreeMapIntoIter<K, V>
+{
+}
@@ -0,0 +1,85 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// An iterator over the entries of a `CtoBTreeMap`, sorted by key.
+pub struct CtoBTreeMapIter<'a, K: 'a + CtoSafe, V: 'a + CtoSafe>
+{
+	raw_range: CtoBTreeMapRawRange<K, V>,
+	length: usize,
+	marker: PhantomData<&'a CtoBTreeMap<K, V>>,
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> Clone for CtoBTreeMapIter<'a, K, V>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		Self
+		{
+			raw_range: self.raw_range.clone(),
+			length: self.length,
+			marker: PhantomData,
+		}
+	}
+}
+
+impl<'a, K: 'a + CtoSafe + Debug, V: 'a + CtoSafe + Debug> Debug for CtoBTreeMapIter<'a, K, V>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_list().entries(self.clone()).finish()
+	}
+}
+
+unsafe impl<'a, K: CtoSafe + Sync, V: CtoSafe + Sync> Sync for CtoBTreeMapIter<'a, K, V>
+{
+}
+
+unsafe impl<'a, K: CtoSafe + Sync, V: CtoSafe + Sync> Send for CtoBTreeMapIter<'a, K, V>
+{
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> Iterator for CtoBTreeMapIter<'a, K, V>
+{
+	type Item = (&'a K, &'a V);
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let next = self.raw_range.next().map(|(key, value)| unsafe { (&*key, &*value) });
+		if next.is_some()
+		{
+			self.length -= 1;
+		}
+		next
+	}
+	
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(self.length, Some(self.length))
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> DoubleEndedIterator for CtoBTreeMapIter<'a, K, V>
+{
+	#[inline(always)]
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		let next = self.raw_range.next_back().map(|(key, value)| unsafe { (&*key, &*value) });
+		if next.is_some()
+		{
+			self.length -= 1;
+		}
+		next
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> ExactSizeIterator for CtoBTreeMapIter<'a, K, V>
+{
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> FusedIterator for CtoBTreeMapIter<'a, K, V>
+{
+}
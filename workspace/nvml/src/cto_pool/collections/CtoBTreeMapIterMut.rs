@@ -0,0 +1,77 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// A mutable iterator over the entries of a `CtoBTreeMap`, sorted by key.
+pub struct CtoBTreeMapIterMut<'a, K: 'a + CtoSafe, V: 'a + CtoSafe>
+{
+	raw_range: CtoBTreeMapRawRange<K, V>,
+	length: usize,
+	marker: PhantomData<&'a mut CtoBTreeMap<K, V>>,
+}
+
+impl<'a, K: 'a + CtoSafe + Debug, V: 'a + CtoSafe + Debug> Debug for CtoBTreeMapIterMut<'a, K, V>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		let mut raw_range = self.raw_range.clone();
+		let mut list = f.debug_list();
+		while let Some((key, value)) = raw_range.next()
+		{
+			list.entry(&unsafe { (&*key, &*value) });
+		}
+		list.finish()
+	}
+}
+
+unsafe impl<'a, K: CtoSafe + Sync, V: CtoSafe + Sync> Sync for CtoBTreeMapIterMut<'a, K, V>
+{
+}
+
+unsafe impl<'a, K: CtoSafe + Send, V: CtoSafe + Send> Send for CtoBTreeMapIterMut<'a, K, V>
+{
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> Iterator for CtoBTreeMapIterMut<'a, K, V>
+{
+	type Item = (&'a K, &'a mut V);
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		let next = self.raw_range.next().map(|(key, value)| unsafe { (&*key, &mut *value) });
+		if next.is_some()
+		{
+			self.length -= 1;
+		}
+		next
+	}
+	
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(self.length, Some(self.length))
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> DoubleEndedIterator for CtoBTreeMapIterMut<'a, K, V>
+{
+	#[inline(always)]
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		let next = self.raw_range.next_back().map(|(key, value)| unsafe { (&*key, &mut *value) });
+		if next.is_some()
+		{
+			self.length -= 1;
+		}
+		next
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> ExactSizeIterator for CtoBTreeMapIterMut<'a, K, V>
+{
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> FusedIterator for CtoBTreeMapIterMut<'a, K, V>
+{
+}
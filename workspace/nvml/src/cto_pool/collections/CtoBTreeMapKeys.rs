@@ -0,0 +1,63 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// An iterator over the keys of a `CtoBTreeMap`, in sorted order.
+pub struct CtoBTreeMapKeys<'a, K: 'a + CtoSafe, V: 'a + CtoSafe>
+{
+	iter: CtoBTreeMapIter<'a, K, V>,
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> Clone for CtoBTreeMapKeys<'a, K, V>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		Self
+		{
+			iter: self.iter.clone(),
+		}
+	}
+}
+
+impl<'a, K: 'a + CtoSafe + Debug, V: 'a + CtoSafe + Debug> Debug for CtoBTreeMapKeys<'a, K, V>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_tuple("CtoBTreeMapKeys").field(&self.iter).finish()
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> Iterator for CtoBTreeMapKeys<'a, K, V>
+{
+	type Item = &'a K;
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.iter.next().map(|(key, _)| key)
+	}
+	
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.iter.size_hint()
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> DoubleEndedIterator for CtoBTreeMapKeys<'a, K, V>
+{
+	#[inline(always)]
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		self.iter.next_back().map(|(key, _)| key)
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> ExactSizeIterator for CtoBTreeMapKeys<'a, K, V>
+{
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> FusedIterator for CtoBTreeMapKeys<'a, K, V>
+{
+}
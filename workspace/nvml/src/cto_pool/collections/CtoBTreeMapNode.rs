@@ -0,0 +1,492 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// Maximum number of keys in a `CtoBTreeMapNode`.
+const CtoBTreeMapNodeCapacity: usize = 2 * CtoBTreeMapNodeMinimumDegree - 1;
+
+/// Every node apart from the root has at least `CtoBTreeMapNodeMinimumDegree - 1` keys.
+const CtoBTreeMapNodeMinimumDegree: usize = 6;
+
+/// A node of a `CtoBTreeMap`.
+/// Nodes are only ever accessed through raw pointers; only the first `len` keys and values, and, for internal nodes, the first `len + 1` children, are initialized.
+struct CtoBTreeMapNode<K: CtoSafe, V: CtoSafe>
+{
+	is_leaf: bool,
+	len: usize,
+	keys: [K; CtoBTreeMapNodeCapacity],
+	values: [V; CtoBTreeMapNodeCapacity],
+	children: [*mut CtoBTreeMapNode<K, V>; CtoBTreeMapNodeCapacity + 1],
+}
+
+impl<K: CtoSafe, V: CtoSafe> CtoBTreeMapNode<K, V>
+{
+	#[inline(always)]
+	fn allocate(cto_pool_alloc: &mut CtoPoolAlloc, is_leaf: bool) -> *mut Self
+	{
+		let node = cto_pool_alloc.alloc_one::<Self>().expect("Out of memory in CTO pool when allocating a new B-tree node").as_ptr();
+		unsafe
+		{
+			write(&mut (*node).is_leaf, is_leaf);
+			write(&mut (*node).len, 0);
+		}
+		node
+	}
+	
+	#[inline(always)]
+	unsafe fn free(cto_pool_alloc: &mut CtoPoolAlloc, node: *mut Self)
+	{
+		cto_pool_alloc.dealloc_one(NonNull::new_unchecked(node))
+	}
+	
+	/// Drops all keys and values in the subtree rooted at `node` and frees all its nodes.
+	unsafe fn drop_subtree(cto_pool_alloc: &mut CtoPoolAlloc, node: *mut Self)
+	{
+		let node_reference = &mut *node;
+		
+		if needs_drop::<K>() || needs_drop::<V>()
+		{
+			let mut index = 0;
+			while index < node_reference.len
+			{
+				drop_in_place(node_reference.key(index));
+				drop_in_place(node_reference.value(index));
+				index += 1;
+			}
+		}
+		
+		if !node_reference.is_leaf
+		{
+			let mut index = 0;
+			while index <= node_reference.len
+			{
+				Self::drop_subtree(cto_pool_alloc, node_reference.child(index));
+				index += 1;
+			}
+		}
+		
+		Self::free(cto_pool_alloc, node)
+	}
+	
+	/// Frees all nodes in the subtree rooted at `node` without dropping their keys and values, which must already have been moved out.
+	unsafe fn free_subtree(cto_pool_alloc: &mut CtoPoolAlloc, node: *mut Self)
+	{
+		let node_reference = &mut *node;
+		
+		if !node_reference.is_leaf
+		{
+			let mut index = 0;
+			while index <= node_reference.len
+			{
+				Self::free_subtree(cto_pool_alloc, node_reference.child(index));
+				index += 1;
+			}
+		}
+		
+		Self::free(cto_pool_alloc, node)
+	}
+	
+	/// Counts the keys in the subtree rooted at `node`.
+	unsafe fn count_subtree(node: *mut Self) -> usize
+	{
+		let node_reference = &mut *node;
+		
+		let mut count = node_reference.len;
+		if !node_reference.is_leaf
+		{
+			let mut index = 0;
+			while index <= node_reference.len
+			{
+				count += Self::count_subtree(node_reference.child(index));
+				index += 1;
+			}
+		}
+		count
+	}
+	
+	/// Number of levels in the subtree rooted at `node`; a leaf has a height of 1.
+	#[inline(always)]
+	unsafe fn height(node: *mut Self) -> usize
+	{
+		let mut height = 1;
+		let mut node = node;
+		while !(&*node).is_leaf
+		{
+			node = Self::first_child(node);
+			height += 1;
+		}
+		height
+	}
+	
+	unsafe fn cto_pool_opened_subtree(node: *mut Self, cto_pool_arc: &CtoPoolArc)
+	{
+		let node_reference = &mut *node;
+		
+		let mut index = 0;
+		while index < node_reference.len
+		{
+			(*node_reference.key(index)).cto_pool_opened(cto_pool_arc);
+			(*node_reference.value(index)).cto_pool_opened(cto_pool_arc);
+			index += 1;
+		}
+		
+		if !node_reference.is_leaf
+		{
+			let mut index = 0;
+			while index <= node_reference.len
+			{
+				Self::cto_pool_opened_subtree(node_reference.child(index), cto_pool_arc);
+				index += 1;
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn is_full(&self) -> bool
+	{
+		self.len == CtoBTreeMapNodeCapacity
+	}
+	
+	/// Has more than the minimum number of keys, so a key can be taken from it without it underflowing.
+	#[inline(always)]
+	fn can_lend(&self) -> bool
+	{
+		self.len >= CtoBTreeMapNodeMinimumDegree
+	}
+	
+	#[inline(always)]
+	unsafe fn key(&mut self, index: usize) -> *mut K
+	{
+		(self.keys.as_mut_ptr()).offset(index as isize)
+	}
+	
+	#[inline(always)]
+	unsafe fn value(&mut self, index: usize) -> *mut V
+	{
+		(self.values.as_mut_ptr()).offset(index as isize)
+	}
+	
+	#[inline(always)]
+	unsafe fn child(&mut self, index: usize) -> *mut Self
+	{
+		*(self.children.as_mut_ptr()).offset(index as isize)
+	}
+	
+	#[inline(always)]
+	unsafe fn child_reference<'a>(&mut self, index: usize) -> &'a mut Self
+	{
+		&mut *self.child(index)
+	}
+	
+	#[inline(always)]
+	unsafe fn set_child(&mut self, index: usize, child: *mut Self)
+	{
+		*(self.children.as_mut_ptr()).offset(index as isize) = child
+	}
+	
+	#[inline(always)]
+	unsafe fn first_child(node: *mut Self) -> *mut Self
+	{
+		(&mut *node).child(0)
+	}
+	
+	#[inline(always)]
+	unsafe fn last_child(node: *mut Self) -> *mut Self
+	{
+		let node_reference = &mut *node;
+		let len = node_reference.len;
+		node_reference.child(len)
+	}
+	
+	/// Linear search; returns `Ok(index)` of `key` or `Err(index)` of the child (or insertion point) that would contain it.
+	#[inline(always)]
+	fn search<Q: ?Sized + Ord>(&mut self, key: &Q) -> Result<usize, usize>
+	where K: Borrow<Q>
+	{
+		let mut index = 0;
+		while index < self.len
+		{
+			match key.cmp(unsafe { &*self.key(index) }.borrow())
+			{
+				Ordering::Greater => index += 1,
+				Ordering::Equal => return Ok(index),
+				Ordering::Less => return Err(index),
+			}
+		}
+		Err(index)
+	}
+	
+	/// Number of keys which are less than `bound` (`inclusive == false`) or less than or equal to `bound` (`inclusive == true`).
+	#[inline(always)]
+	fn count_below<Q: ?Sized + Ord>(&mut self, bound: &Q, inclusive: bool) -> usize
+	where K: Borrow<Q>
+	{
+		let mut index = 0;
+		while index < self.len
+		{
+			match unsafe { &*self.key(index) }.borrow().cmp(bound)
+			{
+				Ordering::Less => index += 1,
+				Ordering::Equal if inclusive => index += 1,
+				_ => break,
+			}
+		}
+		index
+	}
+	
+	#[inline(always)]
+	unsafe fn insert_at(&mut self, index: usize, key: K, value: V)
+	{
+		debug_assert!(!self.is_full(), "node is full");
+		debug_assert!(index <= self.len, "index is out of range");
+		
+		let len = self.len;
+		copy(self.key(index), self.key(index + 1), len - index);
+		copy(self.value(index), self.value(index + 1), len - index);
+		write(self.key(index), key);
+		write(self.value(index), value);
+		self.len += 1;
+	}
+	
+	#[inline(always)]
+	unsafe fn remove_at(&mut self, index: usize) -> (K, V)
+	{
+		debug_assert!(index < self.len, "index is out of range");
+		
+		let key = read(self.key(index));
+		let value = read(self.value(index));
+		let len = self.len;
+		copy(self.key(index + 1), self.key(index), len - index - 1);
+		copy(self.value(index + 1), self.value(index), len - index - 1);
+		self.len -= 1;
+		(key, value)
+	}
+	
+	/// Inserts a child at `index`, shifting later children right; must be called *after* the corresponding `insert_at()`.
+	#[inline(always)]
+	unsafe fn insert_child(&mut self, index: usize, child: *mut Self)
+	{
+		let number_of_children_before = self.len;
+		let children = self.children.as_mut_ptr();
+		copy(children.offset(index as isize), children.offset(index as isize + 1), number_of_children_before - index);
+		self.set_child(index, child)
+	}
+	
+	/// Moves the keys and values from `index` onwards, and for an internal node the children after them, into the empty node `right`.
+	/// For an internal node, the first child of `right` is left for the caller to set.
+	#[inline(always)]
+	unsafe fn move_suffix(&mut self, index: usize, right: &mut Self)
+	{
+		debug_assert!(index <= self.len, "index is out of range");
+		debug_assert!(right.len == 0, "right is not empty");
+		debug_assert!(right.is_leaf == self.is_leaf, "right is not at the same level");
+		
+		let number_moved = self.len - index;
+		copy_nonoverlapping(self.key(index), right.key(0), number_moved);
+		copy_nonoverlapping(self.value(index), right.value(0), number_moved);
+		if !self.is_leaf
+		{
+			copy_nonoverlapping(self.children.as_ptr().offset(index as isize + 1), right.children.as_mut_ptr().offset(1), number_moved);
+		}
+		right.len = number_moved;
+		self.len = index;
+	}
+	
+	/// Removes a child at `index`, shifting later children left; must be called *after* the corresponding `remove_at()`.
+	#[inline(always)]
+	unsafe fn remove_child(&mut self, index: usize) -> *mut Self
+	{
+		let child = self.child(index);
+		let number_of_children_after = self.len + 1;
+		let children = self.children.as_mut_ptr();
+		copy(children.offset(index as isize + 1), children.offset(index as isize), number_of_children_after - index);
+		child
+	}
+	
+	/// Splits the full child at `index` into two, moving its median key up into this node.
+	unsafe fn split_child(&mut self, cto_pool_alloc: &mut CtoPoolAlloc, index: usize)
+	{
+		debug_assert!(!self.is_full(), "node is full");
+		
+		let left = self.child_reference(index);
+		debug_assert!(left.is_full(), "child is not full");
+		
+		let right = &mut *Self::allocate(cto_pool_alloc, left.is_leaf);
+		
+		const Median: usize = CtoBTreeMapNodeMinimumDegree - 1;
+		const NumberMoved: usize = CtoBTreeMapNodeCapacity - Median - 1;
+		
+		copy_nonoverlapping(left.key(Median + 1), right.key(0), NumberMoved);
+		copy_nonoverlapping(left.value(Median + 1), right.value(0), NumberMoved);
+		if !left.is_leaf
+		{
+			copy_nonoverlapping(left.children.as_ptr().offset(Median as isize + 1), right.children.as_mut_ptr(), NumberMoved + 1);
+		}
+		right.len = NumberMoved;
+		
+		let median_key = read(left.key(Median));
+		let median_value = read(left.value(Median));
+		left.len = Median;
+		
+		self.insert_at(index, median_key, median_value);
+		self.insert_child(index + 1, right);
+	}
+	
+	/// Merges the child at `index + 1` and the key at `index` into the child at `index`.
+	unsafe fn merge_children(&mut self, cto_pool_alloc: &mut CtoPoolAlloc, index: usize)
+	{
+		let (key, value) = self.remove_at(index);
+		let right = self.remove_child(index + 1);
+		let left = self.child_reference(index);
+		let right_reference = &mut *right;
+		
+		let left_len = left.len;
+		write(left.key(left_len), key);
+		write(left.value(left_len), value);
+		copy_nonoverlapping(right_reference.key(0), left.key(left_len + 1), right_reference.len);
+		copy_nonoverlapping(right_reference.value(0), left.value(left_len + 1), right_reference.len);
+		if !left.is_leaf
+		{
+			copy_nonoverlapping(right_reference.children.as_ptr(), left.children.as_mut_ptr().offset(left_len as isize + 1), right_reference.len + 1);
+		}
+		left.len = left_len + 1 + right_reference.len;
+		
+		Self::free(cto_pool_alloc, right)
+	}
+	
+	/// Ensures the child at `index` can lend a key before descending into it, by borrowing from a sibling or merging with one.
+	/// Returns the index of the child to descend into, which changes if the child was merged into its left sibling.
+	unsafe fn prepare_child_for_removal(&mut self, cto_pool_alloc: &mut CtoPoolAlloc, index: usize) -> usize
+	{
+		if self.child_reference(index).can_lend()
+		{
+			return index;
+		}
+		
+		if index > 0 && self.child_reference(index - 1).can_lend()
+		{
+			// Rotate right through the key at `index - 1`.
+			let left = self.child_reference(index - 1);
+			let child = self.child_reference(index);
+			
+			let left_len = left.len;
+			let (left_key, left_value) = left.remove_at(left_len - 1);
+			let separator_key = replace(&mut *self.key(index - 1), left_key);
+			let separator_value = replace(&mut *self.value(index - 1), left_value);
+			child.insert_at(0, separator_key, separator_value);
+			if !child.is_leaf
+			{
+				let left_last_child = left.remove_child(left_len);
+				child.insert_child(0, left_last_child);
+			}
+			return index;
+		}
+		
+		if index < self.len && self.child_reference(index + 1).can_lend()
+		{
+			// Rotate left through the key at `index`.
+			let right = self.child_reference(index + 1);
+			let child = self.child_reference(index);
+			
+			let (right_key, right_value) = right.remove_at(0);
+			let separator_key = replace(&mut *self.key(index), right_key);
+			let separator_value = replace(&mut *self.value(index), right_value);
+			let child_len = child.len;
+			child.insert_at(child_len, separator_key, separator_value);
+			if !child.is_leaf
+			{
+				let right_first_child = right.remove_child(0);
+				child.insert_child(child_len + 1, right_first_child);
+			}
+			return index;
+		}
+		
+		if index < self.len
+		{
+			self.merge_children(cto_pool_alloc, index);
+			index
+		}
+		else
+		{
+			self.merge_children(cto_pool_alloc, index - 1);
+			index - 1
+		}
+	}
+	
+	/// Removes the smallest key in the subtree; `self` must be able to lend a key (or be the root).
+	unsafe fn remove_first(&mut self, cto_pool_alloc: &mut CtoPoolAlloc) -> (K, V)
+	{
+		let mut node = self;
+		loop
+		{
+			if node.is_leaf
+			{
+				return node.remove_at(0);
+			}
+			let index = node.prepare_child_for_removal(cto_pool_alloc, 0);
+			node = node.child_reference(index);
+		}
+	}
+	
+	/// Removes the largest key in the subtree; `self` must be able to lend a key (or be the root).
+	unsafe fn remove_last(&mut self, cto_pool_alloc: &mut CtoPoolAlloc) -> (K, V)
+	{
+		let mut node = self;
+		loop
+		{
+			let len = node.len;
+			if node.is_leaf
+			{
+				return node.remove_at(len - 1);
+			}
+			let index = node.prepare_child_for_removal(cto_pool_alloc, len);
+			node = node.child_reference(index);
+		}
+	}
+	
+	/// Removes `key` from the subtree; `self` must be able to lend a key (or be the root).
+	unsafe fn remove<Q: ?Sized + Ord>(&mut self, cto_pool_alloc: &mut CtoPoolAlloc, key: &Q) -> Option<(K, V)>
+	where K: Borrow<Q>
+	{
+		let mut node = self;
+		loop
+		{
+			match node.search(key)
+			{
+				Ok(index) =>
+				{
+					if node.is_leaf
+					{
+						return Some(node.remove_at(index));
+					}
+					
+					if node.child_reference(index).can_lend()
+					{
+						let (predecessor_key, predecessor_value) = node.child_reference(index).remove_last(cto_pool_alloc);
+						return Some((replace(&mut *node.key(index), predecessor_key), replace(&mut *node.value(index), predecessor_value)));
+					}
+					
+					if node.child_reference(index + 1).can_lend()
+					{
+						let (successor_key, successor_value) = node.child_reference(index + 1).remove_first(cto_pool_alloc);
+						return Some((replace(&mut *node.key(index), successor_key), replace(&mut *node.value(index), successor_value)));
+					}
+					
+					node.merge_children(cto_pool_alloc, index);
+					node = node.child_reference(index);
+				}
+				
+				Err(index) =>
+				{
+					if node.is_leaf
+					{
+						return None;
+					}
+					
+					let index = node.prepare_child_for_removal(cto_pool_alloc, index);
+					node = node.child_reference(index);
+				}
+			}
+		}
+	}
+}
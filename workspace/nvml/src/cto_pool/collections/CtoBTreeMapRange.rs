@@ -0,0 +1,63 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// An iterator over a sub-range of entries in a `CtoBTreeMap`.
+pub struct CtoBTreeMapRange<'a, K: 'a + CtoSafe, V: 'a + CtoSafe>
+{
+	raw_range: CtoBTreeMapRawRange<K, V>,
+	marker: PhantomData<&'a CtoBTreeMap<K, V>>,
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> Clone for CtoBTreeMapRange<'a, K, V>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		Self
+		{
+			raw_range: self.raw_range.clone(),
+			marker: PhantomData,
+		}
+	}
+}
+
+impl<'a, K: 'a + CtoSafe + Debug, V: 'a + CtoSafe + Debug> Debug for CtoBTreeMapRange<'a, K, V>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_list().entries(self.clone()).finish()
+	}
+}
+
+unsafe impl<'a, K: CtoSafe + Sync, V: CtoSafe + Sync> Sync for CtoBTreeMapRange<'a, K, V>
+{
+}
+
+unsafe impl<'a, K: CtoSafe + Sync, V: CtoSafe + Sync> Send for CtoBTreeMapRange<'a, K, V>
+{
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> Iterator for CtoBTreeMapRange<'a, K, V>
+{
+	type Item = (&'a K, &'a V);
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.raw_range.next().map(|(key, value)| unsafe { (&*key, &*value) })
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> DoubleEndedIterator for CtoBTreeMapRange<'a, K, V>
+{
+	#[inline(always)]
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		self.raw_range.next_back().map(|(key, value)| unsafe { (&*key, &*value) })
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> FusedIterator for CtoBTreeMapRange<'a, K, V>
+{
+}
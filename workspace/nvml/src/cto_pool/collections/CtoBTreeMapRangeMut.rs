@@ -0,0 +1,56 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// A mutable iterator over a sub-range of entries in a `CtoBTreeMap`.
+pub struct CtoBTreeMapRangeMut<'a, K: 'a + CtoSafe, V: 'a + CtoSafe>
+{
+	raw_range: CtoBTreeMapRawRange<K, V>,
+	marker: PhantomData<&'a mut CtoBTreeMap<K, V>>,
+}
+
+impl<'a, K: 'a + CtoSafe + Debug, V: 'a + CtoSafe + Debug> Debug for CtoBTreeMapRangeMut<'a, K, V>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		let mut raw_range = self.raw_range.clone();
+		let mut list = f.debug_list();
+		while let Some((key, value)) = raw_range.next()
+		{
+			list.entry(&unsafe { (&*key, &*value) });
+		}
+		list.finish()
+	}
+}
+
+unsafe impl<'a, K: CtoSafe + Sync, V: CtoSafe + Sync> Sync for CtoBTreeMapRangeMut<'a, K, V>
+{
+}
+
+unsafe impl<'a, K: CtoSafe + Send, V: CtoSafe + Send> Send for CtoBTreeMapRangeMut<'a, K, V>
+{
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> Iterator for CtoBTreeMapRangeMut<'a, K, V>
+{
+	type Item = (&'a K, &'a mut V);
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.raw_range.next().map(|(key, value)| unsafe { (&*key, &mut *value) })
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> DoubleEndedIterator for CtoBTreeMapRangeMut<'a, K, V>
+{
+	#[inline(always)]
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		self.raw_range.next_back().map(|(key, value)| unsafe { (&*key, &mut *value) })
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> FusedIterator for CtoBTreeMapRangeMut<'a, K, V>
+{
+}
@@ -0,0 +1,126 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// Walks the keys and values of a `CtoBTreeMap` between two cursors, inclusive, from either end.
+struct CtoBTreeMapRawRange<K: CtoSafe, V: CtoSafe>
+{
+	front: CtoBTreeMapCursor<K, V>,
+	back: CtoBTreeMapCursor<K, V>,
+	finished: bool,
+}
+
+impl<K: CtoSafe, V: CtoSafe> Clone for CtoBTreeMapRawRange<K, V>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		Self
+		{
+			front: self.front.clone(),
+			back: self.back.clone(),
+			finished: self.finished,
+		}
+	}
+}
+
+impl<K: CtoSafe, V: CtoSafe> CtoBTreeMapRawRange<K, V>
+{
+	#[inline(always)]
+	fn empty() -> Self
+	{
+		Self
+		{
+			front: CtoBTreeMapCursor::empty(),
+			back: CtoBTreeMapCursor::empty(),
+			finished: true,
+		}
+	}
+	
+	#[inline(always)]
+	fn all(root: *mut CtoBTreeMapNode<K, V>) -> Self
+	{
+		if root.is_null()
+		{
+			return Self::empty();
+		}
+		
+		unsafe
+		{
+			Self
+			{
+				front: CtoBTreeMapCursor::leftmost(root),
+				back: CtoBTreeMapCursor::rightmost(root),
+				finished: false,
+			}
+		}
+	}
+	
+	unsafe fn new<Q: ?Sized + Ord>(root: *mut CtoBTreeMapNode<K, V>, start: Bound<&Q>, end: Bound<&Q>) -> Self
+	where K: Borrow<Q>
+	{
+		let front = CtoBTreeMapCursor::lower_bound(root, start);
+		let back = CtoBTreeMapCursor::upper_bound(root, end);
+		
+		let finished = match (front.current_forwards(), back.current_backwards())
+		{
+			(Some((front_key, _)), Some((back_key, _))) => (&*front_key).borrow() > (&*back_key).borrow(),
+			_ => true,
+		};
+		
+		Self
+		{
+			front,
+			back,
+			finished,
+		}
+	}
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<(*mut K, *mut V)>
+	{
+		if self.finished
+		{
+			return None;
+		}
+		
+		unsafe
+		{
+			let current = self.front.current_forwards();
+			if current.map(|(key, _)| key) == self.back.current_backwards().map(|(key, _)| key)
+			{
+				self.finished = true;
+			}
+			else
+			{
+				self.front.advance_forwards();
+				debug_assert!(!self.front.is_exhausted(), "front passed back");
+			}
+			current
+		}
+	}
+	
+	#[inline(always)]
+	fn next_back(&mut self) -> Option<(*mut K, *mut V)>
+	{
+		if self.finished
+		{
+			return None;
+		}
+		
+		unsafe
+		{
+			let current = self.back.current_backwards();
+			if current.map(|(key, _)| key) == self.front.current_forwards().map(|(key, _)| key)
+			{
+				self.finished = true;
+			}
+			else
+			{
+				self.back.advance_backwards();
+				debug_assert!(!self.back.is_exhausted(), "back passed front");
+			}
+			current
+		}
+	}
+}
@@ -0,0 +1,63 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// An iterator over the values of a `CtoBTreeMap`, in order by key.
+pub struct CtoBTreeMapValues<'a, K: 'a + CtoSafe, V: 'a + CtoSafe>
+{
+	iter: CtoBTreeMapIter<'a, K, V>,
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> Clone for CtoBTreeMapValues<'a, K, V>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		Self
+		{
+			iter: self.iter.clone(),
+		}
+	}
+}
+
+impl<'a, K: 'a + CtoSafe + Debug, V: 'a + CtoSafe + Debug> Debug for CtoBTreeMapValues<'a, K, V>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_tuple("CtoBTreeMapValues").field(&self.iter).finish()
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> Iterator for CtoBTreeMapValues<'a, K, V>
+{
+	type Item = &'a V;
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.iter.next().map(|(_, value)| value)
+	}
+	
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.iter.size_hint()
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> DoubleEndedIterator for CtoBTreeMapValues<'a, K, V>
+{
+	#[inline(always)]
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		self.iter.next_back().map(|(_, value)| value)
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> ExactSizeIterator for CtoBTreeMapValues<'a, K, V>
+{
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> FusedIterator for CtoBTreeMapValues<'a, K, V>
+{
+}
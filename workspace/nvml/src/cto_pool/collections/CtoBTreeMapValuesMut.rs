@@ -0,0 +1,51 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// A mutable iterator over the values of a `CtoBTreeMap`, in order by key.
+pub struct CtoBTreeMapValuesMut<'a, K: 'a + CtoSafe, V: 'a + CtoSafe>
+{
+	iter_mut: CtoBTreeMapIterMut<'a, K, V>,
+}
+
+impl<'a, K: 'a + CtoSafe + Debug, V: 'a + CtoSafe + Debug> Debug for CtoBTreeMapValuesMut<'a, K, V>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_tuple("CtoBTreeMapValuesMut").field(&self.iter_mut).finish()
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> Iterator for CtoBTreeMapValuesMut<'a, K, V>
+{
+	type Item = &'a mut V;
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.iter_mut.next().map(|(_, value)| value)
+	}
+	
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.iter_mut.size_hint()
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> DoubleEndedIterator for CtoBTreeMapValuesMut<'a, K, V>
+{
+	#[inline(always)]
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		self.iter_mut.next_back().map(|(_, value)| value)
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> ExactSizeIterator for CtoBTreeMapValuesMut<'a, K, V>
+{
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> FusedIterator for CtoBTreeMapValuesMut<'a, K, V>
+{
+}
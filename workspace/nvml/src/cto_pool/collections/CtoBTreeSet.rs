@@ -0,0 +1,263 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// CTO pool equivalent to a Rust BTreeSet.
+/// A thin wrapper around a `CtoBTreeMap<T, ()>`.
+pub struct CtoBTreeSet<T: CtoSafe>
+{
+	map: CtoBTreeMap<T, ()>,
+}
+
+impl<T: CtoSafe> CtoSafe for CtoBTreeSet<T>
+{
+	#[inline(always)]
+	fn cto_pool_opened(&mut self, cto_pool_arc: &CtoPoolArc)
+	{
+		self.map.cto_pool_opened(cto_pool_arc)
+	}
+}
+
+impl<T: CtoSafe + Ord + Clone> Clone for CtoBTreeSet<T>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		Self
+		{
+			map: self.map.clone(),
+		}
+	}
+}
+
+impl<T: CtoSafe + PartialEq> PartialEq for CtoBTreeSet<T>
+{
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool
+	{
+		self.map == other.map
+	}
+}
+
+impl<T: CtoSafe + Eq> Eq for CtoBTreeSet<T>
+{
+}
+
+impl<T: CtoSafe + Hash> Hash for CtoBTreeSet<T>
+{
+	#[inline(always)]
+	fn hash<H: Hasher>(&self, state: &mut H)
+	{
+		for value in self.iter()
+		{
+			value.hash(state);
+		}
+	}
+}
+
+impl<T: CtoSafe + Debug> Debug for CtoBTreeSet<T>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_set().entries(self.iter()).finish()
+	}
+}
+
+impl<T: CtoSafe + Ord> Extend<T> for CtoBTreeSet<T>
+{
+	#[inline(always)]
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I)
+	{
+		for value in iter
+		{
+			self.insert(value);
+		}
+	}
+}
+
+impl<'a, T: 'a + CtoSafe + Ord + Copy> Extend<&'a T> for CtoBTreeSet<T>
+{
+	#[inline(always)]
+	fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I)
+	{
+		self.extend(iter.into_iter().cloned())
+	}
+}
+
+impl<T: CtoSafe> IntoIterator for CtoBTreeSet<T>
+{
+	type Item = T;
+	
+	type IntoIter = CtoBTreeSetIntoIter<T>;
+	
+	#[inline(always)]
+	fn into_iter(self) -> Self::IntoIter
+	{
+		CtoBTreeSetIntoIter
+		{
+			into_iter: self.map.into_iter(),
+		}
+	}
+}
+
+impl<'a, T: CtoSafe> IntoIterator for &'a CtoBTreeSet<T>
+{
+	type Item = &'a T;
+	
+	type IntoIter = CtoBTreeSetIter<'a, T>;
+	
+	#[inline(always)]
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter()
+	}
+}
+
+impl<T: CtoSafe> CtoBTreeSet<T>
+{
+	/// Makes a new empty `CtoBTreeSet`.
+	/// Does not allocate anything on its own.
+	#[inline(always)]
+	pub fn new(cto_pool_alloc: CtoPoolAlloc) -> Self
+	{
+		Self
+		{
+			map: CtoBTreeMap::new(cto_pool_alloc),
+		}
+	}
+	
+	/// Returns the number of elements in the set.
+	#[inline(always)]
+	pub fn len(&self) -> usize
+	{
+		self.map.len()
+	}
+	
+	/// Returns `true` if the set contains no elements.
+	#[inline(always)]
+	pub fn is_empty(&self) -> bool
+	{
+		self.map.is_empty()
+	}
+	
+	/// Clears the set, removing all values.
+	#[inline(always)]
+	pub fn clear(&mut self)
+	{
+		self.map.clear()
+	}
+	
+	/// Gets an iterator that visits the values in the set in ascending order.
+	#[inline(always)]
+	pub fn iter(&self) -> CtoBTreeSetIter<T>
+	{
+		CtoBTreeSetIter
+		{
+			keys: self.map.keys(),
+		}
+	}
+	
+	/// Returns the first (smallest) value, if any.
+	#[inline(always)]
+	pub fn first(&self) -> Option<&T>
+	{
+		self.map.first().map(|(value, _)| value)
+	}
+	
+	/// Returns the last (largest) value, if any.
+	#[inline(always)]
+	pub fn last(&self) -> Option<&T>
+	{
+		self.map.last().map(|(value, _)| value)
+	}
+	
+	/// Removes and returns the first (smallest) value, if any.
+	#[inline(always)]
+	pub fn pop_first(&mut self) -> Option<T>
+	{
+		self.map.pop_first().map(|(value, _)| value)
+	}
+	
+	/// Removes and returns the last (largest) value, if any.
+	#[inline(always)]
+	pub fn pop_last(&mut self) -> Option<T>
+	{
+		self.map.pop_last().map(|(value, _)| value)
+	}
+}
+
+impl<T: CtoSafe + Ord> CtoBTreeSet<T>
+{
+	/// Returns `true` if the set contains a value.
+	#[inline(always)]
+	pub fn contains<Q: ?Sized + Ord>(&self, value: &Q) -> bool
+	where T: Borrow<Q>
+	{
+		self.map.contains_key(value)
+	}
+	
+	/// Returns a reference to the value in the set, if any, that is equal to the given value.
+	#[inline(always)]
+	pub fn get<Q: ?Sized + Ord>(&self, value: &Q) -> Option<&T>
+	where T: Borrow<Q>
+	{
+		self.map.find(value).map(|(key, _)| unsafe { &*key })
+	}
+	
+	/// Adds a value to the set.
+	/// If the set did not have this value present, `true` is returned.
+	/// If the set did have this value present, `false` is returned, and the entry is not updated.
+	#[inline(always)]
+	pub fn insert(&mut self, value: T) -> bool
+	{
+		self.map.insert(value, ()).is_none()
+	}
+	
+	/// Removes a value from the set. Returns `true` if the value was present in the set.
+	#[inline(always)]
+	pub fn remove<Q: ?Sized + Ord>(&mut self, value: &Q) -> bool
+	where T: Borrow<Q>
+	{
+		self.map.remove(value).is_some()
+	}
+	
+	/// Removes and returns the value in the set, if any, that is equal to the given one.
+	#[inline(always)]
+	pub fn take<Q: ?Sized + Ord>(&mut self, value: &Q) -> Option<T>
+	where T: Borrow<Q>
+	{
+		self.map.remove_entry(value).map(|(value, _)| value)
+	}
+	
+	/// Constructs a double-ended iterator over a sub-range of elements in the set.
+	/// Panics if the range start is greater than the range end, or if the range start and end are equal and both excluded.
+	#[inline(always)]
+	pub fn range<K: ?Sized + Ord, R: RangeArgument<K>>(&self, range: R) -> CtoBTreeSetRange<T>
+	where T: Borrow<K>
+	{
+		CtoBTreeSetRange
+		{
+			range: self.map.range(range),
+		}
+	}
+	
+	/// Splits the collection into two at the given value.
+	/// Returns everything after the given value, including the value.
+	#[inline(always)]
+	pub fn split_off<Q: ?Sized + Ord>(&mut self, value: &Q) -> Self
+	where T: Borrow<Q>
+	{
+		Self
+		{
+			map: self.map.split_off(value),
+		}
+	}
+	
+	/// Moves all elements from `other` into `self`, leaving `other` empty.
+	#[inline(always)]
+	pub fn append(&mut self, other: &mut Self)
+	{
+		self.map.append(&mut other.map)
+	}
+}
@@ -0,0 +1,51 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// An owning iterator over the values of a `CtoBTreeSet`, in ascending order.
+pub struct CtoBTreeSetIntoIter<T: CtoSafe>
+{
+	into_iter: CtoBTreeMapIntoIter<T, ()>,
+}
+
+impl<T: CtoSafe + Debug> Debug for CtoBTreeSetIntoIter<T>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_tuple("CtoBTreeSetIntoIter").field(&self.into_iter).finish()
+	}
+}
+
+impl<T: CtoSafe> Iterator for CtoBTreeSetIntoIter<T>
+{
+	type Item = T;
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.into_iter.next().map(|(value, _)| value)
+	}
+	
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.into_iter.size_hint()
+	}
+}
+
+impl<T: CtoSafe> DoubleEndedIterator for CtoBTreeSetIntoIter<T>
+{
+	#[inline(always)]
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		self.into_iter.next_back().map(|(value, _)| value)
+	}
+}
+
+impl<T: CtoSafe> ExactSizeIterator for CtoBTreeSetIntoIter<T>
+{
+}
+
+impl<T: CtoSafe> FusedIterator for CtoBTreeSetIntoIter<T>
+{
+}
@@ -0,0 +1,63 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// An iterator over the values of a `CtoBTreeSet`, in ascending order.
+pub struct CtoBTreeSetIter<'a, T: 'a + CtoSafe>
+{
+	keys: CtoBTreeMapKeys<'a, T, ()>,
+}
+
+impl<'a, T: 'a + CtoSafe> Clone for CtoBTreeSetIter<'a, T>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		Self
+		{
+			keys: self.keys.clone(),
+		}
+	}
+}
+
+impl<'a, T: 'a + CtoSafe + Debug> Debug for CtoBTreeSetIter<'a, T>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_list().entries(self.clone()).finish()
+	}
+}
+
+impl<'a, T: 'a + CtoSafe> Iterator for CtoBTreeSetIter<'a, T>
+{
+	type Item = &'a T;
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.keys.next()
+	}
+	
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.keys.size_hint()
+	}
+}
+
+impl<'a, T: 'a + CtoSafe> DoubleEndedIterator for CtoBTreeSetIter<'a, T>
+{
+	#[inline(always)]
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		self.keys.next_back()
+	}
+}
+
+impl<'a, T: 'a + CtoSafe> ExactSizeIterator for CtoBTreeSetIter<'a, T>
+{
+}
+
+impl<'a, T: 'a + CtoSafe> FusedIterator for CtoBTreeSetIter<'a, T>
+{
+}
@@ -0,0 +1,59 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// An iterator over a sub-range of values in a `CtoBTreeSet`.
+pub struct CtoBTreeSetRange<'a, T: 'a + CtoSafe>
+{
+	range: CtoBTreeMapRange<'a, T, ()>,
+}
+
+impl<'a, T: 'a + CtoSafe> Clone for CtoBTreeSetRange<'a, T>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		Self
+		{
+			range: self.range.clone(),
+		}
+	}
+}
+
+impl<'a, T: 'a + CtoSafe + Debug> Debug for CtoBTreeSetRange<'a, T>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_list().entries(self.clone()).finish()
+	}
+}
+
+impl<'a, T: 'a + CtoSafe> Iterator for CtoBTreeSetRange<'a, T>
+{
+	type Item = &'a T;
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.range.next().map(|(value, _)| value)
+	}
+	
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.range.size_hint()
+	}
+}
+
+impl<'a, T: 'a + CtoSafe> DoubleEndedIterator for CtoBTreeSetRange<'a, T>
+{
+	#[inline(always)]
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		self.range.next_back().map(|(value, _)| value)
+	}
+}
+
+impl<'a, T: 'a + CtoSafe> FusedIterator for CtoBTreeSetRange<'a, T>
+{
+}
@@ -0,0 +1,341 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// CTO pool equivalent to a Rust BinaryHeap.
+/// A priority queue implemented with a binary heap stored in a `CtoVec`; `pop()` returns the greatest element.
+pub struct CtoBinaryHeap<T: CtoSafe + Ord>
+{
+	data: CtoVec<T>,
+}
+
+impl<T: CtoSafe + Ord> CtoSafe for CtoBinaryHeap<T>
+{
+	#[inline(always)]
+	fn cto_pool_opened(&mut self, cto_pool_arc: &CtoPoolArc)
+	{
+		self.data.cto_pool_opened(cto_pool_arc)
+	}
+}
+
+impl<T: CtoSafe + Ord + Clone> Clone for CtoBinaryHeap<T>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		let mut data = CtoVec::with_capacity(self.len(), self.data.buf.alloc().clone());
+		data.extend_from_slice(&self.data);
+		Self
+		{
+			data,
+		}
+	}
+}
+
+impl<T: CtoSafe + Ord + Debug> Debug for CtoBinaryHeap<T>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_list().entries(self.iter()).finish()
+	}
+}
+
+impl<T: CtoSafe + Ord> Extend<T> for CtoBinaryHeap<T>
+{
+	#[inline(always)]
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I)
+	{
+		let iter = iter.into_iter();
+		let (lower_bound, _) = iter.size_hint();
+		self.reserve(lower_bound);
+		
+		for element in iter
+		{
+			self.push(element);
+		}
+	}
+}
+
+impl<'a, T: 'a + CtoSafe + Ord + Copy> Extend<&'a T> for CtoBinaryHeap<T>
+{
+	#[inline(always)]
+	fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I)
+	{
+		self.extend(iter.into_iter().cloned())
+	}
+}
+
+impl<T: CtoSafe + Ord> From<CtoVec<T>> for CtoBinaryHeap<T>
+{
+	#[inline(always)]
+	fn from(vec: CtoVec<T>) -> Self
+	{
+		let mut heap = Self
+		{
+			data: vec,
+		};
+		heap.rebuild();
+		heap
+	}
+}
+
+impl<T: CtoSafe + Ord> IntoIterator for CtoBinaryHeap<T>
+{
+	type Item = T;
+	
+	type IntoIter = CtoVecIntoIter<T>;
+	
+	/// Iterates in arbitrary order.
+	#[inline(always)]
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.data.into_iter()
+	}
+}
+
+impl<'a, T: CtoSafe + Ord> IntoIterator for &'a CtoBinaryHeap<T>
+{
+	type Item = &'a T;
+	
+	type IntoIter = slice::Iter<'a, T>;
+	
+	/// Iterates in arbitrary order.
+	#[inline(always)]
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter()
+	}
+}
+
+impl<T: CtoSafe + Ord> CtoBinaryHeap<T>
+{
+	/// Creates an empty `CtoBinaryHeap`.
+	#[inline(always)]
+	pub fn new(cto_pool_alloc: CtoPoolAlloc) -> Self
+	{
+		Self
+		{
+			data: CtoVec::new(cto_pool_alloc),
+		}
+	}
+	
+	/// Creates an empty `CtoBinaryHeap` with space for at least `capacity` elements.
+	#[inline(always)]
+	pub fn with_capacity(capacity: usize, cto_pool_alloc: CtoPoolAlloc) -> Self
+	{
+		Self
+		{
+			data: CtoVec::with_capacity(capacity, cto_pool_alloc),
+		}
+	}
+	
+	/// Returns an iterator visiting all values in the underlying vector, in arbitrary order.
+	#[inline(always)]
+	pub fn iter(&self) -> slice::Iter<T>
+	{
+		self.data.iter()
+	}
+	
+	/// Returns the greatest item in the binary heap, or `None` if it is empty.
+	#[inline(always)]
+	pub fn peek(&self) -> Option<&T>
+	{
+		self.data.get(0)
+	}
+	
+	/// Returns a mutable reference to the greatest item in the binary heap, or `None` if it is empty.
+	/// The heap is restored when the returned `CtoBinaryHeapPeekMut` is dropped.
+	#[inline(always)]
+	pub fn peek_mut(&mut self) -> Option<CtoBinaryHeapPeekMut<T>>
+	{
+		if self.is_empty()
+		{
+			None
+		}
+		else
+		{
+			Some
+			(
+				CtoBinaryHeapPeekMut
+				{
+					heap: self,
+				}
+			)
+		}
+	}
+	
+	/// Returns the number of elements the binary heap can hold without reallocating.
+	#[inline(always)]
+	pub fn capacity(&self) -> usize
+	{
+		self.data.capacity()
+	}
+	
+	/// Reserves capacity for at least `additional` more elements to be inserted.
+	#[inline(always)]
+	pub fn reserve(&mut self, additional: usize)
+	{
+		self.data.reserve(additional)
+	}
+	
+	/// Discards as much additional capacity as possible.
+	#[inline(always)]
+	pub fn shrink_to_fit(&mut self)
+	{
+		self.data.shrink_to_fit()
+	}
+	
+	/// Removes the greatest item from the binary heap and returns it, or `None` if it is empty.
+	#[inline(always)]
+	pub fn pop(&mut self) -> Option<T>
+	{
+		self.data.pop().map(|mut item|
+		{
+			if !self.is_empty()
+			{
+				swap(&mut item, &mut self.data[0]);
+				self.sift_down(0);
+			}
+			item
+		})
+	}
+	
+	/// Pushes an item onto the binary heap.
+	#[inline(always)]
+	pub fn push(&mut self, item: T)
+	{
+		self.data.push(item);
+		let index = self.len() - 1;
+		self.sift_up(index);
+	}
+	
+	/// Consumes the `CtoBinaryHeap` and returns the underlying vector in arbitrary order.
+	#[inline(always)]
+	pub fn into_vec(self) -> CtoVec<T>
+	{
+		self.data
+	}
+	
+	/// Consumes the `CtoBinaryHeap` and returns a vector in sorted (ascending) order.
+	#[inline(always)]
+	pub fn into_sorted_vec(mut self) -> CtoVec<T>
+	{
+		let mut end = self.len();
+		while end > 1
+		{
+			end -= 1;
+			self.data.swap(0, end);
+			self.sift_down_range(0, end);
+		}
+		self.into_vec()
+	}
+	
+	/// Returns the length of the binary heap.
+	#[inline(always)]
+	pub fn len(&self) -> usize
+	{
+		self.data.len()
+	}
+	
+	/// Checks if the binary heap is empty.
+	#[inline(always)]
+	pub fn is_empty(&self) -> bool
+	{
+		self.len() == 0
+	}
+	
+	/// Clears the binary heap, returning an iterator over the removed elements in arbitrary order.
+	#[inline(always)]
+	pub fn drain(&mut self) -> CtoVecDrain<T>
+	{
+		self.data.drain(..)
+	}
+	
+	/// Drops all items from the binary heap.
+	#[inline(always)]
+	pub fn clear(&mut self)
+	{
+		self.data.clear()
+	}
+	
+	/// Moves all the elements of `other` into `self`, leaving `other` empty.
+	#[inline(always)]
+	pub fn append(&mut self, other: &mut Self)
+	{
+		if self.len() < other.len()
+		{
+			swap(self, other);
+		}
+		
+		self.reserve(other.len());
+		for element in other.drain()
+		{
+			self.push(element);
+		}
+	}
+	
+	#[inline(always)]
+	fn sift_up(&mut self, mut index: usize)
+	{
+		while index > 0
+		{
+			let parent = (index - 1) / 2;
+			if self.data[index] <= self.data[parent]
+			{
+				break;
+			}
+			self.data.swap(index, parent);
+			index = parent;
+		}
+	}
+	
+	#[inline(always)]
+	fn sift_down(&mut self, index: usize)
+	{
+		let len = self.len();
+		self.sift_down_range(index, len)
+	}
+	
+	#[inline(always)]
+	fn sift_down_range(&mut self, mut index: usize, end: usize)
+	{
+		loop
+		{
+			let left = 2 * index + 1;
+			if left >= end
+			{
+				break;
+			}
+			
+			let right = left + 1;
+			let greatest_child = if right < end && self.data[right] > self.data[left]
+			{
+				right
+			}
+			else
+			{
+				left
+			};
+			
+			if self.data[index] >= self.data[greatest_child]
+			{
+				break;
+			}
+			self.data.swap(index, greatest_child);
+			index = greatest_child;
+		}
+	}
+	
+	#[inline(always)]
+	fn rebuild(&mut self)
+	{
+		let len = self.len();
+		let mut index = len / 2;
+		while index > 0
+		{
+			index -= 1;
+			self.sift_down(index);
+		}
+	}
+}
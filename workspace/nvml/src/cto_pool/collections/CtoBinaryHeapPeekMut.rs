@@ -0,0 +1,59 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// A mutable reference to the greatest item in a `CtoBinaryHeap`.
+/// Created using `CtoBinaryHeap::peek_mut()`; the heap is restored when this is dropped.
+pub struct CtoBinaryHeapPeekMut<'a, T: 'a + CtoSafe + Ord>
+{
+	heap: &'a mut CtoBinaryHeap<T>,
+}
+
+impl<'a, T: 'a + CtoSafe + Ord + Debug> Debug for CtoBinaryHeapPeekMut<'a, T>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_tuple("CtoBinaryHeapPeekMut").field(&self.heap.data[0]).finish()
+	}
+}
+
+impl<'a, T: 'a + CtoSafe + Ord> Drop for CtoBinaryHeapPeekMut<'a, T>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		self.heap.sift_down(0)
+	}
+}
+
+impl<'a, T: 'a + CtoSafe + Ord> Deref for CtoBinaryHeapPeekMut<'a, T>
+{
+	type Target = T;
+	
+	#[inline(always)]
+	fn deref(&self) -> &T
+	{
+		&self.heap.data[0]
+	}
+}
+
+impl<'a, T: 'a + CtoSafe + Ord> DerefMut for CtoBinaryHeapPeekMut<'a, T>
+{
+	#[inline(always)]
+	fn deref_mut(&mut self) -> &mut T
+	{
+		&mut self.heap.data[0]
+	}
+}
+
+impl<'a, T: 'a + CtoSafe + Ord> CtoBinaryHeapPeekMut<'a, T>
+{
+	/// Removes the peeked value from the heap and returns it.
+	#[inline(always)]
+	pub fn pop(this: Self) -> T
+	{
+		let value = this.heap.pop().unwrap();
+		forget(this);
+		value
+	}
+}
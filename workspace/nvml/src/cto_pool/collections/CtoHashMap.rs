@@ -0,0 +1,731 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// CTO pool equivalent to a Rust HashMap.
+///
+/// Uses open addressing with linear probing and backward shift deletion, so there are no tombstones.
+/// Keys are hashed using SipHash-2-4 with hash keys chosen at random when the map is created and persisted alongside it; hashes are therefore stable across process restarts.
+/// (As for all CTO pool structures, the pool must be reopened on the same architecture).
+pub struct CtoHashMap<K: CtoSafe, V: CtoSafe>
+{
+	hashes: RawVec<u64, CtoPoolAlloc>,
+	pairs: RawVec<(K, V), CtoPoolAlloc>,
+	len: usize,
+	hash_keys: (u64, u64),
+}
+
+impl<K: CtoSafe, V: CtoSafe> Drop for CtoHashMap<K, V>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		if needs_drop::<(K, V)>()
+		{
+			let mut raw_iter = self.raw_iter();
+			while let Some(pair) = raw_iter.next()
+			{
+				unsafe { drop_in_place(pair) }
+			}
+		}
+		// RawVecs handle deallocation
+	}
+}
+
+impl<K: CtoSafe, V: CtoSafe> CtoSafe for CtoHashMap<K, V>
+{
+	#[inline(always)]
+	fn cto_pool_opened(&mut self, cto_pool_arc: &CtoPoolArc)
+	{
+		self.hashes.alloc_mut().cto_pool_opened(cto_pool_arc);
+		self.pairs.alloc_mut().cto_pool_opened(cto_pool_arc);
+		
+		let mut raw_iter = self.raw_iter();
+		while let Some(pair) = raw_iter.next()
+		{
+			let pair = unsafe { &mut *pair };
+			pair.0.cto_pool_opened(cto_pool_arc);
+			pair.1.cto_pool_opened(cto_pool_arc);
+		}
+	}
+}
+
+impl<K: CtoSafe + Eq + Hash + Clone, V: CtoSafe + Clone> Clone for CtoHashMap<K, V>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		let mut clone = Self::with_capacity(self.len, self.hashes.alloc().clone());
+		for (key, value) in self.iter()
+		{
+			clone.insert(key.clone(), value.clone());
+		}
+		clone
+	}
+}
+
+impl<K: CtoSafe + Eq + Hash, V: CtoSafe + PartialEq> PartialEq for CtoHashMap<K, V>
+{
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool
+	{
+		if self.len != other.len
+		{
+			return false;
+		}
+		
+		self.iter().all(|(key, value)| other.get(key).map_or(false, |other_value| *value == *other_value))
+	}
+}
+
+impl<K: CtoSafe + Eq + Hash, V: CtoSafe + Eq> Eq for CtoHashMap<K, V>
+{
+}
+
+impl<K: CtoSafe + Debug, V: CtoSafe + Debug> Debug for CtoHashMap<K, V>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_map().entries(self.iter()).finish()
+	}
+}
+
+impl<'a, K: CtoSafe + Eq + Hash + Borrow<Q>, Q: ?Sized + Eq + Hash, V: CtoSafe> Index<&'a Q> for CtoHashMap<K, V>
+{
+	type Output = V;
+	
+	#[inline(always)]
+	fn index(&self, key: &Q) -> &V
+	{
+		self.get(key).expect("no entry found for key")
+	}
+}
+
+impl<K: CtoSafe + Eq + Hash, V: CtoSafe> Extend<(K, V)> for CtoHashMap<K, V>
+{
+	#[inline(always)]
+	fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I)
+	{
+		let iter = iter.into_iter();
+		let (lower_bound, _) = iter.size_hint();
+		let reserve = if self.is_empty()
+		{
+			lower_bound
+		}
+		else
+		{
+			(lower_bound + 1) / 2
+		};
+		self.reserve(reserve);
+		
+		for (key, value) in iter
+		{
+			self.insert(key, value);
+		}
+	}
+}
+
+impl<'a, K: 'a + CtoSafe + Eq + Hash + Copy, V: 'a + CtoSafe + Copy> Extend<(&'a K, &'a V)> for CtoHashMap<K, V>
+{
+	#[inline(always)]
+	fn extend<I: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: I)
+	{
+		self.extend(iter.into_iter().map(|(&key, &value)| (key, value)))
+	}
+}
+
+impl<K: CtoSafe, V: CtoSafe> IntoIterator for CtoHashMap<K, V>
+{
+	type Item = (K, V);
+	
+	type IntoIter = CtoHashMapIntoIter<K, V>;
+	
+	#[inline(always)]
+	fn into_iter(self) -> Self::IntoIter
+	{
+		CtoHashMapIntoIter
+		{
+			map: self,
+			index: 0,
+		}
+	}
+}
+
+impl<'a, K: CtoSafe, V: CtoSafe> IntoIterator for &'a CtoHashMap<K, V>
+{
+	type Item = (&'a K, &'a V);
+	
+	type IntoIter = CtoHashMapIter<'a, K, V>;
+	
+	#[inline(always)]
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter()
+	}
+}
+
+impl<'a, K: CtoSafe, V: CtoSafe> IntoIterator for &'a mut CtoHashMap<K, V>
+{
+	type Item = (&'a K, &'a mut V);
+	
+	type IntoIter = CtoHashMapIterMut<'a, K, V>;
+	
+	#[inline(always)]
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter_mut()
+	}
+}
+
+impl<K: CtoSafe, V: CtoSafe> CtoHashMap<K, V>
+{
+	const EmptyHash: u64 = 0;
+	
+	/// Set on all hashes of occupied buckets so that they can never equal `EmptyHash`.
+	const OccupiedBit: u64 = 1 << 63;
+	
+	const MinimumNumberOfBuckets: usize = 8;
+	
+	/// Constructs a new, empty `CtoHashMap<K, V>`.
+	/// The map will not allocate until elements are inserted into it.
+	#[inline(always)]
+	pub fn new(cto_pool_alloc: CtoPoolAlloc) -> Self
+	{
+		Self
+		{
+			hashes: RawVec::new_in(cto_pool_alloc.clone()),
+			pairs: RawVec::new_in(cto_pool_alloc),
+			len: 0,
+			hash_keys: Self::random_hash_keys(),
+		}
+	}
+	
+	/// Constructs a new, empty `CtoHashMap<K, V>` which can hold at least `capacity` elements without reallocating.
+	#[inline(always)]
+	pub fn with_capacity(capacity: usize, cto_pool_alloc: CtoPoolAlloc) -> Self
+	{
+		let number_of_buckets = Self::number_of_buckets_for_capacity(capacity);
+		
+		let hashes = RawVec::with_capacity_in(number_of_buckets, cto_pool_alloc.clone());
+		unsafe { write_bytes(hashes.ptr(), 0, number_of_buckets) };
+		
+		Self
+		{
+			hashes,
+			pairs: RawVec::with_capacity_in(number_of_buckets, cto_pool_alloc),
+			len: 0,
+			hash_keys: Self::random_hash_keys(),
+		}
+	}
+	
+	/// Returns the number of elements the map can hold without reallocating.
+	#[inline(always)]
+	pub fn capacity(&self) -> usize
+	{
+		Self::capacity_for_number_of_buckets(self.number_of_buckets())
+	}
+	
+	/// Returns the number of elements in the map.
+	#[inline(always)]
+	pub fn len(&self) -> usize
+	{
+		self.len
+	}
+	
+	/// Returns `true` if the map contains no elements.
+	#[inline(always)]
+	pub fn is_empty(&self) -> bool
+	{
+		self.len == 0
+	}
+	
+	/// An iterator visiting all key-value pairs in arbitrary order.
+	#[inline(always)]
+	pub fn iter(&self) -> CtoHashMapIter<K, V>
+	{
+		CtoHashMapIter
+		{
+			raw_iter: self.raw_iter(),
+			marker: PhantomData,
+		}
+	}
+	
+	/// An iterator visiting all key-value pairs in arbitrary order, with mutable references to the values.
+	#[inline(always)]
+	pub fn iter_mut(&mut self) -> CtoHashMapIterMut<K, V>
+	{
+		CtoHashMapIterMut
+		{
+			raw_iter: self.raw_iter(),
+			marker: PhantomData,
+		}
+	}
+	
+	/// An iterator visiting all keys in arbitrary order.
+	#[inline(always)]
+	pub fn keys(&self) -> CtoHashMapKeys<K, V>
+	{
+		CtoHashMapKeys
+		{
+			iter: self.iter(),
+		}
+	}
+	
+	/// An iterator visiting all values in arbitrary order.
+	#[inline(always)]
+	pub fn values(&self) -> CtoHashMapValues<K, V>
+	{
+		CtoHashMapValues
+		{
+			iter: self.iter(),
+		}
+	}
+	
+	/// An iterator visiting all values mutably in arbitrary order.
+	#[inline(always)]
+	pub fn values_mut(&mut self) -> CtoHashMapValuesMut<K, V>
+	{
+		CtoHashMapValuesMut
+		{
+			iter_mut: self.iter_mut(),
+		}
+	}
+	
+	/// Clears the map, returning all key-value pairs as an iterator. Keeps the allocated memory for reuse.
+	#[inline(always)]
+	pub fn drain(&mut self) -> CtoHashMapDrain<K, V>
+	{
+		CtoHashMapDrain
+		{
+			map: self,
+			index: 0,
+		}
+	}
+	
+	/// Clears the map, removing all key-value pairs. Keeps the allocated memory for reuse.
+	#[inline(always)]
+	pub fn clear(&mut self)
+	{
+		self.drain();
+	}
+	
+	#[inline(always)]
+	fn random_hash_keys() -> (u64, u64)
+	{
+		let random_state = RandomState::new();
+		
+		let mut first = random_state.build_hasher();
+		first.write_u8(0);
+		
+		let mut second = random_state.build_hasher();
+		second.write_u8(1);
+		
+		(first.finish(), second.finish())
+	}
+	
+	#[inline(always)]
+	fn capacity_for_number_of_buckets(number_of_buckets: usize) -> usize
+	{
+		// Maximum load factor of 3/4; there is therefore always at least one empty bucket, which terminates probing.
+		number_of_buckets - number_of_buckets / 4
+	}
+	
+	#[inline(always)]
+	fn number_of_buckets_for_capacity(capacity: usize) -> usize
+	{
+		if capacity == 0
+		{
+			return 0;
+		}
+		
+		let minimum_number_of_buckets = capacity.checked_mul(4).expect("capacity overflow") / 3 + 1;
+		max(Self::MinimumNumberOfBuckets, minimum_number_of_buckets.checked_next_power_of_two().expect("capacity overflow"))
+	}
+	
+	#[inline(always)]
+	fn number_of_buckets(&self) -> usize
+	{
+		self.hashes.cap()
+	}
+	
+	#[inline(always)]
+	fn bucket_mask(&self) -> usize
+	{
+		self.number_of_buckets().wrapping_sub(1)
+	}
+	
+	#[inline(always)]
+	unsafe fn hash_at(&self, index: usize) -> u64
+	{
+		debug_assert!(index < self.number_of_buckets(), "index is out of range");
+		
+		*self.hashes.ptr().offset(index as isize)
+	}
+	
+	#[inline(always)]
+	unsafe fn set_hash_at(&mut self, index: usize, hash: u64)
+	{
+		debug_assert!(index < self.number_of_buckets(), "index is out of range");
+		
+		*self.hashes.ptr().offset(index as isize) = hash
+	}
+	
+	#[inline(always)]
+	unsafe fn pair_pointer(&self, index: usize) -> *mut (K, V)
+	{
+		debug_assert!(index < self.number_of_buckets(), "index is out of range");
+		
+		self.pairs.ptr().offset(index as isize)
+	}
+	
+	#[inline(always)]
+	fn raw_iter(&self) -> CtoHashMapRawIter<K, V>
+	{
+		CtoHashMapRawIter
+		{
+			hashes: self.hashes.ptr(),
+			pairs: self.pairs.ptr(),
+			index: 0,
+			remaining: self.len,
+		}
+	}
+	
+	#[inline(always)]
+	unsafe fn first_empty_bucket(&self, hash: u64) -> usize
+	{
+		let bucket_mask = self.bucket_mask();
+		let mut index = (hash as usize) & bucket_mask;
+		while self.hash_at(index) != Self::EmptyHash
+		{
+			index = (index + 1) & bucket_mask;
+		}
+		index
+	}
+	
+	#[inline(always)]
+	unsafe fn occupy(&mut self, index: usize, hash: u64, key: K, value: V) -> &mut (K, V)
+	{
+		debug_assert!(self.hash_at(index) == Self::EmptyHash, "bucket is not empty");
+		debug_assert!(self.len < self.capacity(), "no capacity");
+		
+		self.set_hash_at(index, hash);
+		let pair_pointer = self.pair_pointer(index);
+		write(pair_pointer, (key, value));
+		self.len += 1;
+		&mut *pair_pointer
+	}
+	
+	/// Removes the pair at `index` and shifts any following pairs in the same cluster backwards so that no tombstone is required.
+	#[inline(always)]
+	unsafe fn remove_at(&mut self, index: usize) -> (K, V)
+	{
+		debug_assert!(self.hash_at(index) != Self::EmptyHash, "bucket is empty");
+		
+		let pair = read(self.pair_pointer(index));
+		self.len -= 1;
+		
+		let bucket_mask = self.bucket_mask();
+		let mut hole = index;
+		let mut next = (hole + 1) & bucket_mask;
+		loop
+		{
+			let next_hash = self.hash_at(next);
+			if next_hash == Self::EmptyHash
+			{
+				break;
+			}
+			
+			let ideal = (next_hash as usize) & bucket_mask;
+			let distance_from_ideal = next.wrapping_sub(ideal) & bucket_mask;
+			let distance_from_hole = next.wrapping_sub(hole) & bucket_mask;
+			if distance_from_ideal >= distance_from_hole
+			{
+				self.set_hash_at(hole, next_hash);
+				copy_nonoverlapping(self.pair_pointer(next), self.pair_pointer(hole), 1);
+				hole = next;
+			}
+			
+			next = (next + 1) & bucket_mask;
+		}
+		self.set_hash_at(hole, Self::EmptyHash);
+		
+		pair
+	}
+	
+	/// Takes the next pair at or after `index` without shifting any other pairs; only suitable when every pair is being taken.
+	#[inline(always)]
+	fn take_next(&mut self, index: &mut usize) -> Option<(K, V)>
+	{
+		while self.len != 0
+		{
+			let current_index = *index;
+			*index += 1;
+			
+			unsafe
+			{
+				if self.hash_at(current_index) != Self::EmptyHash
+				{
+					self.set_hash_at(current_index, Self::EmptyHash);
+					self.len -= 1;
+					return Some(read(self.pair_pointer(current_index)));
+				}
+			}
+		}
+		None
+	}
+	
+	fn resize(&mut self, new_number_of_buckets: usize)
+	{
+		debug_assert!(new_number_of_buckets == 0 || new_number_of_buckets.is_power_of_two(), "new_number_of_buckets must be zero or a power of two");
+		debug_assert!(Self::capacity_for_number_of_buckets(new_number_of_buckets) >= self.len, "new_number_of_buckets is too small");
+		
+		let cto_pool_alloc = self.hashes.alloc().clone();
+		
+		let hashes = RawVec::with_capacity_in(new_number_of_buckets, cto_pool_alloc.clone());
+		unsafe { write_bytes(hashes.ptr(), 0, new_number_of_buckets) };
+		let pairs = RawVec::with_capacity_in(new_number_of_buckets, cto_pool_alloc);
+		
+		let old_hashes = replace(&mut self.hashes, hashes);
+		let old_pairs = replace(&mut self.pairs, pairs);
+		
+		let mut remaining = self.len;
+		let mut old_index = 0;
+		while remaining != 0
+		{
+			unsafe
+			{
+				let hash = *old_hashes.ptr().offset(old_index as isize);
+				if hash != Self::EmptyHash
+				{
+					let new_index = self.first_empty_bucket(hash);
+					self.set_hash_at(new_index, hash);
+					copy_nonoverlapping(old_pairs.ptr().offset(old_index as isize), self.pair_pointer(new_index), 1);
+					remaining -= 1;
+				}
+			}
+			old_index += 1;
+		}
+		
+		// old_hashes and old_pairs deallocate without dropping their contents, which have been moved.
+	}
+}
+
+impl<K: CtoSafe + Eq + Hash, V: CtoSafe> CtoHashMap<K, V>
+{
+	/// Reserves capacity for at least `additional` more elements to be inserted in the map.
+	#[inline(always)]
+	pub fn reserve(&mut self, additional: usize)
+	{
+		let required_capacity = self.len.checked_add(additional).expect("capacity overflow");
+		if required_capacity > self.capacity()
+		{
+			let new_number_of_buckets = Self::number_of_buckets_for_capacity(required_capacity);
+			self.resize(new_number_of_buckets)
+		}
+	}
+	
+	/// Shrinks the capacity of the map as much as possible.
+	#[inline(always)]
+	pub fn shrink_to_fit(&mut self)
+	{
+		let new_number_of_buckets = Self::number_of_buckets_for_capacity(self.len);
+		if new_number_of_buckets < self.number_of_buckets()
+		{
+			self.resize(new_number_of_buckets)
+		}
+	}
+	
+	/// Gets the given key's corresponding entry in the map for in-place manipulation.
+	#[inline(always)]
+	pub fn entry(&mut self, key: K) -> CtoHashMapEntry<K, V>
+	{
+		self.reserve(1);
+		
+		let hash = self.make_hash(&key);
+		match self.search(hash, &key)
+		{
+			Ok(index) => CtoHashMapEntry::Occupied
+			(
+				CtoHashMapOccupiedEntry
+				{
+					map: self,
+					index,
+				}
+			),
+			
+			Err(index) => CtoHashMapEntry::Vacant
+			(
+				CtoHashMapVacantEntry
+				{
+					map: self,
+					hash,
+					index,
+					key,
+				}
+			),
+		}
+	}
+	
+	/// Returns a reference to the value corresponding to the key.
+	#[inline(always)]
+	pub fn get<Q: ?Sized + Eq + Hash>(&self, key: &Q) -> Option<&V>
+	where K: Borrow<Q>
+	{
+		self.find(key).map(|index| unsafe { &(*self.pair_pointer(index)).1 })
+	}
+	
+	/// Returns a mutable reference to the value corresponding to the key.
+	#[inline(always)]
+	pub fn get_mut<Q: ?Sized + Eq + Hash>(&mut self, key: &Q) -> Option<&mut V>
+	where K: Borrow<Q>
+	{
+		match self.find(key)
+		{
+			None => None,
+			Some(index) => Some(unsafe { &mut (*self.pair_pointer(index)).1 }),
+		}
+	}
+	
+	/// Returns `true` if the map contains a value for the specified key.
+	#[inline(always)]
+	pub fn contains_key<Q: ?Sized + Eq + Hash>(&self, key: &Q) -> bool
+	where K: Borrow<Q>
+	{
+		self.find(key).is_some()
+	}
+	
+	/// Inserts a key-value pair into the map.
+	/// If the map did not have this key present, `None` is returned.
+	/// If the map did have this key present, the value is updated, and the old value is returned; the key is not updated.
+	#[inline(always)]
+	pub fn insert(&mut self, key: K, value: V) -> Option<V>
+	{
+		self.reserve(1);
+		
+		let hash = self.make_hash(&key);
+		match self.search(hash, &key)
+		{
+			Ok(index) => Some(replace(unsafe { &mut (*self.pair_pointer(index)).1 }, value)),
+			
+			Err(index) =>
+			{
+				unsafe { self.occupy(index, hash, key, value) };
+				None
+			}
+		}
+	}
+	
+	/// Removes a key from the map, returning the value at the key if the key was previously in the map.
+	#[inline(always)]
+	pub fn remove<Q: ?Sized + Eq + Hash>(&mut self, key: &Q) -> Option<V>
+	where K: Borrow<Q>
+	{
+		self.remove_entry(key).map(|(_, value)| value)
+	}
+	
+	/// Removes a key from the map, returning the stored key and value if the key was previously in the map.
+	#[inline(always)]
+	pub fn remove_entry<Q: ?Sized + Eq + Hash>(&mut self, key: &Q) -> Option<(K, V)>
+	where K: Borrow<Q>
+	{
+		match self.find(key)
+		{
+			None => None,
+			Some(index) => Some(unsafe { self.remove_at(index) }),
+		}
+	}
+	
+	/// Retains only the elements specified by the predicate.
+	/// In other words, remove all pairs `(k, v)` such that `f(&k, &mut v)` returns `false`.
+	#[inline(always)]
+	pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F)
+	{
+		if self.len == 0
+		{
+			return;
+		}
+		
+		let number_of_buckets = self.number_of_buckets();
+		let bucket_mask = self.bucket_mask();
+		
+		// Starting from an empty bucket means removals only ever shift pairs we have not yet visited backwards into the current bucket.
+		let mut index = 0;
+		while unsafe { self.hash_at(index) } != Self::EmptyHash
+		{
+			index += 1;
+		}
+		
+		let mut visited = 0;
+		while visited < number_of_buckets
+		{
+			if unsafe { self.hash_at(index) } != Self::EmptyHash
+			{
+				let retain =
+				{
+					let pair = unsafe { &mut *self.pair_pointer(index) };
+					f(&pair.0, &mut pair.1)
+				};
+				
+				if !retain
+				{
+					drop(unsafe { self.remove_at(index) });
+					continue;
+				}
+			}
+			
+			index = (index + 1) & bucket_mask;
+			visited += 1;
+		}
+	}
+	
+	#[inline(always)]
+	fn make_hash<Q: ?Sized + Hash>(&self, key: &Q) -> u64
+	{
+		#[allow(deprecated)] let mut hasher = SipHasher::new_with_keys(self.hash_keys.0, self.hash_keys.1);
+		key.hash(&mut hasher);
+		hasher.finish() | Self::OccupiedBit
+	}
+	
+	#[inline(always)]
+	fn find<Q: ?Sized + Eq + Hash>(&self, key: &Q) -> Option<usize>
+	where K: Borrow<Q>
+	{
+		if self.len == 0
+		{
+			None
+		}
+		else
+		{
+			self.search(self.make_hash(key), key).ok()
+		}
+	}
+	
+	/// Returns `Ok(index)` of the bucket containing `key`, or `Err(index)` of the empty bucket at which probing stopped.
+	/// There must be at least one bucket.
+	#[inline(always)]
+	fn search<Q: ?Sized + Eq>(&self, hash: u64, key: &Q) -> Result<usize, usize>
+	where K: Borrow<Q>
+	{
+		debug_assert!(self.number_of_buckets() != 0, "there are no buckets");
+		
+		let bucket_mask = self.bucket_mask();
+		let mut index = (hash as usize) & bucket_mask;
+		loop
+		{
+			let bucket_hash = unsafe { self.hash_at(index) };
+			
+			if bucket_hash == Self::EmptyHash
+			{
+				return Err(index);
+			}
+			
+			if bucket_hash == hash && unsafe { (*self.pair_pointer(index)).0.borrow() } == key
+			{
+				return Ok(index);
+			}
+			
+			index = (index + 1) & bucket_mask;
+		}
+	}
+}
@@ -0,0 +1,55 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// A draining iterator over the entries of a `CtoHashMap`.
+/// Any entries not iterated over are dropped when this is dropped.
+pub struct CtoHashMapDrain<'a, K: 'a + CtoSafe, V: 'a + CtoSafe>
+{
+	map: &'a mut CtoHashMap<K, V>,
+	index: usize,
+}
+
+impl<'a, K: 'a + CtoSafe + Debug, V: 'a + CtoSafe + Debug> Debug for CtoHashMapDrain<'a, K, V>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_tuple("CtoHashMapDrain").field(&self.map).finish()
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> Drop for CtoHashMapDrain<'a, K, V>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		for _ in self
+		{
+		}
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> Iterator for CtoHashMapDrain<'a, K, V>
+{
+	type Item = (K, V);
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.map.take_next(&mut self.index)
+	}
+	
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(self.map.len, Some(self.map.len))
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> ExactSizeIterator for CtoHashMapDrain<'a, K, V>
+{
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> FusedIterator for CtoHashMapDrain<'a, K, V>
+{
+}
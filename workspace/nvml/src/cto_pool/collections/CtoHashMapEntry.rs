@@ -0,0 +1,97 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// A view into a single entry in a `CtoHashMap`, which may either be vacant or occupied.
+/// Constructed using `CtoHashMap::entry()`.
+pub enum CtoHashMapEntry<'a, K: 'a + CtoSafe, V: 'a + CtoSafe>
+{
+	/// An occupied entry.
+	Occupied(CtoHashMapOccupiedEntry<'a, K, V>),
+	
+	/// A vacant entry.
+	Vacant(CtoHashMapVacantEntry<'a, K, V>),
+}
+
+impl<'a, K: 'a + CtoSafe + Debug, V: 'a + CtoSafe + Debug> Debug for CtoHashMapEntry<'a, K, V>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use self::CtoHashMapEntry::*;
+		
+		match *self
+		{
+			Occupied(ref occupied_entry) => f.debug_tuple("Entry").field(occupied_entry).finish(),
+			Vacant(ref vacant_entry) => f.debug_tuple("Entry").field(vacant_entry).finish(),
+		}
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> CtoHashMapEntry<'a, K, V>
+{
+	/// Ensures a value is in the entry by inserting the default if empty, and returns a mutable reference to the value in the entry.
+	#[inline(always)]
+	pub fn or_insert(self, default: V) -> &'a mut V
+	{
+		use self::CtoHashMapEntry::*;
+		
+		match self
+		{
+			Occupied(occupied_entry) => occupied_entry.into_mut(),
+			Vacant(vacant_entry) => vacant_entry.insert(default),
+		}
+	}
+	
+	/// Ensures a value is in the entry by inserting the result of the default function if empty, and returns a mutable reference to the value in the entry.
+	#[inline(always)]
+	pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V
+	{
+		use self::CtoHashMapEntry::*;
+		
+		match self
+		{
+			Occupied(occupied_entry) => occupied_entry.into_mut(),
+			Vacant(vacant_entry) => vacant_entry.insert(default()),
+		}
+	}
+	
+	/// Returns a reference to this entry's key.
+	#[inline(always)]
+	pub fn key(&self) -> &K
+	{
+		use self::CtoHashMapEntry::*;
+		
+		match *self
+		{
+			Occupied(ref occupied_entry) => occupied_entry.key(),
+			Vacant(ref vacant_entry) => vacant_entry.key(),
+		}
+	}
+	
+	/// Provides in-place mutable access to an occupied entry before any potential inserts into the map.
+	#[inline(always)]
+	pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self
+	{
+		use self::CtoHashMapEntry::*;
+		
+		match self
+		{
+			Occupied(mut occupied_entry) =>
+			{
+				f(occupied_entry.get_mut());
+				Occupied(occupied_entry)
+			}
+			Vacant(vacant_entry) => Vacant(vacant_entry),
+		}
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe + Default> CtoHashMapEntry<'a, K, V>
+{
+	/// Ensures a value is in the entry by inserting the default value if empty, and returns a mutable reference to the value in the entry.
+	#[inline(always)]
+	pub fn or_default(self) -> &'a mut V
+	{
+		self.or_insert_with(V::default)
+	}
+}
@@ -0,0 +1,43 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// An owning iterator over the entries of a `CtoHashMap`.
+pub struct CtoHashMapIntoIter<K: CtoSafe, V: CtoSafe>
+{
+	map: CtoHashMap<K, V>,
+	index: usize,
+}
+
+impl<K: CtoSafe + Debug, V: CtoSafe + Debug> Debug for CtoHashMapIntoIter<K, V>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_tuple("CtoHashMapIntoIter").field(&self.map).finish()
+	}
+}
+
+impl<K: CtoSafe, V: CtoSafe> Iterator for CtoHashMapIntoIter<K, V>
+{
+	type Item = (K, V);
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.map.take_next(&mut self.index)
+	}
+	
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(self.map.len, Some(self.map.len))
+	}
+}
+
+impl<K: CtoSafe, V: CtoSafe> ExactSizeIterator for CtoHashMapIntoIter<K, V>
+{
+}
+
+impl<K: CtoSafe, V: CtoSafe> FusedIterator for CtoHashMapIntoIter<K, V>
+{
+}
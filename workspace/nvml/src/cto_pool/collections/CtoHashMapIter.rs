@@ -0,0 +1,64 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// An iterator over the entries of a `CtoHashMap`.
+pub struct CtoHashMapIter<'a, K: 'a + CtoSafe, V: 'a + CtoSafe>
+{
+	raw_iter: CtoHashMapRawIter<K, V>,
+	marker: PhantomData<&'a CtoHashMap<K, V>>,
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> Clone for CtoHashMapIter<'a, K, V>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		Self
+		{
+			raw_iter: self.raw_iter.clone(),
+			marker: PhantomData,
+		}
+	}
+}
+
+impl<'a, K: 'a + CtoSafe + Debug, V: 'a + CtoSafe + Debug> Debug for CtoHashMapIter<'a, K, V>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_list().entries(self.clone()).finish()
+	}
+}
+
+unsafe impl<'a, K: CtoSafe + Sync, V: CtoSafe + Sync> Sync for CtoHashMapIter<'a, K, V>
+{
+}
+
+unsafe impl<'a, K: CtoSafe + Sync, V: CtoSafe + Sync> Send for CtoHashMapIter<'a, K, V>
+{
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> Iterator for CtoHashMapIter<'a, K, V>
+{
+	type Item = (&'a K, &'a V);
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.raw_iter.next().map(|pair| unsafe { (&(*pair).0, &(*pair).1) })
+	}
+	
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.raw_iter.size_hint()
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> ExactSizeIterator for CtoHashMapIter<'a, K, V>
+{
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> FusedIterator for CtoHashMapIter<'a, K, V>
+{
+}
@@ -0,0 +1,56 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// A mutable iterator over the entries of a `CtoHashMap`.
+pub struct CtoHashMapIterMut<'a, K: 'a + CtoSafe, V: 'a + CtoSafe>
+{
+	raw_iter: CtoHashMapRawIter<K, V>,
+	marker: PhantomData<&'a mut CtoHashMap<K, V>>,
+}
+
+impl<'a, K: 'a + CtoSafe + Debug, V: 'a + CtoSafe + Debug> Debug for CtoHashMapIterMut<'a, K, V>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		let iter = CtoHashMapIter
+		{
+			raw_iter: self.raw_iter.clone(),
+			marker: PhantomData,
+		};
+		f.debug_list().entries(iter).finish()
+	}
+}
+
+unsafe impl<'a, K: CtoSafe + Sync, V: CtoSafe + Sync> Sync for CtoHashMapIterMut<'a, K, V>
+{
+}
+
+unsafe impl<'a, K: CtoSafe + Send, V: CtoSafe + Send> Send for CtoHashMapIterMut<'a, K, V>
+{
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> Iterator for CtoHashMapIterMut<'a, K, V>
+{
+	type Item = (&'a K, &'a mut V);
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.raw_iter.next().map(|pair| unsafe { (&(*pair).0, &mut (*pair).1) })
+	}
+	
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		self.raw_iter.size_hint()
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> ExactSizeIterator for CtoHashMapIterMut<'a, K, V>
+{
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> FusedIterator for CtoHashMapIterMut<'a, K, V>
+{
+}
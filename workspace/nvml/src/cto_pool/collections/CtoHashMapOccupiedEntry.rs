@@ -0,0 +1,83 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// A view into an occupied entry in a `CtoHashMap`.
+/// It is part of the `CtoHashMapEntry` enum.
+pub struct CtoHashMapOccupiedEntry<'a, K: 'a + CtoSafe, V: 'a + CtoSafe>
+{
+	map: &'a mut CtoHashMap<K, V>,
+	index: usize,
+}
+
+impl<'a, K: 'a + CtoSafe + Debug, V: 'a + CtoSafe + Debug> Debug for CtoHashMapOccupiedEntry<'a, K, V>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_struct("OccupiedEntry").field("key", self.key()).field("value", self.get()).finish()
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> CtoHashMapOccupiedEntry<'a, K, V>
+{
+	/// Gets a reference to the key in the entry.
+	#[inline(always)]
+	pub fn key(&self) -> &K
+	{
+		&self.pair().0
+	}
+	
+	/// Take the ownership of the key and value from the map.
+	#[inline(always)]
+	pub fn remove_entry(self) -> (K, V)
+	{
+		unsafe { self.map.remove_at(self.index) }
+	}
+	
+	/// Gets a reference to the value in the entry.
+	#[inline(always)]
+	pub fn get(&self) -> &V
+	{
+		&self.pair().1
+	}
+	
+	/// Gets a mutable reference to the value in the entry.
+	#[inline(always)]
+	pub fn get_mut(&mut self) -> &mut V
+	{
+		&mut self.pair_mut().1
+	}
+	
+	/// Converts the entry into a mutable reference to the value in the entry with a lifetime bound to the map itself.
+	#[inline(always)]
+	pub fn into_mut(self) -> &'a mut V
+	{
+		unsafe { &mut (*self.map.pair_pointer(self.index)).1 }
+	}
+	
+	/// Sets the value of the entry, and returns the entry's old value.
+	#[inline(always)]
+	pub fn insert(&mut self, value: V) -> V
+	{
+		replace(self.get_mut(), value)
+	}
+	
+	/// Takes the value out of the entry, and returns it.
+	#[inline(always)]
+	pub fn remove(self) -> V
+	{
+		self.remove_entry().1
+	}
+	
+	#[inline(always)]
+	fn pair(&self) -> &(K, V)
+	{
+		unsafe { &*self.map.pair_pointer(self.index) }
+	}
+	
+	#[inline(always)]
+	fn pair_mut(&mut self) -> &mut (K, V)
+	{
+		unsafe { &mut *self.map.pair_pointer(self.index) }
+	}
+}
@@ -0,0 +1,55 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// Walks the occupied buckets of a `CtoHashMap`.
+struct CtoHashMapRawIter<K: CtoSafe, V: CtoSafe>
+{
+	hashes: *const u64,
+	pairs: *mut (K, V),
+	index: usize,
+	remaining: usize,
+}
+
+impl<K: CtoSafe, V: CtoSafe> Clone for CtoHashMapRawIter<K, V>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		Self
+		{
+			hashes: self.hashes,
+			pairs: self.pairs,
+			index: self.index,
+			remaining: self.remaining,
+		}
+	}
+}
+
+impl<K: CtoSafe, V: CtoSafe> Iterator for CtoHashMapRawIter<K, V>
+{
+	type Item = *mut (K, V);
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		while self.remaining != 0
+		{
+			let index = self.index as isize;
+			self.index += 1;
+			
+			if unsafe { *self.hashes.offset(index) } != CtoHashMap::<K, V>::EmptyHash
+			{
+				self.remaining -= 1;
+				return Some(unsafe { self.pairs.offset(index) });
+			}
+		}
+		None
+	}
+	
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(self.remaining, Some(self.remaining))
+	}
+}
@@ -0,0 +1,47 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// A view into a vacant entry in a `CtoHashMap`.
+/// It is part of the `CtoHashMapEntry` enum.
+pub struct CtoHashMapVacantEntry<'a, K: 'a + CtoSafe, V: 'a + CtoSafe>
+{
+	map: &'a mut CtoHashMap<K, V>,
+	hash: u64,
+	index: usize,
+	key: K,
+}
+
+impl<'a, K: 'a + CtoSafe + Debug, V: 'a + CtoSafe> Debug for CtoHashMapVacantEntry<'a, K, V>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_tuple("VacantEntry").field(self.key()).finish()
+	}
+}
+
+impl<'a, K: 'a + CtoSafe, V: 'a + CtoSafe> CtoHashMapVacantEntry<'a, K, V>
+{
+	/// Gets a reference to the key that would be used when inserting a value through the `CtoHashMapVacantEntry`.
+	#[inline(always)]
+	pub fn key(&self) -> &K
+	{
+		&self.key
+	}
+	
+	/// Take ownership of the key.
+	#[inline(always)]
+	pub fn into_key(self) -> K
+	{
+		self.key
+	}
+	
+	/// Sets the value of the entry with the `CtoHashMapVacantEntry`'s key, and returns a mutable reference to it.
+	#[inline(always)]
+	pub fn insert(self, value: V) -> &'a mut V
+	{
+		// Capacity was reserved by `CtoHashMap::entry()`, so `index` is still the right empty bucket.
+		let map = self.map;
+		&mut unsafe { map.occupy(self.index, self.hash, self.key, value) }.1
+	}
+}
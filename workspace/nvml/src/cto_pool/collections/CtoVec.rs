@@ -3,13 +3,19 @@
 
 
 /// CTO pool equivalent to a Rust Vec.
-pub struct CtoVec<T: CtoSafe>
+///
+/// `P` controls how mutations are flushed.
+/// The default, `VolatileMemoryPersistence`, relies on the CTO pool being closed to persist changes.
+/// With `PersistentMemoryPersistence` or `MMapMemoryPersistence`, elements are flushed before the length that covers them, so a power failure can not leave the length covering uninitialized elements.
+/// Changes made to elements in place, eg via `IndexMut`, are not flushed; use `flush()`.
+pub struct CtoVec<T: CtoSafe, P: Persistence = VolatileMemoryPersistence>
 {
 	buf: RawVec<T, CtoPoolAlloc>,
 	len: usize,
+	persistence: PhantomData<P>,
 }
 
-impl<T: CtoSafe> Drop for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> Drop for CtoVec<T, P>
 {
 	#[inline(always)]
 	fn drop(&mut self)
@@ -23,7 +29,7 @@ impl<T: CtoSafe> Drop for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> CtoSafe for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> CtoSafe for CtoVec<T, P>
 {
 	#[inline(always)]
 	fn cto_pool_opened(&mut self, cto_pool_arc: &CtoPoolArc)
@@ -39,7 +45,7 @@ impl<T: CtoSafe> CtoSafe for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe + Clone> CtoVec<T>
+impl<T: CtoSafe + Clone, P: Persistence> CtoVec<T, P>
 {
 	/// Extend from slice.
 	#[inline(always)]
@@ -49,7 +55,7 @@ impl<T: CtoSafe + Clone> CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> Extend<T> for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> Extend<T> for CtoVec<T, P>
 {
 	#[inline(always)]
 	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I)
@@ -58,7 +64,7 @@ impl<T: CtoSafe> Extend<T> for CtoVec<T>
 	}
 }
 
-impl<'a, T: 'a + CtoSafe + Copy> Extend<&'a T> for CtoVec<T>
+impl<'a, T: 'a + CtoSafe + Copy, P: Persistence> Extend<&'a T> for CtoVec<T, P>
 {
 	#[inline(always)]
 	fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I)
@@ -67,7 +73,7 @@ impl<'a, T: 'a + CtoSafe + Copy> Extend<&'a T> for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe + Hash> Hash for CtoVec<T>
+impl<T: CtoSafe + Hash, P: Persistence> Hash for CtoVec<T, P>
 {
 	#[inline(always)]
 	fn hash<H: Hasher>(&self, state: &mut H)
@@ -76,7 +82,7 @@ impl<T: CtoSafe + Hash> Hash for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe + Debug> Debug for CtoVec<T>
+impl<T: CtoSafe + Debug, P: Persistence> Debug for CtoVec<T, P>
 {
 	#[inline(always)]
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result
@@ -85,7 +91,7 @@ impl<T: CtoSafe + Debug> Debug for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe + PartialOrd> PartialOrd for CtoVec<T>
+impl<T: CtoSafe + PartialOrd, P: Persistence> PartialOrd for CtoVec<T, P>
 {
 	#[inline(always)]
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
@@ -94,7 +100,7 @@ impl<T: CtoSafe + PartialOrd> PartialOrd for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe + Ord> Ord for CtoVec<T>
+impl<T: CtoSafe + Ord, P: Persistence> Ord for CtoVec<T, P>
 {
 	#[inline]
 	fn cmp(&self, other: &Self) -> Ordering
@@ -113,7 +119,7 @@ macro_rules! __impl_slice_eq1
     ($Lhs: ty, $Rhs: ty, $Bound: ident) =>
    
 	{
-        impl<'a, 'b, A: $Bound, B: CtoSafe> PartialEq<$Rhs> for $Lhs where A: CtoSafe + PartialEq<B>
+        impl<'a, 'b, A: $Bound, B: CtoSafe, P: Persistence> PartialEq<$Rhs> for $Lhs where A: CtoSafe + PartialEq<B>
        
 	{
             #[inline]
@@ -133,17 +139,17 @@ macro_rules! __impl_slice_eq1
     }
 }
 
-__impl_slice_eq1! { CtoVec<A>, CtoVec<B> }
+__impl_slice_eq1! { CtoVec<A, P>, CtoVec<B, P> }
 
-__impl_slice_eq1! { CtoVec<A>, &'b [B] }
+__impl_slice_eq1! { CtoVec<A, P>, &'b [B] }
 
-__impl_slice_eq1! { CtoVec<A>, &'b mut [B] }
+__impl_slice_eq1! { CtoVec<A, P>, &'b mut [B] }
 
-impl<T: CtoSafe + Eq> Eq for CtoVec<T>
+impl<T: CtoSafe + Eq, P: Persistence> Eq for CtoVec<T, P>
 {
 }
 
-impl<T: CtoSafe> Deref for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> Deref for CtoVec<T, P>
 {
 	type Target = [T];
 	
@@ -159,7 +165,7 @@ impl<T: CtoSafe> Deref for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> DerefMut for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> DerefMut for CtoVec<T, P>
 {
 	#[inline(always)]
 	fn deref_mut(&mut self) -> &mut [T]
@@ -173,7 +179,7 @@ impl<T: CtoSafe> DerefMut for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> AsRef<CtoVec<T>> for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> AsRef<CtoVec<T, P>> for CtoVec<T, P>
 {
 	#[inline(always)]
 	fn as_ref(&self) -> &Self
@@ -182,7 +188,7 @@ impl<T: CtoSafe> AsRef<CtoVec<T>> for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> AsMut<CtoVec<T>> for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> AsMut<CtoVec<T, P>> for CtoVec<T, P>
 {
 	#[inline(always)]
 	fn as_mut(&mut self) -> &mut Self
@@ -191,7 +197,7 @@ impl<T: CtoSafe> AsMut<CtoVec<T>> for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> AsRef<[T]> for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> AsRef<[T]> for CtoVec<T, P>
 {
 	#[inline(always)]
 	fn as_ref(&self) -> &[T]
@@ -200,7 +206,7 @@ impl<T: CtoSafe> AsRef<[T]> for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> AsMut<[T]> for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> AsMut<[T]> for CtoVec<T, P>
 {
 	#[inline(always)]
 	fn as_mut(&mut self) -> &mut [T]
@@ -209,7 +215,7 @@ impl<T: CtoSafe> AsMut<[T]> for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> Index<usize> for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> Index<usize> for CtoVec<T, P>
 {
 	type Output = T;
 	
@@ -221,7 +227,7 @@ impl<T: CtoSafe> Index<usize> for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> IndexMut<usize> for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> IndexMut<usize> for CtoVec<T, P>
 {
 	#[inline]
 	fn index_mut(&mut self, index: usize) -> &mut T
@@ -231,7 +237,7 @@ impl<T: CtoSafe> IndexMut<usize> for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> Index<Range<usize>> for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> Index<Range<usize>> for CtoVec<T, P>
 {
 	type Output = [T];
 	
@@ -242,7 +248,7 @@ impl<T: CtoSafe> Index<Range<usize>> for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> Index<RangeTo<usize>> for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> Index<RangeTo<usize>> for CtoVec<T, P>
 {
 	type Output = [T];
 	
@@ -253,7 +259,7 @@ impl<T: CtoSafe> Index<RangeTo<usize>> for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> Index<RangeFrom<usize>> for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> Index<RangeFrom<usize>> for CtoVec<T, P>
 {
 	type Output = [T];
 	
@@ -264,7 +270,7 @@ impl<T: CtoSafe> Index<RangeFrom<usize>> for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> Index<RangeFull> for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> Index<RangeFull> for CtoVec<T, P>
 {
 	type Output = [T];
 	
@@ -275,7 +281,7 @@ impl<T: CtoSafe> Index<RangeFull> for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> Index<RangeInclusive<usize>> for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> Index<RangeInclusive<usize>> for CtoVec<T, P>
 {
 	type Output = [T];
 	
@@ -286,7 +292,7 @@ impl<T: CtoSafe> Index<RangeInclusive<usize>> for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> Index<RangeToInclusive<usize>> for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> Index<RangeToInclusive<usize>> for CtoVec<T, P>
 {
 	type Output = [T];
 	
@@ -297,7 +303,7 @@ impl<T: CtoSafe> Index<RangeToInclusive<usize>> for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> IndexMut<Range<usize>> for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> IndexMut<Range<usize>> for CtoVec<T, P>
 {
 	#[inline]
 	fn index_mut(&mut self, index: Range<usize>) -> &mut [T]
@@ -306,7 +312,7 @@ impl<T: CtoSafe> IndexMut<Range<usize>> for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> IndexMut<RangeTo<usize>> for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> IndexMut<RangeTo<usize>> for CtoVec<T, P>
 {
 	#[inline]
 	fn index_mut(&mut self, index: RangeTo<usize>) -> &mut [T]
@@ -315,7 +321,7 @@ impl<T: CtoSafe> IndexMut<RangeTo<usize>> for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> IndexMut<RangeFrom<usize>> for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> IndexMut<RangeFrom<usize>> for CtoVec<T, P>
 {
 	#[inline]
 	fn index_mut(&mut self, index: RangeFrom<usize>) -> &mut [T]
@@ -324,7 +330,7 @@ impl<T: CtoSafe> IndexMut<RangeFrom<usize>> for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> IndexMut<RangeFull> for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> IndexMut<RangeFull> for CtoVec<T, P>
 {
 	#[inline]
 	fn index_mut(&mut self, _index: RangeFull) -> &mut [T]
@@ -333,7 +339,7 @@ impl<T: CtoSafe> IndexMut<RangeFull> for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> IndexMut<RangeInclusive<usize>> for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> IndexMut<RangeInclusive<usize>> for CtoVec<T, P>
 {
 	#[inline(always)]
 	fn index_mut(&mut self, index: RangeInclusive<usize>) -> &mut [T]
@@ -342,7 +348,7 @@ impl<T: CtoSafe> IndexMut<RangeInclusive<usize>> for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> IndexMut<RangeToInclusive<usize>> for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> IndexMut<RangeToInclusive<usize>> for CtoVec<T, P>
 {
 	#[inline(always)]
 	fn index_mut(&mut self, index: RangeToInclusive<usize>) -> &mut [T]
@@ -351,7 +357,7 @@ impl<T: CtoSafe> IndexMut<RangeToInclusive<usize>> for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> CtoVec<T>
+impl<T: CtoSafe, P: Persistence> CtoVec<T, P>
 {
 	/// Constructs a new, empty `CtoVec<T>`.
 	#[inline(always)]
@@ -361,6 +367,7 @@ impl<T: CtoSafe> CtoVec<T>
 		{
 			buf: RawVec::new_in(cto_pool_alloc),
 			len: 0,
+			persistence: PhantomData,
 		}
 	}
 	
@@ -372,6 +379,7 @@ impl<T: CtoSafe> CtoVec<T>
 		{
 			buf: RawVec::with_capacity_in(capacity, cto_pool_alloc),
 			len: 0,
+			persistence: PhantomData,
 		}
 	}
 	
@@ -383,6 +391,7 @@ impl<T: CtoSafe> CtoVec<T>
 		{
 			buf: RawVec::from_raw_parts_in(ptr, capacity, cto_pool_alloc),
 			len: length,
+			persistence: PhantomData,
 		}
 	}
 	
@@ -393,11 +402,27 @@ impl<T: CtoSafe> CtoVec<T>
 		self.buf.cap()
 	}
 	
+	/// Returns the number of bytes actually allocated in the CTO pool for this vector's buffer, which may be more than `capacity() * size_of::<T>()`.
+	/// Returns zero if nothing has been allocated (eg the capacity is zero or `T` is zero-sized).
+	#[inline(always)]
+	pub fn usable_size(&self) -> usize
+	{
+		if self.buf.cap() == 0 || size_of::<T>() == 0
+		{
+			0
+		}
+		else
+		{
+			self.buf.alloc().pool_pointer().usable_size(self.buf.ptr() as *mut c_void)
+		}
+	}
+	
 	/// Reserves capacity for at least `additional` more elements to be inserted in the given `CtoVec<T>`.
 	#[inline(always)]
 	pub fn reserve(&mut self, additional: usize)
 	{
 		self.buf.reserve(self.len, additional);
+		self.persist_buffer();
 	}
 	
 	/// Reserves the minimum capacity for exactly `additional` more elements to be inserted in the given `Vec<T>`.
@@ -405,6 +430,7 @@ impl<T: CtoSafe> CtoVec<T>
 	pub fn reserve_exact(&mut self, additional: usize)
 	{
 		self.buf.reserve_exact(self.len, additional);
+		self.persist_buffer();
 	}
 	
 	/// Shrinks the capacity of the vector as much as possible.
@@ -412,22 +438,24 @@ impl<T: CtoSafe> CtoVec<T>
 	pub fn shrink_to_fit(&mut self)
 	{
 		self.buf.shrink_to_fit(self.len);
+		self.persist_buffer();
 	}
 	
 	/// Shortens the vector, keeping the first `len` elements and dropping the rest.
 	#[inline(always)]
 	pub fn truncate(&mut self, len: usize)
 	{
+		let old_len = self.len;
+		if len >= old_len
+		{
+			return
+		}
+		
 		unsafe
 		{
-			// drop any extra elements
-			while len < self.len
-			{
-				// decrement len before the drop_in_place(), so a panic on Drop doesn't re-drop the just-failed value.
-				self.len -= 1;
-				let len = self.len;
-				drop_in_place(self.get_unchecked_mut(len));
-			}
+			// Persist the new length before the drop_in_place(), so neither a panic on Drop nor a power failure can cause a re-drop of the extra elements.
+			self.set_len(len);
+			drop_in_place(from_raw_parts_mut(self.as_mut_ptr().offset(len as isize), old_len - len));
 		}
 	}
 	
@@ -450,10 +478,59 @@ impl<T: CtoSafe> CtoVec<T>
 	}
 	
 	/// Sets the length of a vector.
+	///
+	/// If the length grows, the newly covered elements are flushed before the length.
 	#[inline(always)]
 	pub unsafe fn set_len(&mut self, len: usize)
 	{
-		self.len = len;
+		Self::flush_elements(self.buf.ptr(), self.len, len);
+		Self::persist_len(&mut self.len, len);
+	}
+	
+	/// Flushes all elements and the length.
+	///
+	/// Only necessary after elements have been changed in place, eg via `IndexMut` or `iter_mut()`.
+	#[inline(always)]
+	pub fn flush(&self)
+	{
+		Self::flush_elements(self.buf.ptr(), 0, self.len);
+		P::flush_struct(&self.len);
+		P::drain_memory();
+	}
+	
+	/// Flushes the elements in `from .. to`, eg after they have been moved, before a call to `set_len()`.
+	#[inline(always)]
+	pub(crate) fn flush_range(&self, from: usize, to: usize)
+	{
+		Self::flush_elements(self.buf.ptr(), from, to)
+	}
+	
+	#[inline(always)]
+	fn flush_elements(pointer: *const T, from: usize, to: usize)
+	{
+		if to > from && size_of::<T>() != 0
+		{
+			P::flush_memory(unsafe { pointer.offset(from as isize) } as *mut c_void, (to - from) * size_of::<T>())
+		}
+	}
+	
+	/// Flushes the whole header, including the buffer's pointer and capacity, after the buffer has been reallocated.
+	/// This happens before any larger length is persisted, so a power failure can not leave the length covering the old buffer.
+	#[inline(always)]
+	fn persist_buffer(&self)
+	{
+		P::flush_struct(self);
+		P::drain_memory();
+	}
+	
+	/// Elements flushed before calling this are drained before the length is changed.
+	#[inline(always)]
+	fn persist_len(len_field: &mut usize, len: usize)
+	{
+		P::drain_memory();
+		*len_field = len;
+		P::flush_struct(len_field);
+		P::drain_memory();
 	}
 	
 	/// Removes an element from the vector and returns it.
@@ -484,6 +561,7 @@ impl<T: CtoSafe> CtoVec<T>
 		if len == self.buf.cap()
 		{
 			self.buf.double();
+			self.persist_buffer();
 		}
 		
 		unsafe
@@ -497,6 +575,7 @@ impl<T: CtoSafe> CtoVec<T>
 				// Write it in, overwriting the first copy of the `index`th element.
 				write(p, element);
 			}
+			self.flush_range(index, len);
 			self.set_len(len + 1);
 		}
 	}
@@ -524,6 +603,7 @@ impl<T: CtoSafe> CtoVec<T>
 				// Shift everything down to fill in that spot.
 				copy(ptr.offset(1), ptr, len - index - 1);
 			}
+			self.flush_range(index, len - 1);
 			self.set_len(len - 1);
 			result
 		}
@@ -564,6 +644,7 @@ impl<T: CtoSafe> CtoVec<T>
 		
 		if del > 0
 		{
+			self.flush_range(0, len - del);
 			self.truncate(len - del);
 		}
 	}
@@ -693,6 +774,7 @@ impl<T: CtoSafe> CtoVec<T>
 				r += 1;
 			}
 			
+			self.flush_range(0, w);
 			self.truncate(w);
 		}
 	}
@@ -717,13 +799,15 @@ impl<T: CtoSafe> CtoVec<T>
 		if self.len == self.buf.cap()
 		{
 			self.buf.double();
+			self.persist_buffer();
 		}
 		
 		unsafe
 		{
-			let end = self.as_mut_ptr().offset(self.len as isize);
+			let len = self.len;
+			let end = self.as_mut_ptr().offset(len as isize);
 			write(end, value);
-			self.len += 1;
+			self.set_len(len + 1);
 		}
 	}
 	
@@ -743,7 +827,7 @@ impl<T: CtoSafe> CtoVec<T>
 	/// assert_eq!(&vec, &[1, 2, 3, 4]);
 	/// ```
 	#[inline(always)]
-	pub fn place_back(&mut self) -> CtoVecPlaceBack<T>
+	pub fn place_back(&mut self) -> CtoVecPlaceBack<T, P>
 	{
 		CtoVecPlaceBack
 		{
@@ -773,8 +857,9 @@ impl<T: CtoSafe> CtoVec<T>
 		{
 			unsafe
 			{
-				self.len -= 1;
-				Some(read(self.get_unchecked(self.len())))
+				let len = self.len - 1;
+				self.set_len(len);
+				Some(read(self.get_unchecked(len)))
 			}
 		}
 	}
@@ -812,7 +897,7 @@ impl<T: CtoSafe> CtoVec<T>
 		self.reserve(count);
 		let len = self.len();
 		copy_nonoverlapping(other as *const T, self.get_unchecked_mut(len), count);
-		self.len += count;
+		self.set_len(len + count);
 	}
 	
 	/// Creates a draining iterator that removes the specified range in the vector
@@ -841,7 +926,7 @@ impl<T: CtoSafe> CtoVec<T>
 	/// v.drain(..);
 	/// assert_eq!(v, &[]);
 	/// ```
-	pub fn drain<R>(&mut self, range: R) -> CtoVecDrain<T>
+	pub fn drain<R>(&mut self, range: R) -> CtoVecDrain<T, P>
 		where R: RangeArgument<usize>
 	{
 		// Memory safety
@@ -967,17 +1052,19 @@ impl<T: CtoSafe> CtoVec<T>
 		
 		unsafe
 		{
+			// Shorten `self` first, so a power failure can only leak the moved elements rather than leave them owned twice.
 			self.set_len(at);
-			other.set_len(other_len);
 			
-			copy_nonoverlapping(self.as_ptr().offset(at as isize), other.as_mut_ptr(), other.len());
+			copy_nonoverlapping(self.as_ptr().offset(at as isize), other.as_mut_ptr(), other_len);
+			
+			other.set_len(other_len);
 		}
 		
 		other
 	}
 }
 
-impl<T: CtoSafe> IntoIterator for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> IntoIterator for CtoVec<T, P>
 {
 	type Item = T;
 
@@ -1029,7 +1116,7 @@ impl<T: CtoSafe> IntoIterator for CtoVec<T>
 	}
 }
 
-impl<'a, T: CtoSafe> IntoIterator for &'a CtoVec<T>
+impl<'a, T: CtoSafe, P: Persistence> IntoIterator for &'a CtoVec<T, P>
 {
 	type Item = &'a T;
 	
@@ -1042,7 +1129,7 @@ impl<'a, T: CtoSafe> IntoIterator for &'a CtoVec<T>
 	}
 }
 
-impl<'a, T: CtoSafe> IntoIterator for &'a mut CtoVec<T>
+impl<'a, T: CtoSafe, P: Persistence> IntoIterator for &'a mut CtoVec<T, P>
 {
 	type Item = &'a mut T;
 	
@@ -1055,7 +1142,7 @@ impl<'a, T: CtoSafe> IntoIterator for &'a mut CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe + PartialEq> CtoVec<T>
+impl<T: CtoSafe + PartialEq, P: Persistence> CtoVec<T, P>
 {
 	/// Removes consecutive repeated elements in the vector.
 	///
@@ -1095,7 +1182,7 @@ impl<T: CtoSafe + PartialEq> CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe> CtoVec<T>
+impl<T: CtoSafe, P: Persistence> CtoVec<T, P>
 {
 	#[inline(always)]
 	fn extend_desugared<I: Iterator<Item = T>>(&mut self, mut iterator: I)
@@ -1123,7 +1210,7 @@ impl<T: CtoSafe> CtoVec<T>
 	/// Creates a splicing iterator that replaces the specified range in the vector with the given `replace_with` iterator and yields the removed items.
 	/// `replace_with` does not need to be the same length as `range`.
 	#[inline(always)]
-	pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> CtoVecSplice<I::IntoIter>
+	pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> CtoVecSplice<I::IntoIter, P>
 		where R: RangeArgument<usize>, I: IntoIterator<Item=T>
 	{
 		CtoVecSplice
@@ -1135,7 +1222,7 @@ impl<T: CtoSafe> CtoVec<T>
 	
 	/// Creates an iterator which uses a closure to determine if an element should be removed.
 	#[inline(always)]
-	pub fn drain_filter<F>(&mut self, filter: F) -> CtoVecDrainFilter<T, F>
+	pub fn drain_filter<F>(&mut self, filter: F) -> CtoVecDrainFilter<T, F, P>
 		where F: FnMut(&mut T) -> bool,
 	{
 		let old_len = self.len();
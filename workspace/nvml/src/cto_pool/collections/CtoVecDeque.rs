@@ -0,0 +1,673 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// CTO pool equivalent to a Rust VecDeque.
+/// A growable ring buffer.
+pub struct CtoVecDeque<T: CtoSafe>
+{
+	buf: RawVec<T, CtoPoolAlloc>,
+	head: usize,
+	len: usize,
+}
+
+impl<T: CtoSafe> Drop for CtoVecDeque<T>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		let (front, back) = self.as_mut_slices();
+		unsafe
+		{
+			drop_in_place(front);
+			drop_in_place(back);
+		}
+		// RawVec handles deallocation
+	}
+}
+
+impl<T: CtoSafe> CtoSafe for CtoVecDeque<T>
+{
+	#[inline(always)]
+	fn cto_pool_opened(&mut self, cto_pool_arc: &CtoPoolArc)
+	{
+		self.buf.alloc_mut().cto_pool_opened(cto_pool_arc);
+		
+		for element in self.iter_mut()
+		{
+			element.cto_pool_opened(cto_pool_arc);
+		}
+	}
+}
+
+impl<T: CtoSafe + Clone> Clone for CtoVecDeque<T>
+{
+	#[inline(always)]
+	fn clone(&self) -> Self
+	{
+		let mut clone = Self::with_capacity(self.len, self.buf.alloc().clone());
+		clone.extend(self.iter().cloned());
+		clone
+	}
+}
+
+impl<T: CtoSafe> Extend<T> for CtoVecDeque<T>
+{
+	#[inline(always)]
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I)
+	{
+		let iter = iter.into_iter();
+		let (lower_bound, _) = iter.size_hint();
+		self.reserve(lower_bound);
+		
+		for element in iter
+		{
+			self.push_back(element);
+		}
+	}
+}
+
+impl<'a, T: 'a + CtoSafe + Copy> Extend<&'a T> for CtoVecDeque<T>
+{
+	#[inline(always)]
+	fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I)
+	{
+		self.extend(iter.into_iter().cloned())
+	}
+}
+
+impl<T: CtoSafe + Hash> Hash for CtoVecDeque<T>
+{
+	#[inline(always)]
+	fn hash<H: Hasher>(&self, state: &mut H)
+	{
+		state.write_usize(self.len);
+		for element in self.iter()
+		{
+			element.hash(state);
+		}
+	}
+}
+
+impl<T: CtoSafe + Debug> Debug for CtoVecDeque<T>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_list().entries(self.iter()).finish()
+	}
+}
+
+impl<T: CtoSafe + PartialEq> PartialEq for CtoVecDeque<T>
+{
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool
+	{
+		self.len == other.len && self.iter().eq(other.iter())
+	}
+}
+
+impl<T: CtoSafe + Eq> Eq for CtoVecDeque<T>
+{
+}
+
+impl<T: CtoSafe + PartialOrd> PartialOrd for CtoVecDeque<T>
+{
+	#[inline(always)]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		self.iter().partial_cmp(other.iter())
+	}
+}
+
+impl<T: CtoSafe + Ord> Ord for CtoVecDeque<T>
+{
+	#[inline(always)]
+	fn cmp(&self, other: &Self) -> Ordering
+	{
+		self.iter().cmp(other.iter())
+	}
+}
+
+impl<T: CtoSafe> Index<usize> for CtoVecDeque<T>
+{
+	type Output = T;
+	
+	#[inline(always)]
+	fn index(&self, index: usize) -> &T
+	{
+		self.get(index).expect("Out of bounds access")
+	}
+}
+
+impl<T: CtoSafe> IndexMut<usize> for CtoVecDeque<T>
+{
+	#[inline(always)]
+	fn index_mut(&mut self, index: usize) -> &mut T
+	{
+		self.get_mut(index).expect("Out of bounds access")
+	}
+}
+
+impl<T: CtoSafe> IntoIterator for CtoVecDeque<T>
+{
+	type Item = T;
+	
+	type IntoIter = CtoVecDequeIntoIter<T>;
+	
+	#[inline(always)]
+	fn into_iter(self) -> Self::IntoIter
+	{
+		CtoVecDequeIntoIter
+		{
+			deque: self,
+		}
+	}
+}
+
+impl<'a, T: CtoSafe> IntoIterator for &'a CtoVecDeque<T>
+{
+	type Item = &'a T;
+	
+	type IntoIter = CtoVecDequeIter<'a, T>;
+	
+	#[inline(always)]
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter()
+	}
+}
+
+impl<'a, T: CtoSafe> IntoIterator for &'a mut CtoVecDeque<T>
+{
+	type Item = &'a mut T;
+	
+	type IntoIter = CtoVecDequeIterMut<'a, T>;
+	
+	#[inline(always)]
+	fn into_iter(self) -> Self::IntoIter
+	{
+		self.iter_mut()
+	}
+}
+
+impl<T: CtoSafe + PartialEq> CtoVecDeque<T>
+{
+	/// Returns `true` if the `CtoVecDeque` contains an element equal to the given value.
+	#[inline(always)]
+	pub fn contains(&self, x: &T) -> bool
+	{
+		self.iter().any(|element| element == x)
+	}
+}
+
+impl<T: CtoSafe> CtoVecDeque<T>
+{
+	/// Creates an empty `CtoVecDeque`.
+	/// Does not allocate until elements are pushed onto it.
+	#[inline(always)]
+	pub fn new(cto_pool_alloc: CtoPoolAlloc) -> Self
+	{
+		Self
+		{
+			buf: RawVec::new_in(cto_pool_alloc),
+			head: 0,
+			len: 0,
+		}
+	}
+	
+	/// Creates an empty `CtoVecDeque` with space for at least `capacity` elements.
+	#[inline(always)]
+	pub fn with_capacity(capacity: usize, cto_pool_alloc: CtoPoolAlloc) -> Self
+	{
+		Self
+		{
+			buf: RawVec::with_capacity_in(capacity, cto_pool_alloc),
+			head: 0,
+			len: 0,
+		}
+	}
+	
+	/// Returns the number of elements the `CtoVecDeque` can hold without reallocating.
+	#[inline(always)]
+	pub fn capacity(&self) -> usize
+	{
+		self.buf.cap()
+	}
+	
+	/// Returns the number of elements in the `CtoVecDeque`.
+	#[inline(always)]
+	pub fn len(&self) -> usize
+	{
+		self.len
+	}
+	
+	/// Returns `true` if the `CtoVecDeque` is empty.
+	#[inline(always)]
+	pub fn is_empty(&self) -> bool
+	{
+		self.len == 0
+	}
+	
+	/// Reserves capacity for at least `additional` more elements to be inserted.
+	#[inline(always)]
+	pub fn reserve(&mut self, additional: usize)
+	{
+		let required_capacity = self.len.checked_add(additional).expect("capacity overflow");
+		let capacity = self.capacity();
+		if required_capacity > capacity
+		{
+			let doubled_capacity = capacity.checked_mul(2).unwrap_or(required_capacity);
+			self.relocate(max(max(required_capacity, doubled_capacity), 4))
+		}
+	}
+	
+	/// Shrinks the capacity of the `CtoVecDeque` as much as possible.
+	#[inline(always)]
+	pub fn shrink_to_fit(&mut self)
+	{
+		if size_of::<T>() != 0 && self.capacity() > self.len
+		{
+			let len = self.len;
+			self.relocate(len)
+		}
+	}
+	
+	/// Provides a reference to the element at the given index.
+	/// Element at index 0 is the front of the queue.
+	#[inline(always)]
+	pub fn get(&self, index: usize) -> Option<&T>
+	{
+		if index < self.len
+		{
+			Some(unsafe { &*self.pointer(index) })
+		}
+		else
+		{
+			None
+		}
+	}
+	
+	/// Provides a mutable reference to the element at the given index.
+	/// Element at index 0 is the front of the queue.
+	#[inline(always)]
+	pub fn get_mut(&mut self, index: usize) -> Option<&mut T>
+	{
+		if index < self.len
+		{
+			Some(unsafe { &mut *self.pointer(index) })
+		}
+		else
+		{
+			None
+		}
+	}
+	
+	/// Provides a reference to the front element, or `None` if the `CtoVecDeque` is empty.
+	#[inline(always)]
+	pub fn front(&self) -> Option<&T>
+	{
+		self.get(0)
+	}
+	
+	/// Provides a mutable reference to the front element, or `None` if the `CtoVecDeque` is empty.
+	#[inline(always)]
+	pub fn front_mut(&mut self) -> Option<&mut T>
+	{
+		self.get_mut(0)
+	}
+	
+	/// Provides a reference to the back element, or `None` if the `CtoVecDeque` is empty.
+	#[inline(always)]
+	pub fn back(&self) -> Option<&T>
+	{
+		if self.len == 0
+		{
+			None
+		}
+		else
+		{
+			self.get(self.len - 1)
+		}
+	}
+	
+	/// Provides a mutable reference to the back element, or `None` if the `CtoVecDeque` is empty.
+	#[inline(always)]
+	pub fn back_mut(&mut self) -> Option<&mut T>
+	{
+		if self.len == 0
+		{
+			None
+		}
+		else
+		{
+			let index = self.len - 1;
+			self.get_mut(index)
+		}
+	}
+	
+	/// Appends an element to the back of the `CtoVecDeque`.
+	#[inline(always)]
+	pub fn push_back(&mut self, value: T)
+	{
+		self.reserve(1);
+		
+		let len = self.len;
+		unsafe { write(self.pointer(len), value) };
+		self.len += 1;
+	}
+	
+	/// Prepends an element to the front of the `CtoVecDeque`.
+	#[inline(always)]
+	pub fn push_front(&mut self, value: T)
+	{
+		self.reserve(1);
+		
+		self.head = if self.head == 0
+		{
+			self.capacity() - 1
+		}
+		else
+		{
+			self.head - 1
+		};
+		unsafe { write(self.buf.ptr().offset(self.head as isize), value) };
+		self.len += 1;
+	}
+	
+	/// Removes the last element and returns it, or `None` if the `CtoVecDeque` is empty.
+	#[inline(always)]
+	pub fn pop_back(&mut self) -> Option<T>
+	{
+		if self.len == 0
+		{
+			None
+		}
+		else
+		{
+			self.len -= 1;
+			let len = self.len;
+			Some(unsafe { read(self.pointer(len)) })
+		}
+	}
+	
+	/// Removes the first element and returns it, or `None` if the `CtoVecDeque` is empty.
+	#[inline(always)]
+	pub fn pop_front(&mut self) -> Option<T>
+	{
+		if self.len == 0
+		{
+			None
+		}
+		else
+		{
+			let value = unsafe { read(self.pointer(0)) };
+			self.head = self.physical_index(1);
+			self.len -= 1;
+			Some(value)
+		}
+	}
+	
+	/// Swaps elements at indices `i` and `j`.
+	/// Panics if either index is out of bounds.
+	#[inline(always)]
+	pub fn swap(&mut self, i: usize, j: usize)
+	{
+		assert!(i < self.len, "i is out of bounds");
+		assert!(j < self.len, "j is out of bounds");
+		
+		unsafe { ::std::ptr::swap(self.pointer(i), self.pointer(j)) }
+	}
+	
+	/// Inserts an element at `index`, shifting whichever of the elements before or after it are fewer.
+	/// Panics if `index` is greater than the `CtoVecDeque`'s length.
+	#[inline(always)]
+	pub fn insert(&mut self, index: usize, value: T)
+	{
+		assert!(index <= self.len, "index out of bounds");
+		
+		if index <= self.len / 2
+		{
+			self.push_front(value);
+			for position in 0 .. index
+			{
+				self.swap(position, position + 1);
+			}
+		}
+		else
+		{
+			self.push_back(value);
+			for position in (index .. self.len - 1).rev()
+			{
+				self.swap(position, position + 1);
+			}
+		}
+	}
+	
+	/// Removes and returns the element at `index`, shifting whichever of the elements before or after it are fewer.
+	/// Returns `None` if `index` is out of bounds.
+	#[inline(always)]
+	pub fn remove(&mut self, index: usize) -> Option<T>
+	{
+		if index >= self.len
+		{
+			return None;
+		}
+		
+		if index <= self.len / 2
+		{
+			for position in (0 .. index).rev()
+			{
+				self.swap(position, position + 1);
+			}
+			self.pop_front()
+		}
+		else
+		{
+			for position in index .. self.len - 1
+			{
+				self.swap(position, position + 1);
+			}
+			self.pop_back()
+		}
+	}
+	
+	/// Shortens the `CtoVecDeque`, dropping excess elements from the back.
+	#[inline(always)]
+	pub fn truncate(&mut self, len: usize)
+	{
+		while self.len > len
+		{
+			self.pop_back();
+		}
+	}
+	
+	/// Clears the `CtoVecDeque`, removing all values.
+	#[inline(always)]
+	pub fn clear(&mut self)
+	{
+		self.truncate(0);
+		self.head = 0;
+	}
+	
+	/// Retains only the elements specified by the predicate.
+	#[inline(always)]
+	pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F)
+	{
+		let len = self.len;
+		let mut deleted = 0;
+		for index in 0 .. len
+		{
+			if !f(&self[index])
+			{
+				deleted += 1;
+			}
+			else if deleted > 0
+			{
+				self.swap(index - deleted, index);
+			}
+		}
+		
+		if deleted > 0
+		{
+			self.truncate(len - deleted);
+		}
+	}
+	
+	/// Moves all the elements of `other` into `self`, leaving `other` empty.
+	#[inline(always)]
+	pub fn append(&mut self, other: &mut Self)
+	{
+		self.reserve(other.len);
+		while let Some(element) = other.pop_front()
+		{
+			self.push_back(element);
+		}
+	}
+	
+	/// Splits the `CtoVecDeque` into two at the given index.
+	/// Returns a newly allocated `CtoVecDeque` containing the elements `[at, len)`.
+	#[inline(always)]
+	pub fn split_off(&mut self, at: usize) -> Self
+	{
+		assert!(at <= self.len, "at out of bounds");
+		
+		let mut other = Self::with_capacity(self.len - at, self.buf.alloc().clone());
+		while self.len > at
+		{
+			let element = self.pop_back().unwrap();
+			other.push_front(element);
+		}
+		other
+	}
+	
+	/// Returns a front-to-back iterator.
+	#[inline(always)]
+	pub fn iter(&self) -> CtoVecDequeIter<T>
+	{
+		let (front, back) = self.as_slices();
+		CtoVecDequeIter
+		{
+			front: front.iter(),
+			back: back.iter(),
+		}
+	}
+	
+	/// Returns a front-to-back iterator that returns mutable references.
+	#[inline(always)]
+	pub fn iter_mut(&mut self) -> CtoVecDequeIterMut<T>
+	{
+		let (front, back) = self.as_mut_slices();
+		CtoVecDequeIterMut
+		{
+			front: front.iter_mut(),
+			back: back.iter_mut(),
+		}
+	}
+	
+	/// Returns a pair of slices which contain, in order, the contents of the `CtoVecDeque`.
+	#[inline(always)]
+	pub fn as_slices(&self) -> (&[T], &[T])
+	{
+		let (front_length, back_length) = self.slice_lengths();
+		unsafe { (from_raw_parts(self.buf.ptr().offset(self.head as isize), front_length), from_raw_parts(self.buf.ptr(), back_length)) }
+	}
+	
+	/// Returns a pair of mutable slices which contain, in order, the contents of the `CtoVecDeque`.
+	#[inline(always)]
+	pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T])
+	{
+		let (front_length, back_length) = self.slice_lengths();
+		unsafe { (from_raw_parts_mut(self.buf.ptr().offset(self.head as isize), front_length), from_raw_parts_mut(self.buf.ptr(), back_length)) }
+	}
+	
+	/// Creates a draining iterator that removes the specified range in the `CtoVecDeque` and yields the removed items.
+	/// The elements are removed when the iterator is dropped, even if it is not fully consumed; any not consumed are dropped then.
+	/// If the iterator is leaked, the `CtoVecDeque` may lose the removed elements and those after them.
+	pub fn drain<R: RangeArgument<usize>>(&mut self, range: R) -> CtoVecDequeDrain<T>
+	{
+		let len = self.len;
+		let start = match range.start()
+		{
+			Included(&n) => n,
+			Excluded(&n) => n + 1,
+			Unbounded    => 0,
+		};
+		let end = match range.end()
+		{
+			Included(&n) => n + 1,
+			Excluded(&n) => n,
+			Unbounded    => len,
+		};
+		assert!(start <= end, "drain lower bound was too large");
+		assert!(end <= len, "drain upper bound was too large");
+		
+		// Set the length to `start`, to be safe in case the drain is leaked; the drain restores the tail when dropped.
+		self.len = start;
+		
+		CtoVecDequeDrain
+		{
+			front: start,
+			back: end,
+			tail_start: end,
+			tail_len: len - end,
+			deque: NonNull::from(self),
+			marker: PhantomData,
+		}
+	}
+	
+	#[inline(always)]
+	fn slice_lengths(&self) -> (usize, usize)
+	{
+		let until_end_of_buffer = self.capacity() - self.head;
+		if self.len <= until_end_of_buffer
+		{
+			(self.len, 0)
+		}
+		else
+		{
+			(until_end_of_buffer, self.len - until_end_of_buffer)
+		}
+	}
+	
+	/// Maps a logical index (0 is the front) to an index into `buf`; `logical_index` must be less than the capacity.
+	#[inline(always)]
+	fn physical_index(&self, logical_index: usize) -> usize
+	{
+		let until_end_of_buffer = self.capacity() - self.head;
+		if logical_index >= until_end_of_buffer
+		{
+			logical_index - until_end_of_buffer
+		}
+		else
+		{
+			self.head + logical_index
+		}
+	}
+	
+	#[inline(always)]
+	unsafe fn pointer(&self, logical_index: usize) -> *mut T
+	{
+		self.buf.ptr().offset(self.physical_index(logical_index) as isize)
+	}
+	
+	/// Moves the contents into a new buffer of `new_capacity`, so that they start at index 0.
+	fn relocate(&mut self, new_capacity: usize)
+	{
+		debug_assert!(new_capacity >= self.len, "new_capacity is too small");
+		
+		let buf = RawVec::with_capacity_in(new_capacity, self.buf.alloc().clone());
+		{
+			let (front, back) = self.as_slices();
+			unsafe
+			{
+				copy_nonoverlapping(front.as_ptr(), buf.ptr(), front.len());
+				copy_nonoverlapping(back.as_ptr(), buf.ptr().offset(front.len() as isize), back.len());
+			}
+		}
+		
+		// The old buffer deallocates without dropping its contents, which have been moved.
+		self.buf = buf;
+		self.head = 0;
+	}
+}
@@ -0,0 +1,132 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// A draining iterator over the elements of a `CtoVecDeque`.
+/// Elements are read out of the deque's buffer as they are yielded; the gap left behind is closed when the iterator is dropped.
+pub struct CtoVecDequeDrain<'a, T: 'a + CtoSafe>
+{
+	/// Logical index of the next element to yield from the front.
+	front: usize,
+	
+	/// Logical index one after the next element to yield from the back.
+	back: usize,
+	
+	/// Logical index of tail to preserve
+	tail_start: usize,
+	
+	/// Length of tail
+	tail_len: usize,
+	
+	deque: NonNull<CtoVecDeque<T>>,
+	
+	marker: PhantomData<&'a mut CtoVecDeque<T>>,
+}
+
+impl<'a, T: 'a + CtoSafe + Debug> Debug for CtoVecDequeDrain<'a, T>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		let deque = unsafe { self.deque.as_ref() };
+		f.debug_list().entries((self.front .. self.back).map(|index| unsafe { &*deque.pointer(index) })).finish()
+	}
+}
+
+unsafe impl<'a, T: CtoSafe + Sync> Sync for CtoVecDequeDrain<'a, T>
+{
+}
+
+unsafe impl<'a, T: CtoSafe + Send> Send for CtoVecDequeDrain<'a, T>
+{
+}
+
+impl<'a, T: 'a + CtoSafe> Iterator for CtoVecDequeDrain<'a, T>
+{
+	type Item = T;
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		if self.front == self.back
+		{
+			return None;
+		}
+		
+		let index = self.front;
+		self.front += 1;
+		Some(unsafe { read(self.deque.as_ref().pointer(index)) })
+	}
+	
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		let remaining = self.back - self.front;
+		(remaining, Some(remaining))
+	}
+}
+
+impl<'a, T: 'a + CtoSafe> DoubleEndedIterator for CtoVecDequeDrain<'a, T>
+{
+	#[inline(always)]
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		if self.front == self.back
+		{
+			return None;
+		}
+		
+		self.back -= 1;
+		Some(unsafe { read(self.deque.as_ref().pointer(self.back)) })
+	}
+}
+
+impl<'a, T: 'a + CtoSafe> Drop for CtoVecDequeDrain<'a, T>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		// exhaust self first
+		while let Some(_) = self.next()
+		{
+		}
+		
+		unsafe
+		{
+			let deque = self.deque.as_mut();
+			
+			// The deque's length was set to the start of the drained range when draining began.
+			let start = deque.len;
+			let count = self.tail_start - start;
+			
+			// Close the gap by moving whichever side is shorter.
+			if count != 0
+			{
+				if start <= self.tail_len
+				{
+					for index in (0 .. start).rev()
+					{
+						copy_nonoverlapping(deque.pointer(index), deque.pointer(index + count), 1);
+					}
+					deque.head = deque.physical_index(count);
+				}
+				else
+				{
+					for index in self.tail_start .. self.tail_start + self.tail_len
+					{
+						copy_nonoverlapping(deque.pointer(index), deque.pointer(index - count), 1);
+					}
+				}
+			}
+			
+			deque.len = start + self.tail_len;
+		}
+	}
+}
+
+impl<'a, T: 'a + CtoSafe> ExactSizeIterator for CtoVecDequeDrain<'a, T>
+{
+}
+
+impl<'a, T: 'a + CtoSafe> FusedIterator for CtoVecDequeDrain<'a, T>
+{
+}
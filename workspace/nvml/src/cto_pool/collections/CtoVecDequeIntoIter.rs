@@ -0,0 +1,51 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// An owning iterator over the elements of a `CtoVecDeque`.
+pub struct CtoVecDequeIntoIter<T: CtoSafe>
+{
+	deque: CtoVecDeque<T>,
+}
+
+impl<T: CtoSafe + Debug> Debug for CtoVecDequeIntoIter<T>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_tuple("CtoVecDequeIntoIter").field(&self.deque).finish()
+	}
+}
+
+impl<T: CtoSafe> Iterator for CtoVecDequeIntoIter<T>
+{
+	type Item = T;
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.deque.pop_front()
+	}
+	
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		(self.deque.len, Some(self.deque.len))
+	}
+}
+
+impl<T: CtoSafe> DoubleEndedIterator for CtoVecDequeIntoIter<T>
+{
+	#[inline(always)]
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		self.deque.pop_back()
+	}
+}
+
+impl<T: CtoSafe> ExactSizeIterator for CtoVecDequeIntoIter<T>
+{
+}
+
+impl<T: CtoSafe> FusedIterator for CtoVecDequeIntoIter<T>
+{
+}
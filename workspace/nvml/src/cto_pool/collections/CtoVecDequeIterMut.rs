@@ -0,0 +1,61 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// A mutable iterator over the elements of a `CtoVecDeque`.
+pub struct CtoVecDequeIterMut<'a, T: 'a + CtoSafe>
+{
+	front: slice::IterMut<'a, T>,
+	back: slice::IterMut<'a, T>,
+}
+
+impl<'a, T: 'a + CtoSafe + Debug> Debug for CtoVecDequeIterMut<'a, T>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.debug_tuple("CtoVecDequeIterMut").field(&self.front).field(&self.back).finish()
+	}
+}
+
+impl<'a, T: 'a + CtoSafe> Iterator for CtoVecDequeIterMut<'a, T>
+{
+	type Item = &'a mut T;
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		match self.front.next()
+		{
+			None => self.back.next(),
+			some => some,
+		}
+	}
+	
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>)
+	{
+		let length = self.front.len() + self.back.len();
+		(length, Some(length))
+	}
+}
+
+impl<'a, T: 'a + CtoSafe> DoubleEndedIterator for CtoVecDequeIterMut<'a, T>
+{
+	#[inline(always)]
+	fn next_back(&mut self) -> Option<Self::Item>
+	{
+		match self.back.next_back()
+		{
+			None => self.front.next_back(),
+			some => some,
+		}
+	}
+}
+
+impl<'a, T: 'a + CtoSafe> ExactSizeIterator for CtoVecDequeIterMut<'a, T>
+{
+}
+
+impl<'a, T: 'a + CtoSafe> FusedIterator for CtoVecDequeIterMut<'a, T>
+{
+}
@@ -3,7 +3,7 @@
 
 
 /// A draining iterator for `Vec<T>`.
-pub struct CtoVecDrain<'a, T: 'a + CtoSafe>
+pub struct CtoVecDrain<'a, T: 'a + CtoSafe, P: 'a + Persistence = VolatileMemoryPersistence>
 {
 	/// Index of tail to preserve
 	tail_start: usize,
@@ -14,10 +14,10 @@ pub struct CtoVecDrain<'a, T: 'a + CtoSafe>
 	/// Current remaining range to remove
 	iter: slice::Iter<'a, T>,
 	
-	vec: NonNull<CtoVec<T>>,
+	vec: NonNull<CtoVec<T, P>>,
 }
 
-impl<'a, T: 'a + CtoSafe + Debug> Debug for CtoVecDrain<'a, T>
+impl<'a, T: 'a + CtoSafe + Debug, P: Persistence> Debug for CtoVecDrain<'a, T, P>
 {
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result
 	{
@@ -25,15 +25,15 @@ impl<'a, T: 'a + CtoSafe + Debug> Debug for CtoVecDrain<'a, T>
 	}
 }
 
-unsafe impl<'a, T: CtoSafe + Sync> Sync for CtoVecDrain<'a, T>
+unsafe impl<'a, T: CtoSafe + Sync, P: Persistence> Sync for CtoVecDrain<'a, T, P>
 {
 }
 
-unsafe impl<'a, T: CtoSafe + Send> Send for CtoVecDrain<'a, T>
+unsafe impl<'a, T: CtoSafe + Send, P: Persistence> Send for CtoVecDrain<'a, T, P>
 {
 }
 
-impl<'a, T: CtoSafe> Iterator for CtoVecDrain<'a, T>
+impl<'a, T: CtoSafe, P: Persistence> Iterator for CtoVecDrain<'a, T, P>
 {
 	type Item = T;
 	
@@ -50,7 +50,7 @@ impl<'a, T: CtoSafe> Iterator for CtoVecDrain<'a, T>
 	}
 }
 
-impl<'a, T: CtoSafe> DoubleEndedIterator for CtoVecDrain<'a, T>
+impl<'a, T: CtoSafe, P: Persistence> DoubleEndedIterator for CtoVecDrain<'a, T, P>
 {
 	#[inline(always)]
 	fn next_back(&mut self) -> Option<T>
@@ -59,7 +59,7 @@ impl<'a, T: CtoSafe> DoubleEndedIterator for CtoVecDrain<'a, T>
 	}
 }
 
-impl<'a, T: CtoSafe> Drop for CtoVecDrain<'a, T>
+impl<'a, T: CtoSafe, P: Persistence> Drop for CtoVecDrain<'a, T, P>
 {
 	#[inline(always)]
 	fn drop(&mut self)
@@ -86,7 +86,7 @@ impl<'a, T: CtoSafe> Drop for CtoVecDrain<'a, T>
 	}
 }
 
-impl<'a, T: CtoSafe> ExactSizeIterator for CtoVecDrain<'a, T>
+impl<'a, T: CtoSafe, P: Persistence> ExactSizeIterator for CtoVecDrain<'a, T, P>
 {
 	#[inline(always)]
 	fn is_empty(&self) -> bool
@@ -95,12 +95,12 @@ impl<'a, T: CtoSafe> ExactSizeIterator for CtoVecDrain<'a, T>
 	}
 }
 
-impl<'a, T: CtoSafe> FusedIterator for CtoVecDrain<'a, T>
+impl<'a, T: CtoSafe, P: Persistence> FusedIterator for CtoVecDrain<'a, T, P>
 {
 }
 
 /// Private helper methods for `Splice::drop`
-impl<'a, T: CtoSafe> CtoVecDrain<'a, T>
+impl<'a, T: CtoSafe, P: Persistence> CtoVecDrain<'a, T, P>
 {
 	/// The range from `self.vec.len` to `self.tail_start` contains elements
 	/// that have been moved out.
@@ -118,7 +118,8 @@ impl<'a, T: CtoSafe> CtoVecDrain<'a, T>
 			if let Some(new_item) = replace_with.next()
 			{
 				write(place, new_item);
-				vec.len += 1;
+				let len = vec.len;
+				vec.set_len(len + 1);
 			}
 			else
 			{
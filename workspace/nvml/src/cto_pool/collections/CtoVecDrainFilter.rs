@@ -4,17 +4,17 @@
 
 /// An iterator produced by calling `drain_filter` on CtoVec.
 #[derive(Debug)]
-pub struct CtoVecDrainFilter<'a, T: 'a + CtoSafe, F>
+pub struct CtoVecDrainFilter<'a, T: 'a + CtoSafe, F, P: 'a + Persistence = VolatileMemoryPersistence>
 	where F: FnMut(&mut T) -> bool
 {
-	vec: &'a mut CtoVec<T>,
+	vec: &'a mut CtoVec<T, P>,
 	idx: usize,
 	del: usize,
 	old_len: usize,
 	pred: F,
 }
 
-impl<'a, T: CtoSafe, F> Iterator for CtoVecDrainFilter<'a, T, F>
+impl<'a, T: CtoSafe, F, P: Persistence> Iterator for CtoVecDrainFilter<'a, T, F, P>
 	where F: FnMut(&mut T) -> bool,
 {
 	type Item = T;
@@ -53,7 +53,7 @@ impl<'a, T: CtoSafe, F> Iterator for CtoVecDrainFilter<'a, T, F>
 	}
 }
 
-impl<'a, T: CtoSafe, F> Drop for CtoVecDrainFilter<'a, T, F>
+impl<'a, T: CtoSafe, F, P: Persistence> Drop for CtoVecDrainFilter<'a, T, F, P>
 	where F: FnMut(&mut T) -> bool
 {
 	#[inline(always)]
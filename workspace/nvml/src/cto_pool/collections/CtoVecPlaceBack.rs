@@ -5,14 +5,14 @@
 /// A place for insertion at the back of a `Vec`.
 #[must_use = "places do nothing unless written to with `<-` syntax"]
 #[derive(Debug)]
-pub struct CtoVecPlaceBack<'a, T: 'a + CtoSafe>
+pub struct CtoVecPlaceBack<'a, T: 'a + CtoSafe, P: 'a + Persistence = VolatileMemoryPersistence>
 {
-	vec: &'a mut CtoVec<T>,
+	vec: &'a mut CtoVec<T, P>,
 }
 
-impl<'a, T: CtoSafe> Placer<T> for CtoVecPlaceBack<'a, T>
+impl<'a, T: CtoSafe, P: Persistence> Placer<T> for CtoVecPlaceBack<'a, T, P>
 {
-	type Place = CtoVecPlaceBack<'a, T>;
+	type Place = CtoVecPlaceBack<'a, T, P>;
 	
 	#[inline(always)]
 	fn make_place(self) -> Self
@@ -26,7 +26,7 @@ impl<'a, T: CtoSafe> Placer<T> for CtoVecPlaceBack<'a, T>
 	}
 }
 
-unsafe impl<'a, T: CtoSafe> Place<T> for CtoVecPlaceBack<'a, T>
+unsafe impl<'a, T: CtoSafe, P: Persistence> Place<T> for CtoVecPlaceBack<'a, T, P>
 {
 	#[inline(always)]
 	fn pointer(&mut self) -> *mut T
@@ -35,7 +35,7 @@ unsafe impl<'a, T: CtoSafe> Place<T> for CtoVecPlaceBack<'a, T>
 	}
 }
 
-impl<'a, T: CtoSafe> InPlace<T> for CtoVecPlaceBack<'a, T>
+impl<'a, T: CtoSafe, P: Persistence> InPlace<T> for CtoVecPlaceBack<'a, T, P>
 {
 	type Owner = &'a mut T;
 	
@@ -43,7 +43,8 @@ impl<'a, T: CtoSafe> InPlace<T> for CtoVecPlaceBack<'a, T>
 	unsafe fn finalize(mut self) -> &'a mut T
 	{
 		let ptr = self.pointer();
-		self.vec.len += 1;
+		let len = self.vec.len;
+		self.vec.set_len(len + 1);
 		&mut *ptr
 	}
 }
@@ -4,14 +4,14 @@
 
 /// A splicing iterator for `Vec`.
 #[derive(Debug)]
-pub struct CtoVecSplice<'a, I: Iterator + 'a>
+pub struct CtoVecSplice<'a, I: Iterator + 'a, P: 'a + Persistence = VolatileMemoryPersistence>
 	where <I as Iterator>::Item: CtoSafe
 {
-	drain: CtoVecDrain<'a, I::Item>,
+	drain: CtoVecDrain<'a, I::Item, P>,
 	replace_with: I,
 }
 
-impl<'a, I: Iterator> Iterator for CtoVecSplice<'a, I>
+impl<'a, I: Iterator, P: Persistence> Iterator for CtoVecSplice<'a, I, P>
 	where <I as Iterator>::Item: CtoSafe
 {
 	type Item = I::Item;
@@ -29,7 +29,7 @@ impl<'a, I: Iterator> Iterator for CtoVecSplice<'a, I>
 	}
 }
 
-impl<'a, I: Iterator> DoubleEndedIterator for CtoVecSplice<'a, I>
+impl<'a, I: Iterator, P: Persistence> DoubleEndedIterator for CtoVecSplice<'a, I, P>
 	where <I as Iterator>::Item: CtoSafe
 {
 	#[inline(always)]
@@ -39,12 +39,12 @@ impl<'a, I: Iterator> DoubleEndedIterator for CtoVecSplice<'a, I>
 	}
 }
 
-impl<'a, I: Iterator> ExactSizeIterator for CtoVecSplice<'a, I>
+impl<'a, I: Iterator, P: Persistence> ExactSizeIterator for CtoVecSplice<'a, I, P>
 	where <I as Iterator>::Item: CtoSafe
 {
 }
 
-impl<'a, I: Iterator> Drop for CtoVecSplice<'a, I>
+impl<'a, I: Iterator, P: Persistence> Drop for CtoVecSplice<'a, I, P>
 	where <I as Iterator>::Item: CtoSafe
 {
 	#[inline(always)]
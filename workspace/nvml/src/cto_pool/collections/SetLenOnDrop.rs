@@ -7,21 +7,27 @@
 // The idea is: The length field in SetLenOnDrop is a local variable
 // that the optimizer will see does not alias with any stores through the Vec's data
 // pointer. This is a workaround for alias analysis issue #32155
-struct SetLenOnDrop<'a>
+//
+// The elements written are flushed before the length is set.
+struct SetLenOnDrop<'a, T: 'a + CtoSafe, P: 'a + Persistence>
 {
 	len: &'a mut usize,
 	local_len: usize,
+	pointer: *const T,
+	persistence: PhantomData<P>,
 }
 
-impl<'a> SetLenOnDrop<'a>
+impl<'a, T: CtoSafe, P: Persistence> SetLenOnDrop<'a, T, P>
 {
 	#[inline(always)]
-	fn new(len: &'a mut usize) -> Self
+	fn new(len: &'a mut usize, pointer: *const T) -> Self
 	{
 		Self
 		{
 			local_len: *len,
-			len
+			len,
+			pointer,
+			persistence: PhantomData,
 		}
 	}
 	
@@ -32,11 +38,12 @@ impl<'a> SetLenOnDrop<'a>
 	}
 }
 
-impl<'a> Drop for SetLenOnDrop<'a>
+impl<'a, T: CtoSafe, P: Persistence> Drop for SetLenOnDrop<'a, T, P>
 {
 	#[inline(always)]
 	fn drop(&mut self)
 	{
-		*self.len = self.local_len;
+		CtoVec::<T, P>::flush_elements(self.pointer, *self.len, self.local_len);
+		CtoVec::<T, P>::persist_len(self.len, self.local_len);
 	}
 }
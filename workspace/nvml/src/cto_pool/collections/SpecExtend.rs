@@ -7,7 +7,7 @@ trait SpecExtend<T, I>
 	fn spec_extend(&mut self, iterator: I);
 }
 
-impl<T: CtoSafe, I: Iterator<Item=T>> SpecExtend<T, I> for CtoVec<T>
+impl<T: CtoSafe, I: Iterator<Item=T>, P: Persistence> SpecExtend<T, I> for CtoVec<T, P>
 {
 	#[inline(always)]
 	default fn spec_extend(&mut self, iterator: I)
@@ -16,7 +16,7 @@ impl<T: CtoSafe, I: Iterator<Item=T>> SpecExtend<T, I> for CtoVec<T>
 	}
 }
 
-impl<T: CtoSafe, I: TrustedLen<Item=T>> SpecExtend<T, I> for CtoVec<T>
+impl<T: CtoSafe, I: TrustedLen<Item=T>, P: Persistence> SpecExtend<T, I> for CtoVec<T, P>
 {
 	#[inline(always)]
 	default fn spec_extend(&mut self, iterator: I)
@@ -33,8 +33,9 @@ impl<T: CtoSafe, I: TrustedLen<Item=T>> SpecExtend<T, I> for CtoVec<T>
 			
 			unsafe
 			{
-				let mut ptr = self.as_mut_ptr().offset(self.len() as isize);
-				let mut local_len = SetLenOnDrop::new(&mut self.len);
+				let pointer = self.buf.ptr();
+				let mut ptr = pointer.offset(self.len() as isize);
+				let mut local_len = SetLenOnDrop::<T, P>::new(&mut self.len, pointer);
 				for element in iterator
 				{
 					write(ptr, element);
@@ -52,7 +53,7 @@ impl<T: CtoSafe, I: TrustedLen<Item=T>> SpecExtend<T, I> for CtoVec<T>
 	}
 }
 
-impl<'a, T: 'a + CtoSafe + Clone, I: Iterator<Item=&'a T>> SpecExtend<&'a T, I> for CtoVec<T>
+impl<'a, T: 'a + CtoSafe + Clone, I: Iterator<Item=&'a T>, P: Persistence> SpecExtend<&'a T, I> for CtoVec<T, P>
 {
 	#[inline(always)]
 	default fn spec_extend(&mut self, iterator: I)
@@ -61,7 +62,7 @@ impl<'a, T: 'a + CtoSafe + Clone, I: Iterator<Item=&'a T>> SpecExtend<&'a T, I>
 	}
 }
 
-impl<'a, T: 'a + CtoSafe + Copy> SpecExtend<&'a T, slice::Iter<'a, T>> for CtoVec<T>
+impl<'a, T: 'a + CtoSafe + Copy, P: Persistence> SpecExtend<&'a T, slice::Iter<'a, T>> for CtoVec<T, P>
 {
 	#[inline(always)]
 	fn spec_extend(&mut self, iterator: slice::Iter<'a, T>)
@@ -71,13 +72,13 @@ impl<'a, T: 'a + CtoSafe + Copy> SpecExtend<&'a T, slice::Iter<'a, T>> for CtoVe
 		unsafe
 		{
 			let len = self.len();
+			copy_nonoverlapping(slice.as_ptr(), self.as_mut_ptr().offset(len as isize), slice.len());
 			self.set_len(len + slice.len());
-			self.get_unchecked_mut(len..).copy_from_slice(slice);
 		}
 	}
 }
 
-impl<T: CtoSafe> SpecExtend<T, CtoVecIntoIter<T>> for CtoVec<T>
+impl<T: CtoSafe, P: Persistence> SpecExtend<T, CtoVecIntoIter<T>> for CtoVec<T, P>
 {
 	#[inline(always)]
 	fn spec_extend(&mut self, mut iterator: CtoVecIntoIter<T>)
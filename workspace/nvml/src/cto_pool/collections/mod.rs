@@ -4,11 +4,20 @@
 
 use super::*;
 use ::alloc::raw_vec::RawVec;
+use ::persistent_memory::persistence::Persistence;
+use ::persistent_memory::persistence::VolatileMemoryPersistence;
+use ::std::collections::Bound;
 use ::std::collections::Bound::Included;
 use ::std::collections::Bound::Excluded;
 use ::std::collections::Bound::Unbounded;
+use ::std::collections::hash_map::RandomState;
 use ::std::collections::range::RangeArgument;
+use ::std::cmp::max;
+use ::std::hash::BuildHasher;
+#[allow(deprecated)] use ::std::hash::SipHasher;
 use ::std::mem::forget;
+use ::std::mem::needs_drop;
+use ::std::mem::replace;
 use ::std::mem::size_of;
 use ::std::mem::swap;
 use ::std::intrinsics::arith_offset;
@@ -19,15 +28,52 @@ use ::std::ops::*;
 use ::std::ptr::copy;
 use ::std::ptr::copy_nonoverlapping;
 use ::std::ptr::drop_in_place;
+use ::std::ptr::null_mut;
 use ::std::ptr::read;
 use ::std::ptr::write;
+use ::std::ptr::write_bytes;
 use ::std::slice;
 use ::std::slice::from_raw_parts;
 use ::std::slice::from_raw_parts_mut;
 
 
+include!("CtoBinaryHeap.rs");
+include!("CtoBinaryHeapPeekMut.rs");
+include!("CtoBTreeMap.rs");
+include!("CtoBTreeMapCursor.rs");
+include!("CtoBTreeMapIntoIter.rs");
+include!("CtoBTreeMapIter.rs");
+include!("CtoBTreeMapIterMut.rs");
+include!("CtoBTreeMapKeys.rs");
+include!("CtoBTreeMapNode.rs");
+include!("CtoBTreeMapRange.rs");
+include!("CtoBTreeMapRangeMut.rs");
+include!("CtoBTreeMapRawRange.rs");
+include!("CtoBTreeMapValues.rs");
+include!("CtoBTreeMapValuesMut.rs");
+include!("CtoBTreeSet.rs");
+include!("CtoBTreeSetIntoIter.rs");
+include!("CtoBTreeSetIter.rs");
+include!("CtoBTreeSetRange.rs");
+include!("CtoHashMap.rs");
+include!("CtoHashMapDrain.rs");
+include!("CtoHashMapEntry.rs");
+include!("CtoHashMapIntoIter.rs");
+include!("CtoHashMapIter.rs");
+include!("CtoHashMapIterMut.rs");
+include!("CtoHashMapKeys.rs");
+include!("CtoHashMapOccupiedEntry.rs");
+include!("CtoHashMapRawIter.rs");
+include!("CtoHashMapVacantEntry.rs");
+include!("CtoHashMapValues.rs");
+include!("CtoHashMapValuesMut.rs");
 include!("CtoVec.rs");
 include!("CtoVecDrain.rs");
+include!("CtoVecDeque.rs");
+include!("CtoVecDequeDrain.rs");
+include!("CtoVecDequeIntoIter.rs");
+include!("CtoVecDequeIter.rs");
+include!("CtoVecDequeIterMut.rs");
 include!("CtoVecDrainFilter.rs");
 include!("CtoVecIntoIter.rs");
 include!("CtoVecPlaceBack.rs");
@@ -2,16 +2,102 @@
 // Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
 
 
+type MallocFunction = unsafe extern "C" fn(size: size_t) -> *mut c_void;
+
+type FreeFunction = unsafe extern "C" fn(ptr: *mut c_void);
+
+type ReallocFunction = unsafe extern "C" fn(ptr: *mut c_void, size: size_t) -> *mut c_void;
+
+type StrdupFunction = unsafe extern "C" fn(s: *const c_char) -> *mut c_char;
+
+type PrintFunction = unsafe extern "C" fn(s: *const c_char);
+
+/// The `print` function passed to `initialise_memory_functions()`, as a `usize`; `0` until then.
+static UserPrintFunction: AtomicUsize = AtomicUsize::new(0);
+
+/// Installs `print_trampoline()` as libpmemcto's `print` function exactly once, either with the memory functions passed to `initialise_memory_functions()` or, by default, with libpmemcto's own.
+static PrintTrampolineInstalled: Once = ONCE_INIT;
+
+thread_local!
+{
+	static CapturedStatistics: RefCell<Option<String>> = RefCell::new(None);
+}
+
 /// Initialise memory functions used internally by libpmemcto.
 /// Use if different to libc standard (eg if using DPDK).
+/// Call before any CTO pool is opened; libpmemcto's functions are process-wide.
+/// `print` is wrapped so that `CtoPool::statistics()` can capture output on the calling thread; output on any other thread is passed to `print`.
 pub fn initialise_memory_functions
 (
-	malloc: unsafe extern "C" fn(size: size_t) -> *mut c_void,
-	free: unsafe extern "C" fn(ptr: *mut c_void),
-	realloc: unsafe extern "C" fn(ptr: *mut c_void, size: size_t) -> *mut c_void,
-	strdup: unsafe extern "C" fn(s: *const c_char) -> *mut c_char,
-	print: unsafe extern "C" fn(s: *const c_char),
+	malloc: MallocFunction,
+	free: FreeFunction,
+	realloc: ReallocFunction,
+	strdup: StrdupFunction,
+	print: PrintFunction,
 )
 {
-	unsafe { pmemcto_set_funcs(Some(malloc), Some(free), Some(realloc), Some(strdup), Some(print)) }
+	UserPrintFunction.store(print as usize, Release);
+	
+	// Stops `install_print_trampoline()` later replacing these memory functions with libpmemcto's own.
+	PrintTrampolineInstalled.call_once(|| ());
+	
+	unsafe { pmemcto_set_funcs(Some(malloc), Some(free), Some(realloc), Some(strdup), Some(print_trampoline)) }
+}
+
+/// Installs `print_trampoline()`, keeping libpmemcto's own memory functions, unless `initialise_memory_functions()` has already been called.
+#[inline(always)]
+fn install_print_trampoline()
+{
+	PrintTrampolineInstalled.call_once(|| unsafe { pmemcto_set_funcs(None, None, None, None, Some(print_trampoline)) })
+}
+
+/// Runs `pmemcto_stats_print()` for `pool_pointer` and returns its output rather than printing it.
+fn capture_statistics(pool_pointer: *mut PMEMctopool, options: &CStr) -> String
+{
+	debug_assert!(pool_pointer.is_not_null(), "pool_pointer can not be null");
+	
+	install_print_trampoline();
+	
+	CapturedStatistics.with(|captured| *captured.borrow_mut() = Some(String::with_capacity(16 * 1024)));
+	
+	unsafe { pmemcto_stats_print(pool_pointer, options.as_ptr()) }
+	
+	CapturedStatistics.with(|captured| captured.borrow_mut().take()).unwrap_or_default()
+}
+
+/// Installed permanently as libpmemcto's `print` function; appends to the calling thread's captured statistics if being captured, otherwise passes output to the `print` function passed to `initialise_memory_functions()`.
+/// If there is no such function, output goes to standard error, as it does for libpmemcto's own `print` function.
+unsafe extern "C" fn print_trampoline(s: *const c_char)
+{
+	if s.is_null()
+	{
+		return;
+	}
+	
+	let was_captured = CapturedStatistics.with(|captured|
+	{
+		match *captured.borrow_mut()
+		{
+			Some(ref mut buffer) =>
+			{
+				buffer.push_str(&CStr::from_ptr(s).to_string_lossy());
+				true
+			}
+			None => false,
+		}
+	});
+	
+	if likely(!was_captured)
+	{
+		let print = UserPrintFunction.load(Acquire);
+		if likely(print != 0)
+		{
+			let print: PrintFunction = transmute(print);
+			print(s)
+		}
+		else
+		{
+			let _ = stderr().write_all(CStr::from_ptr(s).to_bytes());
+		}
+	}
 }
@@ -4,13 +4,19 @@
 
 use IsNotNull;
 use self::arc::CtoArc;
+use self::collections::CtoBinaryHeap;
+use self::collections::CtoBTreeMap;
+use self::collections::CtoBTreeSet;
+use self::collections::CtoHashMap;
 use self::collections::CtoVec;
+use self::collections::CtoVecDeque;
 use self::parking_lot_ext::CtoParkingLotMutexLock;
 use self::parking_lot_ext::CtoParkingLotReadWriteLock;
 use self::parking_lot_ext::CtoParkingLotReentrantMutexLock;
 use self::string::CtoString;
 use self::synchronisation::CtoMutexLock;
 use self::synchronisation::CtoReadWriteLock;
+#[cfg(target_os = "linux")] use self::synchronisation::CtoRobustMutexLock;
 use self::boxed::CtoBox;
 use self::rc::CtoRc;
 use ::errors::PmdkError;
@@ -19,12 +25,26 @@ use ::libc::c_void;
 use ::libc::mode_t;
 use ::libc::size_t;
 use ::libc::wchar_t;
+use ::Configuration;
+use ::PoolKind;
+use ::PoolOpenFailure::*;
+use ::PoolsOpenError;
 use ::nvml_sys::*;
+use ::parking_lot::Mutex;
+use ::persistent_memory::c_voidExt;
+use ::persistent_memory::persistence::MMapMemoryPersistence;
+use ::persistent_memory::persistence::Persistence;
+use ::persistent_memory::persistence::PersistentMemoryPersistence;
+use ::parking_lot::MutexGuard;
+use ::rust_extra::likely;
 use ::rust_extra::unlikely;
+use ::std::any::TypeId;
 use ::std::borrow::Borrow;
 use ::std::borrow::BorrowMut;
+use ::std::cell::RefCell;
 use ::std::cmp::min;
 use ::std::cmp::Ordering;
+use ::std::collections::HashMap;
 use ::std::error;
 use ::std::ffi::CString;
 use ::std::ffi::CStr;
@@ -35,6 +55,8 @@ use ::std::fmt::Formatter;
 use ::std::fmt::Pointer;
 use ::std::hash::Hash;
 use ::std::hash::Hasher;
+use ::std::io::stderr;
+use ::std::io::Write;
 use ::std::heap::Alloc;
 use ::std::heap::AllocErr;
 use ::std::heap::CannotReallocInPlace;
@@ -42,7 +64,14 @@ use ::std::heap::Excess;
 use ::std::heap::Layout;
 use ::std::marker::PhantomData;
 use ::std::mem::align_of;
+use ::std::mem::forget;
 use ::std::mem::size_of;
+use ::std::mem::transmute;
+use ::std::num::NonZeroU8;
+use ::std::num::NonZeroU16;
+use ::std::num::NonZeroU32;
+use ::std::num::NonZeroU64;
+use ::std::num::NonZeroUsize;
 use ::std::ops::Deref;
 use ::std::ops::DerefMut;
 #[cfg(unix)] use ::std::os::unix::ffi::OsStrExt;
@@ -50,10 +79,18 @@ use ::std::ptr::copy_nonoverlapping;
 use ::std::ptr::drop_in_place;
 use ::std::ptr::NonNull;
 use ::std::ptr::null;
+use ::std::ptr::read;
 use ::std::ptr::write;
 use ::std::path::Path;
+use ::std::slice::from_raw_parts_mut;
+use ::std::sync::ONCE_INIT;
+use ::std::sync::Once;
+use ::std::sync::atomic::AtomicBool;
 use ::std::sync::atomic::AtomicUsize;
+use ::std::sync::atomic::Ordering::Acquire;
+use ::std::sync::atomic::Ordering::Release;
 use ::std::sync::atomic::Ordering::SeqCst;
+use ::std::time::Duration;
 
 /// An Arc like that in regular Rust's stdlib.
 pub mod arc;
@@ -87,8 +124,9 @@ pub mod synchronisation;
 /// A String like that in regular Rust's stdlib.
 pub mod string;
 
-// /// A wait-free queue based on Yang-Crummey
-// pub mod wait_free_queue;
+/// A wait-free queue based on Yang-Crummey.
+/// Start with `WaitFreeQueue`.
+pub mod wait_free_queue;
 
 include!("Allocator.rs");
 include!("CtoPool.rs");
@@ -96,8 +134,19 @@ include!("CtoPoolAlloc.rs");
 include!("CtoPoolAllocationError.rs");
 include!("CtoPoolArcInner.rs");
 include!("CtoPoolArc.rs");
+include!("CtoPoolConfiguration.rs");
 include!("CtoPoolOpenError.rs");
 include!("CtoPoolPathExt.rs");
+include!("CtoPoolRootAttachment.rs");
+include!("CtoPoolSizeClassStatistics.rs");
+include!("CtoPointerLayoutHeader.rs");
+include!("CtoPointerLayoutVersion.rs");
+include!("CtoPoolsConfiguration.rs");
+include!("CtoPoolStatistics.rs");
+include!("CtoRootFingerprint.rs");
+include!("CtoRootLayout.rs");
+include!("CtoRootMigrations.rs");
+include!("CtoRootVersion.rs");
 include!("CtoSafe.rs");
 include!("initialise_memory_functions.rs");
 include!("PersistentMemoryWrapper.rs");
@@ -3,6 +3,7 @@
 
 
 /// Lock-free read access, but write access requires a lock and a Clone, so it is very inefficient for large objects.
+/// See `::cto_pool::arc::ReadCopyUpdateCell` for an alternative that does not need a lock for writers.
 #[derive(Debug)]
 pub struct ReadCopyUpdateLock<Value: CtoSafe>
 {
@@ -3,7 +3,7 @@
 
 
 /// Similar to a Rust Rc but allocated in a persistent memory CTO Pool.
-pub struct CtoRc<Value: CtoSafe>
+pub struct CtoRc<Value: ?Sized + CtoSafe>
 {
 	persistent_memory_pointer: NonNull<CtoRcInner<Value>>
 }
@@ -33,7 +33,7 @@ impl<Value: CtoSafe> PersistentMemoryWrapper for CtoRc<Value>
 	}
 }
 
-impl<Value: CtoSafe> CtoSafe for CtoRc<Value>
+impl<Value: ?Sized + CtoSafe> CtoSafe for CtoRc<Value>
 {
 	#[inline(always)]
 	fn cto_pool_opened(&mut self, cto_pool_arc: &CtoPoolArc)
@@ -42,7 +42,7 @@ impl<Value: CtoSafe> CtoSafe for CtoRc<Value>
 	}
 }
 
-impl<Value: CtoSafe> Drop for CtoRc<Value>
+impl<Value: ?Sized + CtoSafe> Drop for CtoRc<Value>
 {
 	#[inline(always)]
 	fn drop(&mut self)
@@ -77,7 +77,7 @@ impl<Value: CtoSafe> Drop for CtoRc<Value>
 	}
 }
 
-impl<Value: CtoSafe> Clone for CtoRc<Value>
+impl<Value: ?Sized + CtoSafe> Clone for CtoRc<Value>
 {
 	#[inline(always)]
 	fn clone(&self) -> Self
@@ -91,15 +91,15 @@ impl<Value: CtoSafe> Clone for CtoRc<Value>
 	}
 }
 
-impl<Value: CtoSafe> !Send for CtoRc<Value>
+impl<Value: ?Sized + CtoSafe> !Send for CtoRc<Value>
 {
 }
 
-impl<Value: CtoSafe> !Sync for CtoRc<Value>
+impl<Value: ?Sized + CtoSafe> !Sync for CtoRc<Value>
 {
 }
 
-impl<Value: CtoSafe + PartialEq> PartialEq for CtoRc<Value>
+impl<Value: ?Sized + CtoSafe + PartialEq> PartialEq for CtoRc<Value>
 {
 	#[inline(always)]
 	fn eq(&self, other: &Self) -> bool
@@ -114,11 +114,11 @@ impl<Value: CtoSafe + PartialEq> PartialEq for CtoRc<Value>
 	}
 }
 
-impl<Value: CtoSafe + Eq> Eq for CtoRc<Value>
+impl<Value: ?Sized + CtoSafe + Eq> Eq for CtoRc<Value>
 {
 }
 
-impl<Value: CtoSafe + PartialOrd> PartialOrd for CtoRc<Value>
+impl<Value: ?Sized + CtoSafe + PartialOrd> PartialOrd for CtoRc<Value>
 {
 	#[inline(always)]
 	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
@@ -151,7 +151,7 @@ impl<Value: CtoSafe + PartialOrd> PartialOrd for CtoRc<Value>
 	}
 }
 
-impl<Value: CtoSafe + Ord> Ord for CtoRc<Value>
+impl<Value: ?Sized + CtoSafe + Ord> Ord for CtoRc<Value>
 {
 	#[inline(always)]
 	fn cmp(&self, other: &Self) -> Ordering
@@ -160,7 +160,7 @@ impl<Value: CtoSafe + Ord> Ord for CtoRc<Value>
 	}
 }
 
-impl<Value: CtoSafe + Hash> Hash for CtoRc<Value>
+impl<Value: ?Sized + CtoSafe + Hash> Hash for CtoRc<Value>
 {
 	#[inline(always)]
 	fn hash<H: Hasher>(&self, state: &mut H)
@@ -169,7 +169,7 @@ impl<Value: CtoSafe + Hash> Hash for CtoRc<Value>
 	}
 }
 
-impl<Value: CtoSafe + Display> Display for CtoRc<Value>
+impl<Value: ?Sized + CtoSafe + Display> Display for CtoRc<Value>
 {
 	#[inline(always)]
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result
@@ -178,7 +178,7 @@ impl<Value: CtoSafe + Display> Display for CtoRc<Value>
 	}
 }
 
-impl<Value: CtoSafe + Debug> Debug for CtoRc<Value>
+impl<Value: ?Sized + CtoSafe + Debug> Debug for CtoRc<Value>
 {
 	#[inline(always)]
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result
@@ -187,7 +187,7 @@ impl<Value: CtoSafe + Debug> Debug for CtoRc<Value>
 	}
 }
 
-impl<Value: CtoSafe> Pointer for CtoRc<Value>
+impl<Value: ?Sized + CtoSafe> Pointer for CtoRc<Value>
 {
 	#[inline(always)]
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result
@@ -196,7 +196,7 @@ impl<Value: CtoSafe> Pointer for CtoRc<Value>
 	}
 }
 
-impl<Value: CtoSafe> Deref for CtoRc<Value>
+impl<Value: ?Sized + CtoSafe> Deref for CtoRc<Value>
 {
 	type Target = Value;
 	
@@ -207,7 +207,7 @@ impl<Value: CtoSafe> Deref for CtoRc<Value>
 	}
 }
 
-impl<Value: CtoSafe> Borrow<Value> for CtoRc<Value>
+impl<Value: ?Sized + CtoSafe> Borrow<Value> for CtoRc<Value>
 {
 	#[inline(always)]
 	fn borrow(&self) -> &Value
@@ -216,7 +216,7 @@ impl<Value: CtoSafe> Borrow<Value> for CtoRc<Value>
 	}
 }
 
-impl<Value: CtoSafe> AsRef<Value> for CtoRc<Value>
+impl<Value: ?Sized + CtoSafe> AsRef<Value> for CtoRc<Value>
 {
 	#[inline(always)]
 	fn as_ref(&self) -> &Value
@@ -225,7 +225,7 @@ impl<Value: CtoSafe> AsRef<Value> for CtoRc<Value>
 	}
 }
 
-impl<Value: CtoSafe> CtoRc<Value>
+impl<Value: ?Sized + CtoSafe> CtoRc<Value>
 {
 	/// Downgrades this strong reference to a weak reference.
 	#[inline(always)]
@@ -288,6 +288,46 @@ impl<Value: CtoSafe> CtoRc<Value>
 		this.deref() as *const Value
 	}
 	
+	#[inline(always)]
+	fn persistent_memory(&self) -> &CtoRcInner<Value>
+	{
+		unsafe { self.persistent_memory_pointer.as_ref() }
+	}
+	
+	#[inline(always)]
+	fn persistent_memory_mut(&mut self) -> &mut CtoRcInner<Value>
+	{
+		unsafe { self.persistent_memory_pointer.as_mut() }
+	}
+	
+	#[inline(always)]
+	fn persistent_memory_pointer(&self) -> *mut CtoRcInner<Value>
+	{
+		self.persistent_memory_pointer.as_ptr()
+	}
+}
+
+impl<Value: CtoSafe> CtoRc<Value>
+{
+	/// Moves an already initialized value into a new CtoRc allocated in the persistent memory pool.
+	/// Do not use Heap-allocated objects for fields of Value, ie only use CtoSafe fields.
+	#[inline(always)]
+	pub fn from_value(value: Value, cto_pool_arc: &CtoPoolArc) -> Result<Self, PmdkError>
+	{
+		let persistent_memory_pointer = cto_pool_arc.pool_pointer().aligned_allocate()?;
+		
+		let cto_rc = unsafe
+		{
+			Self::initialize_persistent_memory::<(), _>(persistent_memory_pointer, cto_pool_arc, |value_mut_pointer, _cto_pool_arc|
+			{
+				write(value_mut_pointer, value);
+				Ok(())
+			}).unwrap()
+		};
+		
+		Ok(cto_rc)
+	}
+	
 	/// Gets a raw pointer to Value, suitable for use with FFI.
 	/// Must be eventually passed to `from_raw()`, or a very serious (possibly irrecoverable even with reboots) memory leak will occur.
 	#[inline(always)]
@@ -306,22 +346,62 @@ impl<Value: CtoSafe> CtoRc<Value>
 			persistent_memory_pointer: NonNull::new_unchecked(CtoRcInner::from_raw_value_pointer(raw_value_pointer)),
 		}
 	}
-	
+}
+
+impl<Element: CtoSafe + Clone> CtoRc<[Element]>
+{
+	/// Clones the elements of a slice into a new CtoRc allocated in the persistent memory pool.
 	#[inline(always)]
-	fn persistent_memory(&self) -> &CtoRcInner<Value>
+	pub fn from_slice(slice: &[Element], cto_pool_arc: &CtoPoolArc) -> Result<Self, PmdkError>
 	{
-		unsafe { self.persistent_memory_pointer.as_ref() }
+		Self::from_exact_size_iterator(slice.iter().cloned(), cto_pool_arc)
 	}
-	
+}
+
+impl<Element: CtoSafe> CtoRc<[Element]>
+{
+	/// Moves the elements produced by an iterator into a new CtoRc allocated in the persistent memory pool.
+	/// The elements are first collected using the system allocator, as the length needs to be known before allocating.
 	#[inline(always)]
-	fn persistent_memory_mut(&mut self) -> &mut CtoRcInner<Value>
+	pub fn from_iterator<I: IntoIterator<Item=Element>>(iterable: I, cto_pool_arc: &CtoPoolArc) -> Result<Self, PmdkError>
 	{
-		unsafe { self.persistent_memory_pointer.as_mut() }
+		let elements: Vec<Element> = iterable.into_iter().collect();
+		Self::from_exact_size_iterator(elements.into_iter(), cto_pool_arc)
 	}
 	
 	#[inline(always)]
-	fn persistent_memory_pointer(&self) -> *mut CtoRcInner<Value>
+	fn from_exact_size_iterator<Elements: ExactSizeIterator<Item=Element>>(elements: Elements, cto_pool_arc: &CtoPoolArc) -> Result<Self, PmdkError>
 	{
-		self.persistent_memory_pointer.as_ptr()
+		let persistent_memory_pointer = CtoRcInner::allocate_slice(elements, cto_pool_arc)?;
+		
+		Ok
+		(
+			Self
+			{
+				persistent_memory_pointer: unsafe { NonNull::new_unchecked(persistent_memory_pointer) },
+			}
+		)
+	}
+}
+
+impl CtoRc<str>
+{
+	/// Copies a string slice into a new CtoRc allocated in the persistent memory pool.
+	#[inline(always)]
+	pub fn from_str(value: &str, cto_pool_arc: &CtoPoolArc) -> Result<Self, PmdkError>
+	{
+		let bytes = CtoRc::<[u8]>::from_slice(value.as_bytes(), cto_pool_arc)?;
+		
+		// Works because `str` and `[u8]` have the same layout and `value` was valid UTF-8.
+		let persistent_memory_pointer = bytes.persistent_memory_pointer.as_ptr() as *mut CtoRcInner<str>;
+		forget(bytes);
+		
+		Ok
+		(
+			Self
+			{
+				persistent_memory_pointer: unsafe { NonNull::new_unchecked(persistent_memory_pointer) },
+			}
+		)
 	}
 }
@@ -2,18 +2,18 @@
 // Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
 
 
-// #[repr(C)] is required otherwise `from_raw_value_pointer()` will be very broken indeed.
+// #[repr(C)] is required otherwise `from_raw_value_pointer()` and `allocate_slice()` will be very broken indeed.
 #[repr(C)]
-pub(crate) struct CtoRcInner<Value: CtoSafe>
+pub(crate) struct CtoRcInner<Value: ?Sized + CtoSafe>
 {
-	// Field order matters. `value: Value` must be first otherwise `from_raw_value_pointer()` will be very broken indeed.
-	value: Value,
+	// Field order matters. `value: Value` must be last so that it can be unsized; `from_raw_value_pointer()` and `allocate_slice()` rely on this. Changing it requires incrementing `CtoPointerLayoutVersion`.
 	strong_counter: CtoRcCounter,
 	weak_counter: CtoRcCounter,
 	cto_pool_arc: CtoPoolArc,
+	value: Value,
 }
 
-impl<Value: CtoSafe> Deref for CtoRcInner<Value>
+impl<Value: ?Sized + CtoSafe> Deref for CtoRcInner<Value>
 {
 	type Target = Value;
 	
@@ -24,7 +24,7 @@ impl<Value: CtoSafe> Deref for CtoRcInner<Value>
 	}
 }
 
-impl<Value: CtoSafe> DerefMut for CtoRcInner<Value>
+impl<Value: ?Sized + CtoSafe> DerefMut for CtoRcInner<Value>
 {
 	#[inline(always)]
 	fn deref_mut(&mut self) -> &mut Self::Target
@@ -33,7 +33,7 @@ impl<Value: CtoSafe> DerefMut for CtoRcInner<Value>
 	}
 }
 
-impl<Value: CtoSafe> CtoRcInner<Value>
+impl<Value: ?Sized + CtoSafe> CtoRcInner<Value>
 {
 	#[inline(always)]
 	fn common_initialization(&mut self, cto_pool_arc: &CtoPoolArc)
@@ -42,15 +42,13 @@ impl<Value: CtoSafe> CtoRcInner<Value>
 	}
 	
 	#[inline(always)]
-	fn allocated<InitializationError, Initializer: FnOnce(*mut Value, &CtoPoolArc) -> Result<(), InitializationError>>(&mut self, cto_pool_arc: &CtoPoolArc, initializer: Initializer) -> Result<(), InitializationError>
+	fn header_initialization(&mut self, cto_pool_arc: &CtoPoolArc)
 	{
 		unsafe { write(&mut self.strong_counter, CtoRcCounter::default()) };
 		
 		unsafe { write(&mut self.weak_counter, CtoRcCounter::default()) };
 		
 		self.common_initialization(cto_pool_arc);
-		
-		initializer(&mut self.value, cto_pool_arc)
 	}
 	
 	#[inline(always)]
@@ -61,19 +59,6 @@ impl<Value: CtoSafe> CtoRcInner<Value>
 		self.value.cto_pool_opened(cto_pool_arc)
 	}
 	
-	#[inline(always)]
-	fn into_raw_value_pointer(&mut self) -> *mut Value
-	{
-		self.deref_mut()
-	}
-	
-	#[inline(always)]
-	fn from_raw_value_pointer(raw_value_pointer: *mut Value) -> *mut Self
-	{
-		// Works because Value is the first field and we use #[repr(C)]
-		raw_value_pointer as *mut Self
-	}
-	
 	#[inline(always)]
 	pub(crate) fn strong_count(&self) -> usize
 	{
@@ -116,3 +101,47 @@ impl<Value: CtoSafe> CtoRcInner<Value>
 		self.strong_count() == 1 && self.weak_count() == 0
 	}
 }
+
+impl<Value: CtoSafe> CtoRcInner<Value>
+{
+	#[inline(always)]
+	fn allocated<InitializationError, Initializer: FnOnce(*mut Value, &CtoPoolArc) -> Result<(), InitializationError>>(&mut self, cto_pool_arc: &CtoPoolArc, initializer: Initializer) -> Result<(), InitializationError>
+	{
+		self.header_initialization(cto_pool_arc);
+		
+		initializer(&mut self.value, cto_pool_arc)
+	}
+	
+	#[inline(always)]
+	fn into_raw_value_pointer(&mut self) -> *mut Value
+	{
+		self.deref_mut()
+	}
+	
+	#[inline(always)]
+	fn from_raw_value_pointer(raw_value_pointer: *mut Value) -> *mut Self
+	{
+		// Works because Value is the last field and we use #[repr(C)]
+		let (_layout, value_offset) = Layout::new::<CtoRcInner<()>>().extend(Layout::new::<Value>()).unwrap();
+		
+		unsafe { (raw_value_pointer as *mut u8).offset(-(value_offset as isize)) as *mut Self }
+	}
+}
+
+impl<Element: CtoSafe> CtoRcInner<[Element]>
+{
+	#[inline(always)]
+	fn allocate_slice<Elements: ExactSizeIterator<Item=Element>>(elements: Elements, cto_pool_arc: &CtoPoolArc) -> Result<*mut Self, PmdkError>
+	{
+		let length = elements.len();
+		
+		let allocation_pointer = cto_pool_arc.pool_pointer().aligned_allocate_with_trailing_slice::<CtoRcInner<()>, Element, Elements>(elements)?;
+		
+		// Works because `[Element]` is the last field and we use #[repr(C)]; the cast keeps the slice's length as the pointer's metadata.
+		let persistent_memory_pointer = from_raw_parts_mut(allocation_pointer, length) as *mut [Element] as *mut Self;
+		
+		unsafe { (*persistent_memory_pointer).header_initialization(cto_pool_arc) };
+		
+		Ok(persistent_memory_pointer)
+	}
+}
@@ -3,12 +3,12 @@
 
 
 /// Very similar to Rust's Rc Weak.
-pub struct WeakCtoRc<Value: CtoSafe>
+pub struct WeakCtoRc<Value: ?Sized + CtoSafe>
 {
 	persistent_memory_pointer: Option<NonNull<CtoRcInner<Value>>>,
 }
 
-impl<Value: CtoSafe> Drop for WeakCtoRc<Value>
+impl<Value: ?Sized + CtoSafe> Drop for WeakCtoRc<Value>
 {
 	#[inline(always)]
 	fn drop(&mut self)
@@ -29,7 +29,7 @@ impl<Value: CtoSafe> Drop for WeakCtoRc<Value>
 	}
 }
 
-impl<Value: CtoSafe> Clone for WeakCtoRc<Value>
+impl<Value: ?Sized + CtoSafe> Clone for WeakCtoRc<Value>
 {
 	#[inline(always)]
 	fn clone(&self) -> Self
@@ -46,7 +46,7 @@ impl<Value: CtoSafe> Clone for WeakCtoRc<Value>
 	}
 }
 
-impl<Value: CtoSafe + Debug> Debug for WeakCtoRc<Value>
+impl<Value: ?Sized + CtoSafe + Debug> Debug for WeakCtoRc<Value>
 {
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result
 	{
@@ -54,7 +54,7 @@ impl<Value: CtoSafe + Debug> Debug for WeakCtoRc<Value>
 	}
 }
 
-impl<Value: CtoSafe> Default for WeakCtoRc<Value>
+impl<Value: ?Sized + CtoSafe> Default for WeakCtoRc<Value>
 {
 	/// Constructs a new `WeakCtoRc<Value>`, allocating memory for `Value` without initializing it. Calling `upgrade()` on the return value always gives `None`.
 	#[inline(always)]
@@ -64,7 +64,7 @@ impl<Value: CtoSafe> Default for WeakCtoRc<Value>
 	}
 }
 
-impl<Value: CtoSafe> WeakCtoRc<Value>
+impl<Value: ?Sized + CtoSafe> WeakCtoRc<Value>
 {
 	/// Constructs a new `WeakCtoRc<Value>`, allocating memory for `Value` without initializing it. Calling `upgrade()` on the return value always gives `None`.
 	#[inline(always)]
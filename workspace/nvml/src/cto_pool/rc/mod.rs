@@ -4,6 +4,7 @@
 
 use super::*;
 use ::std::cell::Cell;
+use ::std::mem::forget;
 use ::std::process::abort;
 
 
@@ -3,38 +3,39 @@
 
 
 /// A UTF-8 encoded, growable string. See Rust stdlib String for more details.
-#[derive(PartialOrd, Eq, Ord)]
-pub struct CtoString
+///
+/// `P` controls how mutations are flushed; see `CtoVec`.
+pub struct CtoString<P: Persistence = VolatileMemoryPersistence>
 {
-	vec: CtoVec<u8>,
+	vec: CtoVec<u8, P>,
 }
 
-impl CtoString
+impl<P: Persistence> CtoString<P>
 {
 	/// Creates a new empty `CtoString`.
 	#[inline(always)]
-	pub fn new(cto_pool_alloc: CtoPoolAlloc) -> CtoString
+	pub fn new(cto_pool_alloc: CtoPoolAlloc) -> CtoString<P>
 	{
 		CtoString { vec: CtoVec::new(cto_pool_alloc) }
 	}
 	
 	/// Creates a new empty `CtoString` with a particular capacity.
 	#[inline(always)]
-	pub fn with_capacity(capacity: usize, cto_pool_alloc: CtoPoolAlloc) -> CtoString
+	pub fn with_capacity(capacity: usize, cto_pool_alloc: CtoPoolAlloc) -> CtoString<P>
 	{
 		CtoString { vec: CtoVec::with_capacity(capacity, cto_pool_alloc) }
 	}
 	
 	/// Creates a new `String` from a length, capacity, and pointer.
 	#[inline(always)]
-	pub unsafe fn from_raw_parts(buf: *mut u8, length: usize, capacity: usize, cto_pool_alloc: CtoPoolAlloc) -> CtoString
+	pub unsafe fn from_raw_parts(buf: *mut u8, length: usize, capacity: usize, cto_pool_alloc: CtoPoolAlloc) -> CtoString<P>
 	{
 		CtoString { vec: CtoVec::from_raw_parts(buf, length, capacity, cto_pool_alloc) }
 	}
 	
 	/// Converts a vector of bytes to a `String` without checking that the string contains valid UTF-8.
 	#[inline(always)]
-	pub unsafe fn from_utf8_unchecked(bytes: CtoVec<u8>) -> CtoString
+	pub unsafe fn from_utf8_unchecked(bytes: CtoVec<u8, P>) -> CtoString<P>
 	{
 		CtoString { vec: bytes }
 	}
@@ -54,7 +55,7 @@ impl CtoString
 	/// assert_eq!(&[104, 101, 108, 108, 111][..], &bytes[..]);
 	/// ```
 	#[inline(always)]
-	pub fn into_bytes(self) -> CtoVec<u8>
+	pub fn into_bytes(self) -> CtoVec<u8, P>
 	{
 		self.vec
 	}
@@ -267,6 +268,7 @@ impl CtoString
 		unsafe
 		{
 			copy(self.vec.as_ptr().offset(next as isize), self.vec.as_mut_ptr().offset(idx as isize), len - next);
+			self.vec.flush_range(idx, len - (next - idx));
 			self.vec.set_len(len - (next - idx));
 		}
 		ch
@@ -310,6 +312,7 @@ impl CtoString
 		
 		if del_bytes > 0
 		{
+			self.vec.flush_range(0, len - del_bytes);
 			unsafe { self.vec.set_len(len - del_bytes); }
 		}
 	}
@@ -340,6 +343,7 @@ impl CtoString
 		
 		copy(self.vec.as_ptr().offset(idx as isize), self.vec.as_mut_ptr().offset((idx + amt) as isize), len - idx);
 		copy(bytes.as_ptr(), self.vec.as_mut_ptr().offset(idx as isize), amt);
+		self.vec.flush_range(idx, len);
 		self.vec.set_len(len + amt);
 	}
 	
@@ -366,7 +370,7 @@ impl CtoString
 	/// memory unsafety issues with future users of the `String`, as the rest of
 	/// the standard library assumes that `String`s are valid UTF-8.
 	#[inline(always)]
-	pub unsafe fn as_mut_vec(&mut self) -> &mut CtoVec<u8>
+	pub unsafe fn as_mut_vec(&mut self) -> &mut CtoVec<u8, P>
 	{
 		&mut self.vec
 	}
@@ -411,7 +415,7 @@ impl CtoString
 	/// # }
 	/// ```
 	#[inline(always)]
-	pub fn split_off(&mut self, at: usize) -> CtoString
+	pub fn split_off(&mut self, at: usize) -> CtoString<P>
 	{
 		assert!(self.is_char_boundary(at));
 		let other = self.vec.split_off(at);
@@ -447,7 +451,7 @@ impl CtoString
 	///
 	/// Note: The element range is removed even if the iterator is not
 	/// consumed until the end.
-	pub fn drain<R>(&mut self, range: R) -> CtoStringDrain
+	pub fn drain<R>(&mut self, range: R) -> CtoStringDrain<P>
 		where R: RangeArgument<usize>
 	{
 		// Memory safety
@@ -516,7 +520,7 @@ impl CtoString
 	}
 }
 
-impl Extend<char> for CtoString
+impl<P: Persistence> Extend<char> for CtoString<P>
 {
 	#[inline(always)]
 	fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I)
@@ -531,7 +535,7 @@ impl Extend<char> for CtoString
 	}
 }
 
-impl<'a> Extend<&'a char> for CtoString
+impl<'a, P: Persistence> Extend<&'a char> for CtoString<P>
 {
 	#[inline(always)]
 	fn extend<I: IntoIterator<Item = &'a char>>(&mut self, iter: I)
@@ -540,7 +544,7 @@ impl<'a> Extend<&'a char> for CtoString
 	}
 }
 
-impl<'a> Extend<&'a str> for CtoString
+impl<'a, P: Persistence> Extend<&'a str> for CtoString<P>
 {
 	#[inline(always)]
 	fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I)
@@ -552,10 +556,10 @@ impl<'a> Extend<&'a str> for CtoString
 	}
 }
 
-impl Extend<CtoString> for CtoString
+impl<P: Persistence> Extend<CtoString<P>> for CtoString<P>
 {
 	#[inline(always)]
-	fn extend<I: IntoIterator<Item =CtoString>>(&mut self, iter: I)
+	fn extend<I: IntoIterator<Item = CtoString<P>>>(&mut self, iter: I)
 	{
 		for s in iter
 		{
@@ -564,7 +568,7 @@ impl Extend<CtoString> for CtoString
 	}
 }
 
-impl<'a> Extend<Cow<'a, str>> for CtoString
+impl<'a, P: Persistence> Extend<Cow<'a, str>> for CtoString<P>
 {
 	#[inline(always)]
 	fn extend<I: IntoIterator<Item = Cow<'a, str>>>(&mut self, iter: I)
@@ -577,7 +581,7 @@ impl<'a> Extend<Cow<'a, str>> for CtoString
 }
 
 /// A convenience impl that delegates to the impl for `&str`
-impl<'a, 'b> Pattern<'a> for &'b CtoString
+impl<'a, 'b, P: Persistence> Pattern<'a> for &'b CtoString<P>
 {
 	type Searcher = <&'b str as Pattern<'a>>::Searcher;
 	
@@ -600,26 +604,48 @@ impl<'a, 'b> Pattern<'a> for &'b CtoString
 	}
 }
 
-impl PartialEq for CtoString
+impl<P: Persistence> PartialEq for CtoString<P>
 {
 	#[inline(always)]
-	fn eq(&self, other: &CtoString) -> bool
+	fn eq(&self, other: &Self) -> bool
 	{
 		PartialEq::eq(&self[..], &other[..])
 	}
 	
 	#[inline(always)]
-	fn ne(&self, other: &CtoString) -> bool
+	fn ne(&self, other: &Self) -> bool
 	{
 		PartialEq::ne(&self[..], &other[..])
 	}
 }
 
+impl<P: Persistence> Eq for CtoString<P>
+{
+}
+
+impl<P: Persistence> PartialOrd for CtoString<P>
+{
+	#[inline(always)]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		PartialOrd::partial_cmp(&self.vec, &other.vec)
+	}
+}
+
+impl<P: Persistence> Ord for CtoString<P>
+{
+	#[inline(always)]
+	fn cmp(&self, other: &Self) -> Ordering
+	{
+		Ord::cmp(&self.vec, &other.vec)
+	}
+}
+
 macro_rules! impl_eq
 {
     ($lhs:ty, $rhs: ty) =>
     {
-        impl<'a, 'b> PartialEq<$rhs> for $lhs
+        impl<'a, 'b, P: Persistence> PartialEq<$rhs> for $lhs
         {
 			#[inline(always)]
             fn eq(&self, other: &$rhs) -> bool { PartialEq::eq(&self[..], &other[..]) }
@@ -630,11 +656,11 @@ macro_rules! impl_eq
     }
 }
 
-impl_eq! { CtoString, str }
-impl_eq! { CtoString, &'a str }
-impl_eq! { CtoString, Cow<'a, str> }
+impl_eq! { CtoString<P>, str }
+impl_eq! { CtoString<P>, &'a str }
+impl_eq! { CtoString<P>, Cow<'a, str> }
 
-impl Display for CtoString
+impl<P: Persistence> Display for CtoString<P>
 {
 	#[inline(always)]
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result
@@ -643,7 +669,7 @@ impl Display for CtoString
 	}
 }
 
-impl Debug for CtoString
+impl<P: Persistence> Debug for CtoString<P>
 {
 	#[inline(always)]
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
@@ -652,7 +678,7 @@ impl Debug for CtoString
 	}
 }
 
-impl Hash for CtoString
+impl<P: Persistence> Hash for CtoString<P>
 {
 	#[inline(always)]
 	fn hash<H: Hasher>(&self, hasher: &mut H)
@@ -661,19 +687,19 @@ impl Hash for CtoString
 	}
 }
 
-impl<'a> Add<&'a str> for CtoString
+impl<'a, P: Persistence> Add<&'a str> for CtoString<P>
 {
-	type Output = CtoString;
+	type Output = Self;
 	
 	#[inline(always)]
-	fn add(mut self, other: &str) -> CtoString
+	fn add(mut self, other: &str) -> CtoString<P>
 	{
 		self.push_str(other);
 		self
 	}
 }
 
-impl<'a> AddAssign<&'a str> for CtoString
+impl<'a, P: Persistence> AddAssign<&'a str> for CtoString<P>
 {
 	#[inline(always)]
 	fn add_assign(&mut self, other: &str)
@@ -682,7 +708,7 @@ impl<'a> AddAssign<&'a str> for CtoString
 	}
 }
 
-impl Index<Range<usize>> for CtoString
+impl<P: Persistence> Index<Range<usize>> for CtoString<P>
 {
 	type Output = str;
 	
@@ -693,7 +719,7 @@ impl Index<Range<usize>> for CtoString
 	}
 }
 
-impl Index<RangeTo<usize>> for CtoString
+impl<P: Persistence> Index<RangeTo<usize>> for CtoString<P>
 {
 	type Output = str;
 	
@@ -704,7 +730,7 @@ impl Index<RangeTo<usize>> for CtoString
 	}
 }
 
-impl Index<RangeFrom<usize>> for CtoString
+impl<P: Persistence> Index<RangeFrom<usize>> for CtoString<P>
 {
 	type Output = str;
 	
@@ -715,7 +741,7 @@ impl Index<RangeFrom<usize>> for CtoString
 	}
 }
 
-impl Index<RangeFull> for CtoString
+impl<P: Persistence> Index<RangeFull> for CtoString<P>
 {
 	type Output = str;
 	
@@ -726,7 +752,7 @@ impl Index<RangeFull> for CtoString
 	}
 }
 
-impl Index<RangeInclusive<usize>> for CtoString
+impl<P: Persistence> Index<RangeInclusive<usize>> for CtoString<P>
 {
 	type Output = str;
 	
@@ -737,7 +763,7 @@ impl Index<RangeInclusive<usize>> for CtoString
 	}
 }
 
-impl Index<RangeToInclusive<usize>> for CtoString
+impl<P: Persistence> Index<RangeToInclusive<usize>> for CtoString<P>
 {
 	type Output = str;
 	
@@ -748,7 +774,7 @@ impl Index<RangeToInclusive<usize>> for CtoString
 	}
 }
 
-impl IndexMut<Range<usize>> for CtoString
+impl<P: Persistence> IndexMut<Range<usize>> for CtoString<P>
 {
 	#[inline(always)]
 	fn index_mut(&mut self, index: Range<usize>) -> &mut str
@@ -757,7 +783,7 @@ impl IndexMut<Range<usize>> for CtoString
 	}
 }
 
-impl IndexMut<RangeTo<usize>> for CtoString
+impl<P: Persistence> IndexMut<RangeTo<usize>> for CtoString<P>
 {
 	#[inline(always)]
 	fn index_mut(&mut self, index: RangeTo<usize>) -> &mut str
@@ -766,7 +792,7 @@ impl IndexMut<RangeTo<usize>> for CtoString
 	}
 }
 
-impl IndexMut<RangeFrom<usize>> for CtoString
+impl<P: Persistence> IndexMut<RangeFrom<usize>> for CtoString<P>
 {
 	#[inline(always)]
 	fn index_mut(&mut self, index: RangeFrom<usize>) -> &mut str
@@ -775,7 +801,7 @@ impl IndexMut<RangeFrom<usize>> for CtoString
 	}
 }
 
-impl IndexMut<RangeFull> for CtoString
+impl<P: Persistence> IndexMut<RangeFull> for CtoString<P>
 {
 	#[inline(always)]
 	fn index_mut(&mut self, _index: RangeFull) -> &mut str
@@ -784,7 +810,7 @@ impl IndexMut<RangeFull> for CtoString
 	}
 }
 
-impl IndexMut<RangeInclusive<usize>> for CtoString
+impl<P: Persistence> IndexMut<RangeInclusive<usize>> for CtoString<P>
 {
 	#[inline(always)]
 	fn index_mut(&mut self, index: RangeInclusive<usize>) -> &mut str
@@ -793,7 +819,7 @@ impl IndexMut<RangeInclusive<usize>> for CtoString
 	}
 }
 
-impl IndexMut<RangeToInclusive<usize>> for CtoString
+impl<P: Persistence> IndexMut<RangeToInclusive<usize>> for CtoString<P>
 {
 	#[inline(always)]
 	fn index_mut(&mut self, index: RangeToInclusive<usize>) -> &mut str
@@ -802,7 +828,7 @@ impl IndexMut<RangeToInclusive<usize>> for CtoString
 	}
 }
 
-impl Deref for CtoString
+impl<P: Persistence> Deref for CtoString<P>
 {
 	type Target = str;
 	
@@ -813,7 +839,7 @@ impl Deref for CtoString
 	}
 }
 
-impl DerefMut for CtoString
+impl<P: Persistence> DerefMut for CtoString<P>
 {
 	#[inline(always)]
 	fn deref_mut(&mut self) -> &mut str
@@ -822,7 +848,7 @@ impl DerefMut for CtoString
 	}
 }
 
-impl AsRef<str> for CtoString
+impl<P: Persistence> AsRef<str> for CtoString<P>
 {
 	#[inline(always)]
 	fn as_ref(&self) -> &str
@@ -831,7 +857,7 @@ impl AsRef<str> for CtoString
 	}
 }
 
-impl AsRef<[u8]> for CtoString
+impl<P: Persistence> AsRef<[u8]> for CtoString<P>
 {
 	#[inline(always)]
 	fn as_ref(&self) -> &[u8]
@@ -840,16 +866,16 @@ impl AsRef<[u8]> for CtoString
 	}
 }
 
-impl From<CtoString> for CtoVec<u8>
+impl<P: Persistence> From<CtoString<P>> for CtoVec<u8, P>
 {
 	#[inline(always)]
-	fn from(string: CtoString) -> CtoVec<u8>
+	fn from(string: CtoString<P>) -> CtoVec<u8, P>
 	{
 		string.into_bytes()
 	}
 }
 
-impl fmt::Write for CtoString
+impl<P: Persistence> fmt::Write for CtoString<P>
 {
 	#[inline(always)]
 	fn write_str(&mut self, s: &str) -> fmt::Result
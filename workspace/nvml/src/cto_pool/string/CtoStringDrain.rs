@@ -3,10 +3,10 @@
 
 
 /// A draining iterator for `String`.
-pub struct CtoStringDrain<'a>
+pub struct CtoStringDrain<'a, P: 'a + Persistence = VolatileMemoryPersistence>
 {
 	/// Will be used as &'a mut String in the destructor
-	string: *mut CtoString,
+	string: *mut CtoString<P>,
 	
 	/// Start of part to remove
 	start: usize,
@@ -18,7 +18,7 @@ pub struct CtoStringDrain<'a>
 	iter: Chars<'a>,
 }
 
-impl<'a> Debug for CtoStringDrain<'a>
+impl<'a, P: Persistence> Debug for CtoStringDrain<'a, P>
 {
 	#[inline(always)]
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result
@@ -27,15 +27,15 @@ impl<'a> Debug for CtoStringDrain<'a>
 	}
 }
 
-unsafe impl<'a> Sync for CtoStringDrain<'a>
+unsafe impl<'a, P: Persistence> Sync for CtoStringDrain<'a, P>
 {
 }
 
-unsafe impl<'a> Send for CtoStringDrain<'a>
+unsafe impl<'a, P: Persistence> Send for CtoStringDrain<'a, P>
 {
 }
 
-impl<'a> Drop for CtoStringDrain<'a>
+impl<'a, P: Persistence> Drop for CtoStringDrain<'a, P>
 {
 	#[inline(always)]
 	fn drop(&mut self)
@@ -52,7 +52,7 @@ impl<'a> Drop for CtoStringDrain<'a>
 	}
 }
 
-impl<'a> Iterator for CtoStringDrain<'a>
+impl<'a, P: Persistence> Iterator for CtoStringDrain<'a, P>
 {
 	type Item = char;
 	
@@ -69,7 +69,7 @@ impl<'a> Iterator for CtoStringDrain<'a>
 	}
 }
 
-impl<'a> DoubleEndedIterator for CtoStringDrain<'a>
+impl<'a, P: Persistence> DoubleEndedIterator for CtoStringDrain<'a, P>
 {
 	#[inline(always)]
 	fn next_back(&mut self) -> Option<char>
@@ -78,6 +78,6 @@ impl<'a> DoubleEndedIterator for CtoStringDrain<'a>
 	}
 }
 
-impl<'a> FusedIterator for CtoStringDrain<'a>
+impl<'a, P: Persistence> FusedIterator for CtoStringDrain<'a, P>
 {
 }
@@ -50,6 +50,8 @@
 
 
 use super::*;
+use ::persistent_memory::persistence::Persistence;
+use ::persistent_memory::persistence::VolatileMemoryPersistence;
 use ::std::borrow::Cow;
 use ::std::collections::Bound::Excluded;
 use ::std::collections::Bound::Included;
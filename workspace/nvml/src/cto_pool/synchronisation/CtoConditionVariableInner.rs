@@ -130,7 +130,7 @@ impl CtoConditionVariableInner
 		
 		let timeout = seconds.map(|s| timespec { tv_sec: s, tv_nsec: (nanoseconds % 1_000_000_000) as _}).unwrap_or(Self::MaximumTimeSpec);
 		
-		match unsafe { pthread_cond_timedwait(self.inner.get(), mutex, &timeout) }
+		match unsafe { pthread_cond_timedwait(self.cond.get(), mutex, &timeout) }
 		{
 			ResultIsOk => TimedOut::Succeeded,
 			
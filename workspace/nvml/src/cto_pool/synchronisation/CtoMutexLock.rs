@@ -116,6 +116,14 @@ impl<Value: CtoSafe> CtoMutexLock<Value>
 		self.persistent_memory().try_lock()
 	}
 	
+	/// Returns Some(lock_guard) if could be locked before `duration` elapsed.
+	/// Returns None if the lock was still held by another when the time out expired.
+	#[inline(always)]
+	pub fn lock_timeout<'mutex>(&'mutex self, duration: Duration) -> Option<CtoMutexLockGuard<'mutex, Value>>
+	{
+		self.persistent_memory().lock_timeout(duration)
+	}
+	
 	#[inline(always)]
 	fn persistent_memory(&self) -> &CtoMutexLockInner<Value>
 	{
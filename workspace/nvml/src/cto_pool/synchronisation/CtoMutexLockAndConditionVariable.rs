@@ -73,4 +73,17 @@ impl<Value: CtoSafe> CtoMutexLockAndConditionVariable<Value>
 			cto_condition_variable: &self.cto_condition_variable,
 		})
 	}
+	
+	/// Returns Some(lock_guard) if could be locked before `duration` elapsed.
+	/// Returns None if the lock was still held by another when the time out expired.
+	/// Use the resultant object to access wait / notify behaviour of the condition variable.
+	#[inline(always)]
+	pub fn lock_timeout<'mutex>(&'mutex self, duration: Duration) -> Option<CtoMutexLockGuardWithConditionVariable<'mutex, Value>>
+	{
+		self.cto_mutex_lock.lock_timeout(duration).map(|cto_mutex_lock_guard| CtoMutexLockGuardWithConditionVariable
+		{
+			cto_mutex_lock_guard,
+			cto_condition_variable: &self.cto_condition_variable,
+		})
+	}
 }
@@ -161,6 +161,30 @@ impl<Value: CtoSafe> CtoMutexLockInner<Value>
 		}
 	}
 	
+	#[cfg(unix)]
+	#[inline(always)]
+	pub(crate) fn lock_timeout<'mutex>(&'mutex self, duration: Duration) -> Option<CtoMutexLockGuard<'mutex, Value>>
+	{
+		#[cfg(not(any(target_os = "macos", target_os = "ios")))]
+		{
+			let deadline = realtime_deadline(duration);
+			
+			match unsafe { pthread_mutex_timedlock(self.mutex.get(), &deadline) }
+			{
+				ResultIsOk => Some(CtoMutexLockGuard(self)),
+				
+				ETIMEDOUT => None,
+				
+				illegal @ _ => panic!("Was not 0 or ETIMEDOUT but was '{}'", illegal),
+			}
+		}
+		
+		#[cfg(any(target_os = "macos", target_os = "ios"))]
+		{
+			poll_until_timeout(duration, || self.try_lock())
+		}
+	}
+	
 	// Behavior is undefined if the current thread does not actually hold the mutex.
 	#[cfg(unix)]
 	#[inline(always)]
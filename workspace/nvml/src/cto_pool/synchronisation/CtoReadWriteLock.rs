@@ -117,6 +117,15 @@ impl<Value: CtoSafe> CtoReadWriteLock<Value>
 		self.persistent_memory().try_read()
 	}
 	
+	/// Try to obtain a read lock, waiting no longer than `duration`.
+	/// Returns None if still write-locked by another when the time out expired.
+	/// Panics if write-locked by this thread.
+	#[inline(always)]
+	pub fn read_timeout<'read_write_lock>(&'read_write_lock self, duration: Duration) -> Option<CtoReadWriteLockReadGuard<'read_write_lock, Value>>
+	{
+		self.persistent_memory().read_timeout(duration)
+	}
+	
 	/// Obtains a write lock.
 	/// Panics if already write-locked or there are extant read-locks.
 	#[inline(always)]
@@ -133,6 +142,15 @@ impl<Value: CtoSafe> CtoReadWriteLock<Value>
 		self.persistent_memory().try_write()
 	}
 	
+	/// Tries to obtain a write lock, waiting no longer than `duration`.
+	/// Returns None if still locked by others when the time out expired.
+	/// Panics if already locked by this thread.
+	#[inline(always)]
+	pub fn write_timeout<'read_write_lock>(&'read_write_lock self, duration: Duration) -> Option<CtoReadWriteLockWriteGuard<'read_write_lock, Value>>
+	{
+		self.persistent_memory().write_timeout(duration)
+	}
+	
 	#[inline(always)]
 	fn persistent_memory(&self) -> &CtoReadWriteLockInner<Value>
 	{
@@ -175,6 +175,47 @@ impl<Value: CtoSafe> CtoReadWriteLockInner<Value>
 		}
 	}
 	
+	#[cfg(unix)]
+	#[inline(always)]
+	pub(crate) fn read_timeout<'read_write_lock>(&'read_write_lock self, duration: Duration) -> Option<CtoReadWriteLockReadGuard<'read_write_lock, Value>>
+	{
+		#[cfg(not(any(target_os = "macos", target_os = "ios")))]
+		{
+			let deadline = realtime_deadline(duration);
+			
+			let result = unsafe { pthread_rwlock_timedrdlock(self.rwlock(), &deadline) };
+			
+			// See comments for `read()` regarding deadlock.
+			if result == ETIMEDOUT
+			{
+				None
+			}
+			else if result == EAGAIN
+			{
+				panic!("rwlock maximum reader count exceeded");
+			}
+			else if result == EDEADLK || self.is_write_locked()
+			{
+				if result == ResultIsOk
+				{
+					self.unlock_pthread_read_or_write_lock();
+				}
+				panic!("rwlock read lock would result in deadlock");
+			}
+			else
+			{
+				debug_assert_pthread_result_ok!(result);
+				self.increment_number_of_read_locks();
+				Some(CtoReadWriteLockReadGuard(self))
+			}
+		}
+		
+		#[cfg(any(target_os = "macos", target_os = "ios"))]
+		{
+			poll_until_timeout(duration, || self.try_read())
+		}
+	}
+	
 	#[cfg(unix)]
 	#[inline(always)]
 	pub(crate) fn write<'read_write_lock>(&'read_write_lock self) -> CtoReadWriteLockWriteGuard<'read_write_lock, Value>
@@ -224,6 +265,43 @@ impl<Value: CtoSafe> CtoReadWriteLockInner<Value>
 		}
 	}
 	
+	#[cfg(unix)]
+	#[inline(always)]
+	pub(crate) fn write_timeout<'read_write_lock>(&'read_write_lock self, duration: Duration) -> Option<CtoReadWriteLockWriteGuard<'read_write_lock, Value>>
+	{
+		#[cfg(not(any(target_os = "macos", target_os = "ios")))]
+		{
+			let deadline = realtime_deadline(duration);
+			
+			let result = unsafe { pthread_rwlock_timedwrlock(self.rwlock(), &deadline) };
+			
+			// See comments for `read()` regarding deadlock.
+			if result == ETIMEDOUT
+			{
+				None
+			}
+			else if result == EDEADLK || self.is_write_locked() || self.there_are_read_locks()
+			{
+				if result == ResultIsOk
+				{
+					self.unlock_pthread_read_or_write_lock();
+				}
+				panic!("rwlock write lock would result in deadlock");
+			}
+			else
+			{
+				debug_assert_pthread_result_ok!(result);
+				self.set_is_write_locked();
+				Some(CtoReadWriteLockWriteGuard(self))
+			}
+		}
+		
+		#[cfg(any(target_os = "macos", target_os = "ios"))]
+		{
+			poll_until_timeout(duration, || self.try_write())
+		}
+	}
+	
 	#[inline(always)]
 	unsafe fn read_unlock(&self)
 	{
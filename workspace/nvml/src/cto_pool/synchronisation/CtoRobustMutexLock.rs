@@ -0,0 +1,131 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// A robust Mutex, similar to `CtoMutexLock` but aware of the death of the thread or process holding it.
+/// If the holder dies, the next `lock()` returns `CtoRobustMutexLockError::OwnerDied`, and the new owner can check and repair the protected value before calling `OwnerDied::mark_consistent()`.
+/// If the new owner unlocks without doing so, every subsequent `lock()` returns `CtoRobustMutexLockError::NotRecoverable`.
+/// Unlike `CtoMutexLock`, the mutex is not re-initialized when the CTO pool is re-opened, so that the death of an owner in a previous process is still reported.
+pub struct CtoRobustMutexLock<Value: CtoSafe>
+{
+	persistent_memory_pointer: NonNull<CtoRobustMutexLockInner<Value>>,
+}
+
+impl<Value: CtoSafe> CtoSafe for CtoRobustMutexLock<Value>
+{
+	#[inline(always)]
+	fn cto_pool_opened(&mut self, cto_pool_arc: &CtoPoolArc)
+	{
+		self.persistent_memory_mut().cto_pool_opened(cto_pool_arc)
+	}
+}
+
+impl<Value: CtoSafe> Drop for CtoRobustMutexLock<Value>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		let pool_pointer = self.persistent_memory().cto_pool_arc.pool_pointer();
+		
+		let persistent_memory_pointer = self.persistent_memory_pointer.as_ptr();
+		
+		unsafe { drop_in_place(persistent_memory_pointer) }
+		
+		pool_pointer.free(persistent_memory_pointer);
+	}
+}
+
+unsafe impl<Value: CtoSafe> Send for CtoRobustMutexLock<Value>
+{
+}
+
+unsafe impl<Value: CtoSafe> Sync for CtoRobustMutexLock<Value>
+{
+}
+
+impl<Value: CtoSafe> UnwindSafe for CtoRobustMutexLock<Value>
+{
+}
+
+impl<Value: CtoSafe> RefUnwindSafe for CtoRobustMutexLock<Value>
+{
+}
+
+impl<Value: CtoSafe> Debug for CtoRobustMutexLock<Value>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.pad("CtoRobustMutexLock { .. }")
+	}
+}
+
+impl<Value: CtoSafe> CtoRobustMutexLock<Value>
+{
+	#[inline(always)]
+	pub(crate) fn allocate<InitializationError, Initializer: FnOnce(*mut Value, &CtoPoolArc) -> Result<(), InitializationError>>(process_shared: bool, initializer: Initializer, cto_pool_arc: &CtoPoolArc) -> Result<Self, CtoPoolAllocationError<InitializationError>>
+	{
+		let pool_pointer = cto_pool_arc.pool_pointer();
+		
+		let persistent_memory_pointer = match pool_pointer.aligned_allocate::<CtoRobustMutexLockInner<Value>>()
+		{
+			Err(allocation_error) => return Err(CtoPoolAllocationError::Allocation(allocation_error)),
+			
+			Ok(persistent_memory_pointer) => persistent_memory_pointer,
+		};
+		
+		match unsafe { (*persistent_memory_pointer).allocated(process_shared, cto_pool_arc, initializer) }
+		{
+			Ok(()) => Ok
+			(
+				Self
+				{
+					persistent_memory_pointer: unsafe { NonNull::new_unchecked(persistent_memory_pointer) },
+				}
+			),
+			
+			Err(initialization_error) =>
+			{
+				pool_pointer.free(persistent_memory_pointer);
+				
+				Err(CtoPoolAllocationError::Initialization(initialization_error))
+			}
+		}
+	}
+	
+	/// Locks a mutex.
+	/// Returns `Err(CtoRobustMutexLockError::OwnerDied)` if the previous owner died whilst holding the lock; the lock is still acquired.
+	/// Returns `Err(CtoRobustMutexLockError::NotRecoverable)` if a previous owner died and the value was never marked consistent.
+	#[inline(always)]
+	pub fn lock<'mutex>(&'mutex self) -> Result<CtoRobustMutexLockGuard<'mutex, Value>, CtoRobustMutexLockError<CtoRobustMutexLockGuard<'mutex, Value>>>
+	{
+		self.persistent_memory().lock()
+	}
+	
+	/// Returns Some(result) if could be locked; see `lock()` for the meaning of result.
+	/// Returns None if the lock is held by another.
+	#[inline(always)]
+	pub fn try_lock<'mutex>(&'mutex self) -> Option<Result<CtoRobustMutexLockGuard<'mutex, Value>, CtoRobustMutexLockError<CtoRobustMutexLockGuard<'mutex, Value>>>>
+	{
+		self.persistent_memory().try_lock()
+	}
+	
+	/// Returns Some(result) if could be locked before `duration` elapsed; see `lock()` for the meaning of result.
+	/// Returns None if the lock was still held by another when the time out expired.
+	#[inline(always)]
+	pub fn lock_timeout<'mutex>(&'mutex self, duration: Duration) -> Option<Result<CtoRobustMutexLockGuard<'mutex, Value>, CtoRobustMutexLockError<CtoRobustMutexLockGuard<'mutex, Value>>>>
+	{
+		self.persistent_memory().lock_timeout(duration)
+	}
+	
+	#[inline(always)]
+	fn persistent_memory(&self) -> &CtoRobustMutexLockInner<Value>
+	{
+		unsafe { self.persistent_memory_pointer.as_ref() }
+	}
+	
+	#[inline(always)]
+	fn persistent_memory_mut(&mut self) -> &mut CtoRobustMutexLockInner<Value>
+	{
+		unsafe { self.persistent_memory_pointer.as_mut() }
+	}
+}
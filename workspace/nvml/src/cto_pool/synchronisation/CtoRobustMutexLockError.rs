@@ -0,0 +1,56 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// Why a `CtoRobustMutexLock` could not be cleanly locked.
+pub enum CtoRobustMutexLockError<Guard>
+{
+	/// The previous owner died whilst holding the lock.
+	/// The lock is now held; check and repair the protected value, then call `OwnerDied::mark_consistent()`.
+	OwnerDied(OwnerDied<Guard>),
+	
+	/// A previous owner died whilst holding the lock and the protected value was never marked consistent (`ENOTRECOVERABLE`).
+	/// The lock can never be acquired again.
+	NotRecoverable,
+}
+
+impl<Guard> Debug for CtoRobustMutexLockError<Guard>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		use self::CtoRobustMutexLockError::*;
+		
+		match *self
+		{
+			OwnerDied(ref owner_died) => f.debug_tuple("OwnerDied").field(owner_died).finish(),
+			
+			NotRecoverable => f.write_str("NotRecoverable"),
+		}
+	}
+}
+
+impl<Guard> Display for CtoRobustMutexLockError<Guard>
+{
+	#[inline(always)]
+	fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error>
+	{
+		use self::CtoRobustMutexLockError::*;
+		
+		match *self
+		{
+			OwnerDied(_) => write!(formatter, "The previous owner of the robust mutex died whilst holding it"),
+			
+			NotRecoverable => write!(formatter, "The robust mutex is not recoverable"),
+		}
+	}
+}
+
+impl<Guard> error::Error for CtoRobustMutexLockError<Guard>
+{
+	#[inline(always)]
+	fn description(&self) -> &str
+	{
+		"Cto Robust Mutex Lock Error"
+	}
+}
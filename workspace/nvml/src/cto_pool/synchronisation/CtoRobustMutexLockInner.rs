@@ -0,0 +1,178 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+pub(crate) struct CtoRobustMutexLockInner<Value: CtoSafe>
+{
+	mutex: UnsafeCell<pthread_mutex_t>,
+	process_shared: bool,
+	cto_pool_arc: CtoPoolArc,
+	value: UnsafeCell<Value>,
+}
+
+impl<Value: CtoSafe> Drop for CtoRobustMutexLockInner<Value>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		let result = unsafe { pthread_mutex_destroy(self.mutex.get()) };
+		debug_assert_pthread_result_ok!(result);
+	}
+}
+
+unsafe impl<Value: CtoSafe> Send for CtoRobustMutexLockInner<Value>
+{
+}
+
+unsafe impl<Value: CtoSafe> Sync for CtoRobustMutexLockInner<Value>
+{
+}
+
+impl<Value: CtoSafe> UnwindSafe for CtoRobustMutexLockInner<Value>
+{
+}
+
+impl<Value: CtoSafe> RefUnwindSafe for CtoRobustMutexLockInner<Value>
+{
+}
+
+impl<Value: CtoSafe> Deref for CtoRobustMutexLockInner<Value>
+{
+	type Target = Value;
+	
+	#[inline(always)]
+	fn deref(&self) -> &Self::Target
+	{
+		unsafe { &*self.value.get() }
+	}
+}
+
+impl<Value: CtoSafe> DerefMut for CtoRobustMutexLockInner<Value>
+{
+	#[inline(always)]
+	fn deref_mut(&mut self) -> &mut Self::Target
+	{
+		unsafe { &mut *self.value.get() }
+	}
+}
+
+impl<Value: CtoSafe> CtoRobustMutexLockInner<Value>
+{
+	#[inline(always)]
+	fn allocated<InitializationError, Initializer: FnOnce(*mut Value, &CtoPoolArc) -> Result<(), InitializationError>>(&mut self, process_shared: bool, cto_pool_arc: &CtoPoolArc, initializer: Initializer) -> Result<(), InitializationError>
+	{
+		unsafe { write(&mut self.process_shared, process_shared) };
+		
+		self.initialize_mutex();
+		
+		cto_pool_arc.write(&mut self.cto_pool_arc);
+		
+		initializer(self.value.get(), cto_pool_arc)
+	}
+	
+	// Unlike `CtoMutexLockInner`, the mutex is deliberately not re-initialized.
+	// If its owner died whilst holding it, the kernel will have marked it as such whilst walking the owner's robust futex list, and that is exactly what `lock()` must report.
+	#[inline(always)]
+	fn cto_pool_opened(&mut self, cto_pool_arc: &CtoPoolArc)
+	{
+		cto_pool_arc.write(&mut self.cto_pool_arc);
+		
+		self.deref_mut().cto_pool_opened(cto_pool_arc);
+	}
+	
+	// self.mutex must be at a stable memory address.
+	// See `CtoMutexLockInner.common_initialization()` for why PTHREAD_MUTEX_NORMAL is used.
+	#[inline(always)]
+	fn initialize_mutex(&mut self)
+	{
+		unsafe
+		{
+			write(&mut self.mutex, UnsafeCell::new(PTHREAD_MUTEX_INITIALIZER));
+			
+			let mut mutex_options: pthread_mutexattr_t = uninitialized();
+			
+			let result = pthread_mutexattr_init(&mut mutex_options);
+			debug_assert_pthread_result_ok!(result);
+			
+			let result = pthread_mutexattr_settype(&mut mutex_options, PTHREAD_MUTEX_NORMAL);
+			debug_assert_pthread_result_ok!(result);
+			
+			let result = pthread_mutexattr_setrobust(&mut mutex_options, PTHREAD_MUTEX_ROBUST);
+			debug_assert_pthread_result_ok!(result);
+			
+			if self.process_shared
+			{
+				let result = pthread_mutexattr_setpshared(&mut mutex_options, PTHREAD_PROCESS_SHARED);
+				debug_assert_pthread_result_ok!(result);
+			}
+			
+			let result = pthread_mutex_init(self.mutex.get(), &mutex_options);
+			debug_assert_pthread_result_ok!(result);
+			
+			let result = pthread_mutexattr_destroy(&mut mutex_options);
+			debug_assert_pthread_result_ok!(result);
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn lock<'mutex>(&'mutex self) -> Result<CtoRobustMutexLockGuard<'mutex, Value>, CtoRobustMutexLockError<CtoRobustMutexLockGuard<'mutex, Value>>>
+	{
+		let result = unsafe { pthread_mutex_lock(self.mutex.get()) };
+		self.lock_result(result)
+	}
+	
+	#[inline(always)]
+	pub(crate) fn try_lock<'mutex>(&'mutex self) -> Option<Result<CtoRobustMutexLockGuard<'mutex, Value>, CtoRobustMutexLockError<CtoRobustMutexLockGuard<'mutex, Value>>>>
+	{
+		match unsafe { pthread_mutex_trylock(self.mutex.get()) }
+		{
+			EBUSY => None,
+			
+			result @ _ => Some(self.lock_result(result)),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn lock_timeout<'mutex>(&'mutex self, duration: Duration) -> Option<Result<CtoRobustMutexLockGuard<'mutex, Value>, CtoRobustMutexLockError<CtoRobustMutexLockGuard<'mutex, Value>>>>
+	{
+		let deadline = realtime_deadline(duration);
+		
+		match unsafe { pthread_mutex_timedlock(self.mutex.get(), &deadline) }
+		{
+			ETIMEDOUT => None,
+			
+			result @ _ => Some(self.lock_result(result)),
+		}
+	}
+	
+	#[inline(always)]
+	fn lock_result<'mutex>(&'mutex self, result: i32) -> Result<CtoRobustMutexLockGuard<'mutex, Value>, CtoRobustMutexLockError<CtoRobustMutexLockGuard<'mutex, Value>>>
+	{
+		match result
+		{
+			ResultIsOk => Ok(CtoRobustMutexLockGuard(self)),
+			
+			EOWNERDEAD => Err(CtoRobustMutexLockError::OwnerDied(OwnerDied(CtoRobustMutexLockGuard(self)))),
+			
+			ENOTRECOVERABLE => Err(CtoRobustMutexLockError::NotRecoverable),
+			
+			illegal @ _ => panic!("Was not 0, EOWNERDEAD or ENOTRECOVERABLE but was '{}'", illegal),
+		}
+	}
+	
+	// Behavior is undefined if the current thread does not actually hold the mutex.
+	#[inline(always)]
+	unsafe fn mark_consistent(&self)
+	{
+		let result = pthread_mutex_consistent(self.mutex.get());
+		debug_assert_pthread_result_ok!(result);
+	}
+	
+	// Behavior is undefined if the current thread does not actually hold the mutex.
+	#[inline(always)]
+	unsafe fn unlock_mutex(&self)
+	{
+		let result = pthread_mutex_unlock(self.mutex.get());
+		debug_assert_pthread_result_ok!(result);
+	}
+}
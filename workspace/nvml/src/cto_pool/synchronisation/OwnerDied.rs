@@ -0,0 +1,61 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// The lock was acquired, but its previous owner died whilst holding it, so the protected value may be inconsistent.
+/// Dereferences to the lock guard so that the value can be checked and repaired.
+pub struct OwnerDied<Guard>(Guard);
+
+impl<Guard> Debug for OwnerDied<Guard>
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		f.pad("OwnerDied { .. }")
+	}
+}
+
+impl<Guard> Deref for OwnerDied<Guard>
+{
+	type Target = Guard;
+	
+	#[inline(always)]
+	fn deref(&self) -> &Self::Target
+	{
+		&self.0
+	}
+}
+
+impl<Guard> DerefMut for OwnerDied<Guard>
+{
+	#[inline(always)]
+	fn deref_mut(&mut self) -> &mut Self::Target
+	{
+		&mut self.0
+	}
+}
+
+impl<Guard> OwnerDied<Guard>
+{
+	/// Gives up on repairing the protected value.
+	/// Once the returned guard is dropped, the lock becomes permanently unusable, and all future attempts to lock it return `CtoRobustMutexLockError::NotRecoverable`.
+	#[inline(always)]
+	pub fn into_inconsistent_guard(self) -> Guard
+	{
+		self.0
+	}
+}
+
+impl<'mutex_lock, Value: CtoSafe> OwnerDied<CtoRobustMutexLockGuard<'mutex_lock, Value>>
+{
+	/// Marks the protected value as consistent again, after it has been checked and repaired, and returns the lock guard.
+	/// The lock then behaves normally once unlocked.
+	#[inline(always)]
+	pub fn mark_consistent(self) -> CtoRobustMutexLockGuard<'mutex_lock, Value>
+	{
+		let guard = self.0;
+		
+		unsafe { (guard.0).mark_consistent() };
+		
+		guard
+	}
+}
@@ -38,6 +38,23 @@ use super::*;
 	timespec,
 	time_t,
 };
+#[cfg(all(unix, not(any(target_os = "macos", target_os = "ios"))))] use ::libc::
+{
+	pthread_mutex_timedlock,
+	pthread_rwlock_timedrdlock,
+	pthread_rwlock_timedwrlock,
+};
+#[cfg(target_os = "linux")] use ::libc::
+{
+	EBUSY,
+	ENOTRECOVERABLE,
+	EOWNERDEAD,
+	pthread_mutex_consistent,
+	pthread_mutexattr_setpshared,
+	pthread_mutexattr_setrobust,
+	PTHREAD_MUTEX_ROBUST,
+	PTHREAD_PROCESS_SHARED,
+};
 #[cfg(target_os = "dragonfly")] use ::libc::EINVAL;
 use ::std::cell::UnsafeCell;
 use ::std::cmp::min;
@@ -52,6 +69,8 @@ use ::std::time::Duration;
 
 include!("debug_assert_pthread_result_ok.rs");
 include!("debug_assert_pthread_result_ok_dragonfly.rs");
+include!("poll_until_timeout.rs");
+include!("realtime_deadline.rs");
 
 
 include!("CtoConditionVariable.rs");
@@ -65,5 +84,10 @@ include!("CtoReadWriteLock.rs");
 include!("CtoReadWriteLockInner.rs");
 include!("CtoReadWriteLockReadGuard.rs");
 include!("CtoReadWriteLockWriteGuard.rs");
+#[cfg(target_os = "linux")] include!("CtoRobustMutexLock.rs");
+#[cfg(target_os = "linux")] include!("CtoRobustMutexLockError.rs");
+#[cfg(target_os = "linux")] include!("CtoRobustMutexLockGuard.rs");
+#[cfg(target_os = "linux")] include!("CtoRobustMutexLockInner.rs");
+#[cfg(target_os = "linux")] include!("OwnerDied.rs");
 include!("ResultIsOk.rs");
 include!("TimedOut.rs");
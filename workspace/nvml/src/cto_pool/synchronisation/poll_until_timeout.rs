@@ -0,0 +1,31 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+// macOS and iOS do not implement `pthread_mutex_timedlock()` or `pthread_rwlock_timed*lock()`, so we poll `try_lock` instead, sleeping briefly between attempts.
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+#[inline(always)]
+fn poll_until_timeout<Guard, TryLock: Fn() -> Option<Guard>>(duration: Duration, try_lock: TryLock) -> Option<Guard>
+{
+	use ::std::thread::sleep;
+	use ::std::time::Instant;
+	
+	let maximum_sleep = Duration::from_millis(1);
+	
+	let started = Instant::now();
+	loop
+	{
+		if let Some(guard) = try_lock()
+		{
+			return Some(guard);
+		}
+		
+		let elapsed = started.elapsed();
+		if elapsed >= duration
+		{
+			return None;
+		}
+		
+		sleep(min(duration - elapsed, maximum_sleep));
+	}
+}
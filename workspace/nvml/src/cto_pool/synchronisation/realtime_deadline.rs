@@ -0,0 +1,23 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+// `pthread_mutex_timedlock()` and `pthread_rwlock_timed*lock()` take an absolute deadline measured against `CLOCK_REALTIME`, unlike our condition variables, which use `CLOCK_MONOTONIC` where it is available.
+#[cfg(all(unix, not(any(target_os = "macos", target_os = "ios"))))]
+#[inline(always)]
+fn realtime_deadline(duration: Duration) -> timespec
+{
+	use ::libc::clock_gettime;
+	use ::libc::CLOCK_REALTIME;
+	
+	let mut now: timespec = unsafe { uninitialized() };
+	let result = unsafe { clock_gettime(CLOCK_REALTIME, &mut now) };
+	debug_assert_pthread_result_ok!(result);
+	
+	// Nanosecond calculations can't overflow because both values are below 1e9.
+	let nanoseconds = duration.subsec_nanos() + now.tv_nsec as u32;
+	
+	let seconds = CtoConditionVariableInner::saturating_cast_to_time_t(duration.as_secs()).checked_add((nanoseconds / 1_000_000_000) as time_t).and_then(|s| s.checked_add(now.tv_sec));
+	
+	seconds.map(|s| timespec { tv_sec: s, tv_nsec: (nanoseconds % 1_000_000_000) as _}).unwrap_or(CtoConditionVariableInner::MaximumTimeSpec)
+}
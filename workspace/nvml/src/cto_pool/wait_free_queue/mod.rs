@@ -2,13 +2,17 @@
 // Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
 
 
-#![allow(dead_code)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
-#![allow(unused_variables)]
 
 
+use ExtendedNonNull;
 use IsNotNull;
+use ToNonNull;
+use hyper_thread::*;
+use super::*;
+use super::arc::CtoStrongArc;
+use super::arc::CtoStrongArcInner;
 use ::std::cell::Cell as CopyCell;
 use ::std::cell::UnsafeCell;
 use ::std::intrinsics::atomic_load_acq;
@@ -21,6 +25,7 @@ use ::std::intrinsics::atomic_xadd;
 use ::std::intrinsics::atomic_xadd_relaxed;
 use ::std::mem::uninitialized;
 use ::std::mem::size_of;
+use ::std::mem::transmute_copy;
 use ::std::ops::Deref;
 use ::std::ops::Neg;
 use ::std::ptr::NonNull;
@@ -224,12 +229,6 @@ impl<T> CacheAligned<T>
 	{
 		CacheAligned(value)
 	}
-	
-	#[inline(always)]
-	fn initialize(&mut self, value: T)
-	{
-		unsafe { write(&mut self.0, value) }
-	}
 }
 
 impl<T: Copy> CacheAligned<CopyCell<T>>
@@ -256,59 +255,11 @@ impl<T: Copy> CacheAligned<volatile<T>>
 		self.0.get()
 	}
 	
-	#[inline(always)]
-	pub(crate) fn set(&self, value: T)
-	{
-		self.0.set(value)
-	}
-	
-	#[inline(always)]
-	pub(crate) fn acquire(&self) -> T
-	{
-		self.0.acquire()
-	}
-	
-	#[inline(always)]
-	pub(crate) fn release(&self, value: T)
-	{
-		self.0.release(value)
-	}
-	
-	#[inline(always)]
-	pub(crate) fn relaxed_fetch_and_add(&self, increment: T) -> T
-	{
-		self.0.relaxed_fetch_and_add(increment)
-	}
-	
-	#[inline(always)]
-	pub(crate) fn sequentially_consistent_fetch_and_add(&self, increment: T) -> T
-	{
-		self.0.sequentially_consistent_fetch_and_add(increment)
-	}
-	
-	#[inline(always)]
-	pub(crate) fn relaxed_relaxed_compare_and_swap(&self, compare: &mut T, value: T) -> bool
-	{
-		self.0.relaxed_relaxed_compare_and_swap(compare, value)
-	}
-	
-	#[inline(always)]
-	pub(crate) fn sequentially_consistent_compare_and_swap(&self, compare: &mut T, value: T) -> bool
-	{
-		self.0.sequentially_consistent_compare_and_swap(compare, value)
-	}
-	
 	#[inline(always)]
 	pub(crate) fn release_acquire_compare_and_swap(&self, compare: &mut T, value: T) -> bool
 	{
 		self.0.release_acquire_compare_and_swap(compare, value)
 	}
-	
-	#[inline(always)]
-	pub(crate) fn acquire_relaxed_compare_and_swap(&self, compare: &mut T, value: T) -> bool
-	{
-		self.0.acquire_relaxed_compare_and_swap(compare, value)
-	}
 }
 
 #[cfg_attr(target_pointer_width = "32", repr(C, align(64)))]
@@ -333,21 +284,6 @@ impl<T> DoubleCacheAligned<T>
 	}
 }
 
-impl<T: Copy> DoubleCacheAligned<T>
-{
-	#[inline(always)]
-	pub(crate) fn get(&self) -> T
-	{
-		self.0
-	}
-	
-	#[inline(always)]
-	pub(crate) fn set(&mut self, value: T)
-	{
-		self.0 = value
-	}
-}
-
 impl<T: Copy> DoubleCacheAligned<volatile<T>>
 {
 	#[inline(always)]
@@ -392,24 +328,6 @@ impl<T: Copy> DoubleCacheAligned<volatile<T>>
 		self.0.relaxed_relaxed_compare_and_swap(compare, value)
 	}
 	
-	#[inline(always)]
-	pub(crate) fn sequentially_consistent_compare_and_swap(&self, compare: &mut T, value: T) -> bool
-	{
-		self.0.sequentially_consistent_compare_and_swap(compare, value)
-	}
-	
-	#[inline(always)]
-	pub(crate) fn release_acquire_compare_and_swap(&self, compare: &mut T, value: T) -> bool
-	{
-		self.0.release_acquire_compare_and_swap(compare, value)
-	}
-	
-	#[inline(always)]
-	pub(crate) fn acquire_relaxed_compare_and_swap(&self, compare: &mut T, value: T) -> bool
-	{
-		self.0.acquire_relaxed_compare_and_swap(compare, value)
-	}
-	
 	#[inline(always)]
 	pub(crate) fn acquire_relaxed_compare_and_swap_do_not_mutate_compare(&self, compare: T, value: T) -> bool
 	{
@@ -541,7 +459,8 @@ impl<Value> volatile<NonNull<Node<Value>>>
 	
 	// We almost want a 'reserve' for memory allocation, so that this thread can at least always populate a find_cell request
 	// Theoretical reserve limit of nodes is (maximum_node_identifier - current_node_identifier) - (if thread handle self has a spare already)
-	fn find_cell(&self, at_position_index: PositionIndex, this: &PerHyperThreadHandle<Value>, cto_pool_arc: &CtoPoolArc) -> Result<&Cell<Value>, PmdkError>
+	// Panics if a new node can not be allocated; by this point another thread may be waiting on the cell, so there is no safe way to back out.
+	fn find_cell(&self, at_position_index: PositionIndex, this: &PerHyperThreadHandle<Value>, cto_pool_arc: &CtoPoolArc) -> &Cell<Value>
 	{
 		let mut current = self.get().long_reference();
 		
@@ -553,7 +472,7 @@ impl<Value> volatile<NonNull<Node<Value>>>
 			
 			if next.is_null()
 			{
-				let spare_node_to_use_for_next = this.get_non_null_spare_node(cto_pool_arc)?;
+				let spare_node_to_use_for_next = this.get_non_null_spare_node(cto_pool_arc);
 				spare_node_to_use_for_next.reference().identifier.set(current_node_identifier.increment());
 				
 				if current.next.release_acquire_compare_and_swap(&mut next, spare_node_to_use_for_next.as_ptr())
@@ -569,7 +488,7 @@ impl<Value> volatile<NonNull<Node<Value>>>
 		
 		self.set(current.to_non_null());
 		
-		Ok(at_position_index.get_cell(current))
+		at_position_index.get_cell(current)
 	}
 }
 
@@ -603,14 +522,14 @@ impl<T: Copy> volatile<T>
 	#[inline(always)]
 	pub(crate) fn acquire(&self) -> T
 	{
-		unsafe { atomic_load_acq(self.0.get() as *const T) }
+		Self::from_word(unsafe { atomic_load_acq(self.word_pointer() as *const usize) })
 	}
 	
 	/// primitives.h calls this `RELEASE`.
 	#[inline(always)]
 	pub(crate) fn release(&self, value: T)
 	{
-		unsafe { atomic_store_rel(self.0.get(), value) }
+		unsafe { atomic_store_rel(self.word_pointer(), Self::to_word(value)) }
 	}
 	
 	/// An atomic fetch-and-add that is relaxed.
@@ -619,7 +538,7 @@ impl<T: Copy> volatile<T>
 	#[inline(always)]
 	pub(crate) fn relaxed_fetch_and_add(&self, increment: T) -> T
 	{
-		unsafe { atomic_xadd_relaxed(self.0.get(), increment) }
+		Self::from_word(unsafe { atomic_xadd_relaxed(self.word_pointer(), Self::to_word(increment)) })
 	}
 	
 	/// An atomic fetch-and-add that also ensures sequential consistency.
@@ -628,7 +547,7 @@ impl<T: Copy> volatile<T>
 	#[inline(always)]
 	pub(crate) fn sequentially_consistent_fetch_and_add(&self, increment: T) -> T
 	{
-		unsafe { atomic_xadd(self.0.get(), increment) }
+		Self::from_word(unsafe { atomic_xadd(self.word_pointer(), Self::to_word(increment)) })
 	}
 	
 	/// An atomic compare-and-swap that is completely relaxed.
@@ -639,11 +558,11 @@ impl<T: Copy> volatile<T>
 	#[inline(always)]
 	pub(crate) fn relaxed_relaxed_compare_and_swap(&self, compare: &mut T, value: T) -> bool
 	{
-		let (value, ok) = unsafe { atomic_cxchg_relaxed(self.0.get(), *compare, value) };
+		let (was, ok) = unsafe { atomic_cxchg_relaxed(self.word_pointer(), Self::to_word(*compare), Self::to_word(value)) };
 		
 		if !ok
 		{
-			*compare = value;
+			*compare = Self::from_word(was);
 		}
 		ok
 	}
@@ -656,11 +575,11 @@ impl<T: Copy> volatile<T>
 	#[inline(always)]
 	pub(crate) fn sequentially_consistent_compare_and_swap(&self, compare: &mut T, value: T) -> bool
 	{
-		let (value, ok) = unsafe { atomic_cxchg(self.0.get(), *compare, value) };
+		let (was, ok) = unsafe { atomic_cxchg(self.word_pointer(), Self::to_word(*compare), Self::to_word(value)) };
 		
 		if !ok
 		{
-			*compare = value;
+			*compare = Self::from_word(was);
 		}
 		ok
 	}
@@ -673,11 +592,11 @@ impl<T: Copy> volatile<T>
 	#[inline(always)]
 	pub(crate) fn release_acquire_compare_and_swap(&self, compare: &mut T, value: T) -> bool
 	{
-		let (value, ok) = unsafe { atomic_cxchg_acqrel(self.0.get(), *compare, value) };
+		let (was, ok) = unsafe { atomic_cxchg_acqrel(self.word_pointer(), Self::to_word(*compare), Self::to_word(value)) };
 		
 		if !ok
 		{
-			*compare = value;
+			*compare = Self::from_word(was);
 		}
 		ok
 	}
@@ -685,29 +604,34 @@ impl<T: Copy> volatile<T>
 	/// An atomic compare-and-swap that ensures acquire semantic when succeed or relaxed semantic when failed.
 	/// true if successful.
 	/// false if failed.
-	/// compare is updated if failed.
-	/// primitives.h calls this `CASa`.
+	/// compare is left unchanged.
 	#[inline(always)]
-	pub(crate) fn acquire_relaxed_compare_and_swap(&self, compare: &mut T, value: T) -> bool
+	pub(crate) fn acquire_relaxed_compare_and_swap_do_not_mutate_compare(&self, compare: T, value: T) -> bool
 	{
-		let (value, ok) = unsafe { atomic_cxchg_acq_failrelaxed(self.0.get(), *compare, value) };
-		
-		if !ok
-		{
-			*compare = value;
-		}
+		let (_was, ok) = unsafe { atomic_cxchg_acq_failrelaxed(self.word_pointer(), Self::to_word(compare), Self::to_word(value)) };
 		ok
 	}
 	
-	/// An atomic compare-and-swap that ensures acquire semantic when succeed or relaxed semantic when failed.
-	/// true if successful.
-	/// false if failed.
-	/// compare is left unchanged.
+	// The atomic intrinsics only accept integers and raw pointers, so values (all of which are one word wide: pointers, `PositionIndex`, `NodeIdentifier` and `NodePointerIdentifier`) are operated on as an `usize`.
+	// Fetch-and-add on an `usize` is the same as on an `isize` for two's complement.
 	#[inline(always)]
-	pub(crate) fn acquire_relaxed_compare_and_swap_do_not_mutate_compare(&self, compare: T, value: T) -> bool
+	fn word_pointer(&self) -> *mut usize
 	{
-		let (value, ok) = unsafe { atomic_cxchg_acq_failrelaxed(self.0.get(), compare, value) };
-		ok
+		debug_assert_eq!(size_of::<T>(), size_of::<usize>(), "T is not one word wide");
+		
+		self.0.get() as *mut usize
+	}
+	
+	#[inline(always)]
+	fn to_word(value: T) -> usize
+	{
+		unsafe { transmute_copy(&value) }
+	}
+	
+	#[inline(always)]
+	fn from_word(word: usize) -> T
+	{
+		unsafe { transmute_copy(&word) }
 	}
 }
 
@@ -1085,7 +1009,7 @@ impl<Value> Node<Value>
 	#[inline(always)]
 	fn new_node(cto_pool_arc: &CtoPoolArc) -> Result<NonNull<Self>, PmdkError>
 	{
-		let node = cto_pool_arc.page_aligned_allocate()?;
+		let node = cto_pool_arc.page_aligned_allocate::<Self>()?;
 		unsafe { node.as_ptr().write_bytes(0, 1) }
 		Ok(node)
 	}
@@ -1137,9 +1061,32 @@ impl<Value> Node<Value>
 	}
 }
 
+impl<Value: CtoSafe> Node<Value>
+{
+	// Only valid when the queue is quiescent, ie when the CTO pool has just been opened.
+	// A cell holds a value that is still enqueued if the value was never replaced by `Top` and no dequeuer has claimed it.
+	#[inline(always)]
+	fn values_cto_pool_opened(&self, cto_pool_arc: &CtoPoolArc)
+	{
+		let mut cell_index = 0;
+		while cell_index < Cells::<Value>::NumberOfCellsInANode
+		{
+			let cell = self.get_cell(cell_index);
+			let value = cell.value();
+			if value.is_not_bottom() && value.is_not_top() && cell.dequeuer().is_bottom()
+			{
+				value.to_non_null().mutable_reference().cto_pool_opened(cto_pool_arc)
+			}
+			cell_index += 1;
+		}
+	}
+}
+
+/// Rust implementation of a persistent variant of the wait-free queue of Chaoran Yang and John Mellor-Crummey, "A Wait-free Queue as Fast as Fetch-and-Add", PPoPP 2016; see <https://github.com/chaoran/fast-wait-free-queue>.
+/// Each hyper thread has its own handle, allocated when the queue is created; a thread finds its handle using `hyper_thread_index()`.
 #[cfg_attr(target_pointer_width = "32", repr(C, align(64)))]
 #[cfg_attr(target_pointer_width = "64", repr(C, align(128)))]
-struct WaitFreeQueue<Value>
+pub struct WaitFreeQueue<Value: CtoSafe>
 {
 	// Called in wfqueue.c code `Ei`.
 	// Initially 1.
@@ -1158,12 +1105,18 @@ struct WaitFreeQueue<Value>
 	// Called in wfqueue.c code `Hp`.
 	head_of_queue_node_pointer: volatile<NonNull<Node<Value>>>,
 	
-	// A singularly-linked list of per-thread handles, atomically updated.
+	// A ring of per-thread handles, atomically updated.
 	// tail is only NULL before the very first PerHyperThreadHandle is created.
-	// The list follow the `.next` pointer in each PerHyperThreadHandle.
-	// The terminal PerHyperThreadHandle has a `.next` which points to itself.
+	// The ring follows the `.next` pointer in each PerHyperThreadHandle.
+	// A ring of just one PerHyperThreadHandle has a `.next` which points to itself.
 	tail: volatile<*mut PerHyperThreadHandle<Value>>,
 	
+	// Indexed by `hyper_thread_index()`; entries at or after `number_of_hyper_threads` are null.
+	// Was the `handle_t *phs[q->nprocs]` array in wfqueue.c's tests.
+	per_hyper_thread_handles: [*mut PerHyperThreadHandle<Value>; NumberOfHyperThreads::InclusiveMaximumNumberOfHyperThreads],
+	
+	number_of_hyper_threads: NumberOfHyperThreads,
+	
 	maximum_garbage: MaximumGarbage,
 	
 	reference_counter: AtomicUsize,
@@ -1171,7 +1124,7 @@ struct WaitFreeQueue<Value>
 	cto_pool_arc: CtoPoolArc,
 }
 
-impl<Value> CtoSafe for WaitFreeQueue<Value>
+impl<Value: CtoSafe> CtoSafe for WaitFreeQueue<Value>
 {
 	#[inline(always)]
 	fn cto_pool_opened(&mut self, cto_pool_arc: &CtoPoolArc)
@@ -1179,33 +1132,66 @@ impl<Value> CtoSafe for WaitFreeQueue<Value>
 		// self.reference_counter is left as-is
 		cto_pool_arc.write(&mut self.cto_pool_arc);
 		
-		xxxx: Overwrite all nodes, Overwrite the single-linked list of per-hyper-thread handles
+		// A crash whilst collecting garbage leaves the sentinel `NodeIdentifier::NoHeadOfQueue` in place; nodes older than `head_of_queue_node_pointer` may be leaked.
+		let head_of_queue_node_identifier = self.head_of_queue_node_pointer().reference().identifier();
+		self.release_head_of_queue_node_identifier(head_of_queue_node_identifier);
 		
-		xxxx: Look at FreeList
+		// A crash inside `enqueue()` or `dequeue()` leaves a hazard pointer taken.
+		let mut index = 0;
+		while index < self.number_of_hyper_threads.as_usize()
+		{
+			self.per_hyper_thread_handle(index).exit_critical_section_protected_by_hazard_pointer();
+			index += 1;
+		}
 		
-		xxxx: What about *mut Value?
+		let mut node = self.head_of_queue_node_pointer().long_reference();
+		loop
+		{
+			node.values_cto_pool_opened(cto_pool_arc);
+			
+			let next = node.next.get();
+			if next.is_null()
+			{
+				break
+			}
+			node = next.to_non_null().long_reference();
+		}
 	}
 }
 
-impl<Value> Drop for WaitFreeQueue<Value>
+impl<Value: CtoSafe> Drop for WaitFreeQueue<Value>
 {
 	#[inline(always)]
 	fn drop(&mut self)
 	{
-		xxxx: Look at FreeList
+		// As for `PersistentFetchAndAddArrayQueue`, any values still enqueued are not dropped; they are owned by whoever enqueued them.
 		
-		// Clean up any remaining nodes
-		// Free any remaining *mut Value pointers (drop_in_place)
+		let mut index = 0;
+		while index < self.number_of_hyper_threads.as_usize()
+		{
+			let per_hyper_thread_handle = self.per_hyper_thread_handles[index];
+			if per_hyper_thread_handle.is_not_null()
+			{
+				per_hyper_thread_handle.to_non_null().reference().free(&self.cto_pool_arc);
+			}
+			index += 1;
+		}
 		
-		let tail = self.tail();
-		if tail.is_not_null()
+		let mut node = self.head_of_queue_node_pointer().as_ptr();
+		while node.is_not_null()
 		{
-			tail.to_non_null().mutable_reference().drop_like(&self.cto_pool_arc);
+			let next = node.to_non_null().reference().next.get();
+			self.cto_pool_arc.free_pointer(node);
+			node = next;
 		}
+		
+		// Destroy ourselves
+		let cto_pool_arc = self.cto_pool_arc.clone();
+		cto_pool_arc.free_pointer(self)
 	}
 }
 
-impl<Value> CtoStrongArcInner for WaitFreeQueue<Value>
+impl<Value: CtoSafe> CtoStrongArcInner for WaitFreeQueue<Value>
 {
 	#[inline(always)]
 	fn reference_counter(&self) -> &AtomicUsize
@@ -1214,18 +1200,16 @@ impl<Value> CtoStrongArcInner for WaitFreeQueue<Value>
 	}
 }
 
-impl<Value> WaitFreeQueue<Value>
+impl<Value: CtoSafe> WaitFreeQueue<Value>
 {
 	const MaximumPatienceForFastPath: isize = 10;
 	
+	/// Creates a new instance, with one handle for each of `number_of_hyper_threads`.
+	/// `hyper_thread_index()` must return a value less than `number_of_hyper_threads` for every thread that enqueues or dequeues.
 	#[inline(always)]
-	pub fn new(cto_pool_arc: &CtoPoolArc, number_of_threads: NumberOfHyperThreads) -> Result<(CtoStrongArc<Self>, [NonNull<PerHyperThreadHandle<Value>>; Self::InclusiveMaximumNumberOfHyperThreads]), PmdkError>
+	pub fn new(cto_pool_arc: &CtoPoolArc, number_of_hyper_threads: NumberOfHyperThreads) -> Result<CtoStrongArc<Self>, PmdkError>
 	{
-		xxxx: Rather than using CtoPoolArc, use a free list, as Node, WaitFreeQueue and PerHyperThreadHandle are all 4096 bytes.
-	
-		xxxx: Make PerHTHandles part of the queue? And let threads find them using a simple index? (then a queue isn't the same size as a 4096 byte free list block)
-		
-		let mut this = cto_pool_arc.page_size_align_malloc()?;
+		let mut this = cto_pool_arc.page_aligned_allocate::<Self>()?;
 		let head_node = match Node::new_node(cto_pool_arc)
 		{
 			Err(pmdk_error) =>
@@ -1249,33 +1233,72 @@ impl<Value> WaitFreeQueue<Value>
 			let this: &mut Self = this.mutable_reference();
 			unsafe
 			{
-				write(&mut this.maximum_garbage, number_of_threads.maximum_garbage());
+				write(&mut this.per_hyper_thread_handles, [null_mut(); NumberOfHyperThreads::InclusiveMaximumNumberOfHyperThreads]);
+				write(&mut this.number_of_hyper_threads, number_of_hyper_threads);
+				write(&mut this.maximum_garbage, number_of_hyper_threads.maximum_garbage());
 				write(&mut this.reference_counter, Self::new_reference_counter());
 				write(&mut this.cto_pool_arc, cto_pool_arc.clone())
 			};
 		}
 		
-		let thread_handles =
+		if let Err(pmdk_error) = number_of_hyper_threads.allocate_per_hyper_thread_handles(this.mutable_reference())
 		{
-			let this: &Self = this.reference();
-			match number_of_threads.allocate_per_hyper_thread_handles(this)
-			{
-				Err(pmdk_error) =>
-				{
-					// free self.tail (look at drop)
-					// free head_node
-					// free this
-					xxxxx xxxx
-				}
-				Ok(thread_handles) => thread_handles
-			}
-		};
+			// Frees any per-hyper-thread handles allocated so far, the head node and `this`.
+			unsafe { drop_in_place(this.as_ptr()) };
+			return Err(pmdk_error)
+		}
 		
-		Ok((CtoStrongArc::new(this), thread_handles))
+		Ok(CtoStrongArc::new(this))
 	}
 	
+	/// Enqueue a value.
+	/// Panics if out-of-memory when a new node is needed; the queue can not be left in a safe state.
+	/// Panics if `hyper_thread_index()` is not less than the number of hyper threads this queue was created with.
 	#[inline(always)]
-	pub fn enqueue(&self, this: &PerHyperThreadHandle<Value>, value_to_enqueue: NonNull<Value>)
+	pub fn enqueue(&self, value_to_enqueue: NonNull<Value>)
+	{
+		self.enqueue_faster(hyper_thread_index(), value_to_enqueue)
+	}
+	
+	/// Enqueue a value.
+	/// Panics if out-of-memory when a new node is needed; the queue can not be left in a safe state.
+	/// Slightly faster as no need to look up `hyper_thread_index`.
+	/// Panics if `hyper_thread_index` is not less than the number of hyper threads this queue was created with.
+	#[inline(always)]
+	pub fn enqueue_faster(&self, hyper_thread_index: usize, value_to_enqueue: NonNull<Value>)
+	{
+		self.enqueue_with_handle(self.per_hyper_thread_handle(hyper_thread_index), value_to_enqueue)
+	}
+	
+	/// Dequeue a value.
+	/// Returns None if there is no value.
+	/// Panics if `hyper_thread_index()` is not less than the number of hyper threads this queue was created with.
+	#[inline(always)]
+	pub fn dequeue(&self) -> Option<NonNull<Value>>
+	{
+		self.dequeue_faster(hyper_thread_index())
+	}
+	
+	/// Dequeue a value.
+	/// Returns None if there is no value.
+	/// Slightly faster as no need to look up `hyper_thread_index`.
+	/// Panics if `hyper_thread_index` is not less than the number of hyper threads this queue was created with.
+	#[inline(always)]
+	pub fn dequeue_faster(&self, hyper_thread_index: usize) -> Option<NonNull<Value>>
+	{
+		self.dequeue_with_handle(self.per_hyper_thread_handle(hyper_thread_index))
+	}
+	
+	#[inline(always)]
+	fn per_hyper_thread_handle(&self, hyper_thread_index: usize) -> &PerHyperThreadHandle<Value>
+	{
+		assert!(hyper_thread_index < self.number_of_hyper_threads.as_usize(), "hyper_thread_index '{}' is not less than the number of hyper threads '{}' this queue was created with", hyper_thread_index, self.number_of_hyper_threads.as_usize());
+		
+		self.per_hyper_thread_handles[hyper_thread_index].to_non_null().long_reference()
+	}
+	
+	#[inline(always)]
+	fn enqueue_with_handle(&self, this: &PerHyperThreadHandle<Value>, value_to_enqueue: NonNull<Value>)
 	{
 		assert!(value_to_enqueue.as_ptr().is_not_top(), "value_to_enqueue is not allowed to be top");
 		
@@ -1296,9 +1319,8 @@ impl<Value> WaitFreeQueue<Value>
 		this.exit_critical_section_protected_by_hazard_pointer()
 	}
 	
-	/// Returns None if there is no value.
 	#[inline(always)]
-	pub fn dequeue(&self, this: &PerHyperThreadHandle<Value>) -> Option<NonNull<Value>>
+	fn dequeue_with_handle(&self, this: &PerHyperThreadHandle<Value>) -> Option<NonNull<Value>>
 	{
 		this.enter_critical_section_protected_by_hazard_pointer(this.dequeuer_node_pointer_identifier());
 		let dequeued_value =
@@ -1337,7 +1359,8 @@ impl<Value> WaitFreeQueue<Value>
 		if this.spare_is_null()
 		{
 			self.collect_node_garbage_after_dequeue(this);
-			this.set_new_spare_node(&self.cto_pool_arc); // Failing to set the spare due to being out-of-memory is non-fatal but worrisome.
+			// Failing to set the spare due to being out-of-memory is non-fatal but worrisome; `find_cell()` will try again.
+			let _ = this.set_new_spare_node(&self.cto_pool_arc);
 		}
 		
 		debug_assert!(dequeued_value.is_not_top(), "dequeued_value was top");
@@ -1352,8 +1375,7 @@ impl<Value> WaitFreeQueue<Value>
 		
 		let index_after_the_next_position_for_enqueue = self.sequentially_consistent_fetch_and_increment_enqueue_next_position_index();
 		
-		// Out of memory - can we fallback to slow path?
-		let cell = this.pointer_to_the_node_for_enqueue_find_cell(index_after_the_next_position_for_enqueue, this, &self.cto_pool_arc)?;
+		let cell = this.pointer_to_the_node_for_enqueue_find_cell(index_after_the_next_position_for_enqueue, this, &self.cto_pool_arc);
 		
 		if cell.relaxed_relaxed_compare_and_swap_value(value_to_enqueue)
 		{
@@ -1385,7 +1407,6 @@ impl<Value> WaitFreeQueue<Value>
 		'do_while: while
 		{
 			index_after_the_next_position_for_enqueue = self.relaxed_fetch_and_increment_enqueue_next_position_index();
-			// Out of memory - must retry?
 			cell = tail.find_cell(index_after_the_next_position_for_enqueue, this, &self.cto_pool_arc);
 			
 			let mut expected_enqueuer = <*mut Enqueuer<Value>>::Bottom;
@@ -1400,7 +1421,6 @@ impl<Value> WaitFreeQueue<Value>
 		}
 		
 		enqueue_position_index = -enqueuer.enqueue_position_index.get();
-		// Out of memory - must retry?
 		cell = this.pointer_to_the_node_for_enqueue_find_cell(enqueue_position_index, this, &self.cto_pool_arc);
 		if enqueue_position_index > index_after_the_next_position_for_enqueue
 		{
@@ -1577,7 +1597,6 @@ impl<Value> WaitFreeQueue<Value>
 			
 			while dequeue_position_index_x == old_dequeue_position_index && new_dequeue_position_index.is_zero()
 			{
-				// Out of memory - now what?
 				let cell = h.find_cell(position_index, this, &self.cto_pool_arc);
 				
 				let mut index_of_the_next_position_for_dequeue = self.dequeue_next_position_index();
@@ -1616,7 +1635,6 @@ impl<Value> WaitFreeQueue<Value>
 				break;
 			}
 			
-			// Out of memory - now what?
 			let cell = other_pointer_to_the_node_for_dequeue.find_cell(dequeue_position_index_x, this, &self.cto_pool_arc);
 			let mut was = <*mut Dequeuer>::Bottom;
 			if cell.value().is_top() || cell.relaxed_relaxed_compare_and_swap_dequeuer(&mut was, dequeuer.as_ptr()) || was == dequeuer.as_ptr()
@@ -1771,12 +1789,14 @@ impl<Value> WaitFreeQueue<Value>
 	}
 }
 
+/// The number of hyper threads that will use a `WaitFreeQueue`; one per-hyper-thread handle is allocated for each.
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct NumberOfHyperThreads(u16);
 
 impl NumberOfHyperThreads
 {
-	pub const InclusiveMaximumNumberOfHyperThreads: usize = 256;
+	/// Inclusive maximum.
+	pub const InclusiveMaximumNumberOfHyperThreads: usize = MaximumSupportedHyperThreads;
 	
 	/// Panics if `number_of_hyper_threads` is 0 or exceeds InclusiveMaximumNumberOfHyperThreads.
 	#[inline(always)]
@@ -1788,6 +1808,7 @@ impl NumberOfHyperThreads
 		NumberOfHyperThreads(number_of_hyper_threads)
 	}
 	
+	/// Number of garbage nodes to accumulate before trying to free them; twice the number of hyper threads.
 	#[inline(always)]
 	pub fn maximum_garbage(&self) -> MaximumGarbage
 	{
@@ -1796,28 +1817,32 @@ impl NumberOfHyperThreads
 		MaximumGarbage(maximum_garbage as isize)
 	}
 	
-	xxxx; // the resultant array is subject to false (cache line) sharing, as each handle is 8 bytes and a cache line is 64 bytes.
+	/// Number of hyper threads as an `usize`.
 	#[inline(always)]
-	pub(crate) fn allocate_per_hyper_thread_handles<Value>(&self, wait_free_queue: &WaitFreeQueue<Value>) -> Result<[NonNull<PerHyperThreadHandle<Value>>; Self::InclusiveMaximumNumberOfHyperThreads], PmdkError>
+	pub fn as_usize(&self) -> usize
+	{
+		self.0 as usize
+	}
+	
+	// The array of handles is only read after creation, so false (cache line) sharing of it, as each handle is 8 bytes and a cache line is 64 bytes, does not matter.
+	// If an error occurs, handles already allocated are left in `wait_free_queue.per_hyper_thread_handles` so that they can be freed.
+	#[inline(always)]
+	pub(crate) fn allocate_per_hyper_thread_handles<Value: CtoSafe>(&self, wait_free_queue: &mut WaitFreeQueue<Value>) -> Result<(), PmdkError>
 	{
-		let mut per_hyper_thread_handles = unsafe { uninitialized() };
-		
 		let mut index = 0;
-		while index < self.0
+		while index < self.as_usize()
 		{
-			match PerHyperThreadHandle::new(wait_free_queue)
-			{
-				Ok(per_hyper_thread_handle) => *unsafe { per_hyper_thread_handles.get_unchecked_mut(index as usize) } = per_hyper_thread_handle,
-				Err(pmdk_error) => return Err(pmdk_error),
-			}
+			let per_hyper_thread_handle = PerHyperThreadHandle::new(wait_free_queue)?;
+			wait_free_queue.per_hyper_thread_handles[index] = per_hyper_thread_handle.as_ptr();
 			
 			index += 1;
 		}
 		
-		Ok(per_hyper_thread_handles)
+		Ok(())
 	}
 }
 
+/// Number of garbage nodes to accumulate before trying to free them.
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct MaximumGarbage(isize);
 
@@ -1894,30 +1919,15 @@ struct PerHyperThreadHandle<Value>
 	spare: CacheAligned<CopyCell<*mut Node<Value>>>,
 }
 
-impl<Value> PerHyperThreadHandle<Value>
+impl<Value: CtoSafe> PerHyperThreadHandle<Value>
 {
-	pub(crate) fn drop_like(&mut self, cto_pool_arc: &CtoPoolArc)
-	{
-		let next = self.next_in_singularly_linked_list_or_self_if_end_of_list();
-		if self.as_ptr() != next.as_ptr()
-		{
-			next.reference().drop_like(cto_pool_arc);
-		}
-		let spare = self.spare();
-		if spare.is_not_null()
-		{
-			cto_pool_arc.free_pointer(spare)
-		}
-		cto_pool_arc.free_pointer(self.as_ptr())
-	}
-	
 	// A combination of `thread_init` and `queue_init`.
 	pub(crate) fn new(wait_free_queue: &WaitFreeQueue<Value>) -> Result<NonNull<Self>, PmdkError>
 	{
 		let cto_pool_arc = &wait_free_queue.cto_pool_arc;
-		let per_hyper_thread_handle_non_null = cto_pool_arc.page_aligned_allocate()?;
+		let per_hyper_thread_handle_non_null = cto_pool_arc.page_aligned_allocate::<Self>()?;
 		
-		let spare_node = match cto_pool_arc.page_aligned_allocate()
+		let spare_node = match Node::new_node(cto_pool_arc)
 		{
 			Err(pmdk_error) =>
 			{
@@ -1954,7 +1964,7 @@ impl<Value> PerHyperThreadHandle<Value>
 			this.initialize_next_enqueuer_and_dequeuer_to_help();
 		}
 		
-		per_hyper_thread_handle_non_null
+		Ok(per_hyper_thread_handle_non_null)
 	}
 	
 	#[inline(always)]
@@ -1984,6 +1994,22 @@ impl<Value> PerHyperThreadHandle<Value>
 			while !tail.next_in_singularly_linked_list_or_self_if_end_of_list.CASra(&mut next, per_hyper_thread_handle_non_null)
 		}
 	}
+}
+
+impl<Value> PerHyperThreadHandle<Value>
+{
+	// Frees the spare node, if any, and this handle.
+	// Does not follow the ring of handles; `WaitFreeQueue` frees each handle it holds.
+	#[inline(always)]
+	fn free(&self, cto_pool_arc: &CtoPoolArc)
+	{
+		let spare = self.spare();
+		if spare.is_not_null()
+		{
+			cto_pool_arc.free_pointer(spare)
+		}
+		cto_pool_arc.free_pointer(self.as_ptr())
+	}
 	
 	#[inline(always)]
 	fn initialize_next(&self)
@@ -2035,12 +2061,6 @@ impl<Value> PerHyperThreadHandle<Value>
 		sequentially_consistent_fence()
 	}
 	
-	#[inline(always)]
-	fn release_hazard_node_pointer_identifier(&self, hazard_node_pointer_identifier: NodePointerIdentifier)
-	{
-		self.hazard_node_pointer_identifier.release(hazard_node_pointer_identifier)
-	}
-	
 	#[inline(always)]
 	fn initialize_hazard_node_pointer_identifier(&self)
 	{
@@ -2078,13 +2098,13 @@ impl<Value> PerHyperThreadHandle<Value>
 	}
 	
 	#[inline(always)]
-	fn pointer_to_the_node_for_enqueue_find_cell(&self, position_index: PositionIndex, this: &PerHyperThreadHandle<Value>, cto_pool_arc: &CtoPoolArc) -> Result<&Cell<Value>, PmdkError>
+	fn pointer_to_the_node_for_enqueue_find_cell(&self, position_index: PositionIndex, this: &PerHyperThreadHandle<Value>, cto_pool_arc: &CtoPoolArc) -> &Cell<Value>
 	{
 		self.pointer_to_the_node_for_enqueue_reference().find_cell(position_index, this, cto_pool_arc)
 	}
 	
 	#[inline(always)]
-	fn pointer_to_the_node_for_dequeue_find_cell(&self, position_index: PositionIndex, this: &PerHyperThreadHandle<Value>, cto_pool_arc: &CtoPoolArc) -> Result<&Cell<Value>, PmdkError>
+	fn pointer_to_the_node_for_dequeue_find_cell(&self, position_index: PositionIndex, this: &PerHyperThreadHandle<Value>, cto_pool_arc: &CtoPoolArc) -> &Cell<Value>
 	{
 		self.pointer_to_the_node_for_dequeue_reference().find_cell(position_index, this, cto_pool_arc)
 	}
@@ -2157,16 +2177,16 @@ impl<Value> PerHyperThreadHandle<Value>
 	}
 	
 	#[inline(always)]
-	fn get_non_null_spare_node(&self, cto_pool_arc: &CtoPoolArc) -> Result<NonNull<Node<Value>>, PmdkError>
+	fn get_non_null_spare_node(&self, cto_pool_arc: &CtoPoolArc) -> NonNull<Node<Value>>
 	{
 		let spare = self.spare();
 		if spare.is_not_null()
 		{
-			Ok(spare.to_non_null())
+			spare.to_non_null()
 		}
 		else
 		{
-			self.set_new_spare_node(cto_pool_arc)
+			self.set_new_spare_node(cto_pool_arc).expect("Out of memory in CTO pool when allocating a new wait free queue node")
 		}
 	}
 	
@@ -2220,3 +2240,217 @@ impl<Value> PerHyperThreadHandle<Value>
 		self as *const _ as *mut _
 	}
 }
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use ::std::env::temp_dir;
+	use ::std::ffi::CString;
+	use ::std::fs::remove_file;
+	use ::std::process;
+	use ::std::sync::Arc;
+	use ::std::thread;
+	
+	const NumberOfProducers: usize = 4;
+	
+	const NumberOfConsumers: usize = 4;
+	
+	const ValuesPerProducer: usize = 10_000;
+	
+	const TotalValues: usize = NumberOfProducers * ValuesPerProducer;
+	
+	#[test]
+	fn multiple_producers_and_multiple_consumers_dequeue_every_value_exactly_once()
+	{
+		let pool_file_path = temp_dir().join(format!("nvml-wait-free-queue-test-{}.cto", process::id()));
+		let _ = remove_file(&pool_file_path);
+		let layout_name = CString::new("wait_free_queue_test").unwrap();
+		let cto_pool_arc = CtoPoolArc::new(pool_file_path.create_cto_pool(&layout_name, 64 * 1024 * 1024, 0o600).expect("could not create CTO pool"));
+		
+		// Each producer and each consumer has its own hyper thread index, so that no two threads ever share a handle.
+		let queue = WaitFreeQueue::<u64>::new(&cto_pool_arc, NumberOfHyperThreads::new((NumberOfProducers + NumberOfConsumers) as u16)).expect("could not create queue");
+		
+		// Enqueued values point into this; value `n` is at index `n`.
+		let values: Arc<Vec<u64>> = Arc::new((0 .. TotalValues as u64).collect());
+		let dequeued_count = Arc::new(AtomicUsize::new(0));
+		
+		let producers: Vec<_> = (0 .. NumberOfProducers).map(|producer|
+		{
+			let queue = queue.clone();
+			let values = values.clone();
+			thread::spawn(move ||
+			{
+				let mut index = producer * ValuesPerProducer;
+				while index < (producer + 1) * ValuesPerProducer
+				{
+					queue.enqueue_faster(producer, unsafe { NonNull::new_unchecked(values.as_ptr().offset(index as isize) as *mut u64) });
+					index += 1;
+				}
+			})
+		}).collect();
+		
+		let consumers: Vec<_> = (0 .. NumberOfConsumers).map(|consumer|
+		{
+			let queue = queue.clone();
+			let dequeued_count = dequeued_count.clone();
+			thread::spawn(move ||
+			{
+				let mut dequeued = Vec::new();
+				while dequeued_count.load(SeqCst) < TotalValues
+				{
+					match queue.dequeue_faster(NumberOfProducers + consumer)
+					{
+						None => spin_loop_hint(),
+						Some(value) =>
+						{
+							dequeued.push(unsafe { *value.as_ptr() });
+							dequeued_count.fetch_add(1, SeqCst);
+						}
+					}
+				}
+				dequeued
+			})
+		}).collect();
+		
+		for producer in producers
+		{
+			producer.join().expect("producer panicked");
+		}
+		
+		let mut times_dequeued = vec![0usize; TotalValues];
+		for consumer in consumers
+		{
+			for value in consumer.join().expect("consumer panicked")
+			{
+				times_dequeued[value as usize] += 1;
+			}
+		}
+		
+		for (value, &times) in times_dequeued.iter().enumerate()
+		{
+			assert_eq!(times, 1, "value '{}' was dequeued '{}' times", value, times);
+		}
+		assert_eq!(queue.dequeue_faster(0), None, "queue should be empty");
+		
+		drop(queue);
+		drop(cto_pool_arc);
+		let _ = remove_file(&pool_file_path);
+	}
+	
+	/// Root of the pool used by `values_enqueued_before_reopen_are_dequeued_after_it`; enqueued values point into `values`.
+	struct QueueRoot
+	{
+		values: CtoVec<u64>,
+		queue: CtoStrongArc<WaitFreeQueue<u64>>,
+	}
+	
+	impl CtoSafe for QueueRoot
+	{
+		#[inline(always)]
+		fn cto_pool_opened(&mut self, cto_pool_arc: &CtoPoolArc)
+		{
+			self.values.cto_pool_opened(cto_pool_arc);
+			self.queue.cto_pool_opened(cto_pool_arc)
+		}
+	}
+	
+	#[test]
+	fn values_enqueued_before_reopen_are_dequeued_after_it()
+	{
+		let pool_file_path = temp_dir().join(format!("nvml-wait-free-queue-reopen-test-{}.cto", process::id()));
+		let _ = remove_file(&pool_file_path);
+		let layout_name = CString::new("wait_free_queue_reopen_test").unwrap();
+		let pool_pointer = pool_file_path.create_cto_pool(&layout_name, 64 * 1024 * 1024, 0o600).expect("could not create CTO pool");
+		
+		let number_of_hyper_threads = NumberOfHyperThreads::new((NumberOfProducers + NumberOfConsumers) as u16);
+		let values_dequeued_before_reopen = TotalValues / 2;
+		let mut times_dequeued = vec![0usize; TotalValues];
+		
+		{
+			let cto_pool = CtoPool::<QueueRoot>::open_from_allocator(CtoPoolArc::new(pool_pointer), |root: &mut QueueRoot, cto_pool_arc: &CtoPoolArc|
+			{
+				let mut values: CtoVec<u64> = cto_pool_arc.allocate_vec_with_capacity(TotalValues);
+				values.extend(0 .. TotalValues as u64);
+				let queue = WaitFreeQueue::new(cto_pool_arc, number_of_hyper_threads)?;
+				unsafe
+				{
+					write(&mut root.values, values);
+					write(&mut root.queue, queue);
+				}
+				Ok::<(), PmdkError>(())
+			}).expect("could not create root");
+			
+			// Raw pointers are not `Send`, so the address of the values is passed to each producer.
+			let values_address = cto_pool.values.as_ptr() as usize;
+			let producers: Vec<_> = (0 .. NumberOfProducers).map(|producer|
+			{
+				let queue = cto_pool.queue.clone();
+				thread::spawn(move ||
+				{
+					let mut index = producer * ValuesPerProducer;
+					while index < (producer + 1) * ValuesPerProducer
+					{
+						queue.enqueue_faster(producer, unsafe { NonNull::new_unchecked((values_address as *mut u64).offset(index as isize)) });
+						index += 1;
+					}
+				})
+			}).collect();
+			
+			for producer in producers
+			{
+				producer.join().expect("producer panicked");
+			}
+			
+			let mut dequeued = 0;
+			while dequeued < values_dequeued_before_reopen
+			{
+				let value = cto_pool.queue.dequeue_faster(NumberOfProducers).expect("queue should not be empty");
+				times_dequeued[unsafe { *value.as_ptr() } as usize] += 1;
+				dequeued += 1;
+			}
+		}
+		
+		// A new `CtoPoolArc` for the same pool has not yet attached the root, so opening it calls `cto_pool_opened()` on the existing root, just as reopening the pool file does.
+		let cto_pool = CtoPool::<QueueRoot>::open_from_allocator(CtoPoolArc::new(pool_pointer), |_: &mut QueueRoot, _: &CtoPoolArc| -> Result<(), PmdkError>
+		{
+			panic!("root should already exist")
+		}).expect("could not reopen root");
+		
+		let consumers: Vec<_> = (0 .. NumberOfConsumers).map(|consumer|
+		{
+			let queue = cto_pool.queue.clone();
+			thread::spawn(move ||
+			{
+				let mut dequeued = Vec::new();
+				while let Some(value) = queue.dequeue_faster(NumberOfProducers + consumer)
+				{
+					dequeued.push(unsafe { *value.as_ptr() });
+				}
+				dequeued
+			})
+		}).collect();
+		
+		for consumer in consumers
+		{
+			for value in consumer.join().expect("consumer panicked")
+			{
+				times_dequeued[value as usize] += 1;
+			}
+		}
+		
+		for (value, &times) in times_dequeued.iter().enumerate()
+		{
+			assert_eq!(times, 1, "value '{}' was dequeued '{}' times", value, times);
+		}
+		
+		// The queue is still usable after reopening.
+		let value = unsafe { NonNull::new_unchecked(cto_pool.values.as_ptr() as *mut u64) };
+		cto_pool.queue.enqueue_faster(0, value);
+		assert_eq!(cto_pool.queue.dequeue_faster(0), Some(value), "queue should return the value enqueued after reopening");
+		assert_eq!(cto_pool.queue.dequeue_faster(0), None, "queue should be empty");
+		
+		drop(cto_pool);
+		let _ = remove_file(&pool_file_path);
+	}
+}
@@ -19,6 +19,7 @@
 #![feature(i128_type)]
 #![feature(inclusive_range)]
 #![feature(integer_atomics)]
+#![feature(nonzero)]
 #![feature(offset_to)]
 #![feature(optin_builtin_traits)]
 #![feature(pattern)]
@@ -46,7 +47,7 @@
 //!
 //! The most interesting modules are `cto_pool`, `block_pool`  and `log_pool`.
 //! The module `persistent_memory` provides low-level support.
-//! The module `object_pool` can not work well with Rust.
+//! The module `object_pool` supports transactions through `ObjectPool::transaction()`.
 //!
 //! The struct `Configuration` can be used to create and manage several different pools of persistent memory.
 //!
@@ -57,6 +58,7 @@
 
 
 extern crate alloc;
+extern crate bincode;
 #[macro_use] extern crate bitflags;
 extern crate errno;
 #[cfg(target_os = "windows")] extern crate kernel32;
@@ -94,7 +96,7 @@ mod errors;
 pub mod log_pool;
 
 /// Object pools allow the storage of arbitrary objects, arranged in graphs or lists, with support for Mutexes, Read-Write locks, condition variables and transactions.
-/// Transactions can not work on Rust currently as they use `setjmp/longjmp`.
+/// Transactions are begun without `setjmp/longjmp`; see `ObjectPool::transaction()`.
 pub mod object_pool;
 
 /// Basic abstractions supporting the use of persistent memory.
@@ -103,6 +105,12 @@ pub mod persistent_memory;
 
 use ::block_pool::BlockPool;
 use ::block_pool::BlockPoolsConfiguration;
+use ::cto_pool::CtoPool;
+use ::cto_pool::CtoPoolArc;
+use ::cto_pool::CtoPoolOpenError;
+use ::cto_pool::CtoPoolsConfiguration;
+use ::cto_pool::CtoSafe;
+use ::errors::PmdkError;
 use ::libc::mode_t;
 use ::log_pool::LogPool;
 use ::log_pool::LogPoolsConfiguration;
@@ -110,7 +118,12 @@ use ::object_pool::ObjectPool;
 use ::object_pool::ObjectPoolsConfiguration;
 use ::rust_extra::unlikely;
 use ::std::collections::HashMap;
+use ::std::error;
+use ::std::fmt;
+use ::std::fmt::Display;
+use ::std::fmt::Formatter;
 use ::std::path::Path;
+use ::std::path::PathBuf;
 use ::std::ptr::NonNull;
 
 
@@ -118,5 +131,8 @@ include!("Configuration.rs");
 include!("ExtendedNonNull.rs");
 include!("IsNotNull.rs");
 include!("initialise_memory_functions.rs");
+include!("PoolKind.rs");
+include!("PoolOpenFailure.rs");
 include!("Pools.rs");
+include!("PoolsOpenError.rs");
 include!("ToNonNull.rs");
@@ -0,0 +1,24 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// The default `RecordCodec`; a compact binary encoding using `bincode`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BincodeRecordCodec;
+
+impl RecordCodec for BincodeRecordCodec
+{
+	type Error = ::bincode::Error;
+	
+	#[inline(always)]
+	fn encode<T: Serialize>(&self, value: &T, buffer: &mut Vec<u8>) -> Result<(), Self::Error>
+	{
+		::bincode::serialize_into(buffer, value)
+	}
+	
+	#[inline(always)]
+	fn decode<T: DeserializeOwned>(&self, payload: &[u8]) -> Result<T, Self::Error>
+	{
+		::bincode::deserialize(payload)
+	}
+}
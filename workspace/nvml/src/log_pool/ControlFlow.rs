@@ -0,0 +1,36 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// Returned by the closure passed to `LogPool.walk_with()` to control whether walking continues.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ControlFlow
+{
+	/// Continue walking the log.
+	Continue,
+	
+	/// Stop walking the log.
+	Stop,
+}
+
+impl Default for ControlFlow
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		ControlFlow::Continue
+	}
+}
+
+impl ControlFlow
+{
+	#[inline(always)]
+	fn walk_callback_result(self) -> WalkCallbackResult
+	{
+		match self
+		{
+			ControlFlow::Continue => <*mut PMEMlogpool as PMEMlogpoolExt>::ContinueWalking,
+			ControlFlow::Stop => <*mut PMEMlogpool as PMEMlogpoolExt>::StopWalking,
+		}
+	}
+}
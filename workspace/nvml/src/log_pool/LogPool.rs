@@ -44,7 +44,37 @@ impl LogPool
 		self.0.amount_of_usable_space_in_the_log_pool_in_bytes()
 	}
 	
+	/// Atomically append a buffer to the log (roughly equivalent to `write`).
+	#[inline(always)]
+	pub fn append(&mut self, buffer: &[u8]) -> Result<(), AppendError>
+	{
+		if unlikely(buffer.is_empty())
+		{
+			return Ok(())
+		}
+		
+		self.0.append_atomically(buffer.as_ptr() as *const c_void, buffer.len())
+	}
+	
+	/// Atomically append several buffers to the log as if they were one contiguous buffer (roughly equivalent to `writev`).
+	/// Either all buffers are appended or none are.
+	#[inline(always)]
+	pub fn append_all(&mut self, buffers: &[&[u8]]) -> Result<(), AppendError>
+	{
+		let io_vectors: Vec<iovec> = buffers.iter().filter(|buffer| !buffer.is_empty()).map(|buffer| iovec { iov_base: buffer.as_ptr() as *mut c_void, iov_len: buffer.len() }).collect();
+		
+		let count = io_vectors.len();
+		if unlikely(count == 0)
+		{
+			return Ok(())
+		}
+		assert!(count <= ::std::i32::MAX as usize, "Too many buffers '{}'", count);
+		
+		self.0.append_vector_atomically(io_vectors.as_ptr(), count as u31)
+	}
+	
 	/// Atomically append to the log (roughly equivalent to `write`).
+	/// Prefer the use of `append()`.
 	#[inline(always)]
 	pub fn append_atomically(&mut self, buffer: *const c_void, count: usize) -> Result<(), AppendError>
 	{
@@ -52,6 +82,7 @@ impl LogPool
 	}
 	
 	/// Atomically append to the log using an `iovec` (roughly equivalent to `writev`).
+	/// Prefer the use of `append_all()`.
 	#[inline(always)]
 	pub fn append_vector_atomically(&mut self, buffer: *const iovec, count: u31) -> Result<(), AppendError>
 	{
@@ -72,8 +103,19 @@ impl LogPool
 		self.0.rewind()
 	}
 	
+	/// Walk ('read') the log, calling `callback` for each chunk until it returns `ControlFlow::Stop`.
+	/// chunk_size may be zero, in which case `callback` is called just once with the entire contents of the log.
+	/// Otherwise each chunk is `chunk_size` bytes long, except, possibly, the last.
+	/// If `callback` panics, walking stops and the panic is resumed after the log has been released.
+	#[inline(always)]
+	pub fn walk_with<Callback: FnMut(&[u8]) -> ControlFlow>(&self, chunk_size: usize, callback: Callback)
+	{
+		WalkWith::new(callback).walk(self.0, chunk_size)
+	}
+	
 	/// Walk ('read') the log.
 	/// chunk_size may be zero, in which case `for_each_chunk_callback` is called just once.
+	/// Prefer the use of `walk_with()`.
 	#[inline(always)]
 	pub fn walk(&self, chunk_size: usize, for_each_chunk_callback: ForEachChunkCallback, callback_argument: *mut c_void)
 	{
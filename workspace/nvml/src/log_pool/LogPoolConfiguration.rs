@@ -33,14 +33,26 @@ impl LogPoolConfiguration
 {
 	/// Open or create (if necessary) a log pool.
 	/// Do not use this method directly unless only using one log pool.
-	pub fn open_or_create(&self, log_pool_sets_folder_path: &Path, file_name: &str) -> LogPool
+	pub fn open_or_create(&self, log_pool_sets_folder_path: &Path, file_name: &str) -> Result<LogPool, PoolsOpenError>
 	{
 		let pool_set_file_path = log_pool_sets_folder_path.join(file_name);
+		let error = |failure| PoolsOpenError::pool(PoolKind::Log, file_name, &pool_set_file_path, failure);
 		
 		if likely(pool_set_file_path.exists())
 		{
-			assert!(pool_set_file_path.is_file(), "pool_set_file_path '{:?}' is not a file", pool_set_file_path);
-			LogPool::open(&pool_set_file_path).expect("Could not open LogPool")
+			if unlikely(!pool_set_file_path.is_file())
+			{
+				return Err(error(PoolSetFileIsNotAFile))
+			}
+			
+			match LogPool::validate(&pool_set_file_path)
+			{
+				Err(pmdk_error) => return Err(error(ValidationFailed(pmdk_error))),
+				Ok(false) => return Err(error(Invalid)),
+				Ok(true) => (),
+			}
+			
+			LogPool::open(&pool_set_file_path).map_err(|pmdk_error| error(OpenFailed(pmdk_error)))
 		}
 		else
 		{
@@ -49,11 +61,14 @@ impl LogPoolConfiguration
 				None => 0,
 				Some(pool_size) =>
 				{
-					assert!(pool_size >= PMEMLOG_MIN_POOL, "pool_size '{}' is smaller than PMEMLOG_MIN_POOL '{}'", pool_size, PMEMLOG_MIN_POOL);
+					if unlikely(pool_size < PMEMLOG_MIN_POOL)
+					{
+						return Err(error(PoolSizeTooSmall { pool_size, minimum_pool_size: PMEMLOG_MIN_POOL }))
+					}
 					pool_size
 				},
 			};
-			LogPool::create(&pool_set_file_path, pool_size, self.permissions).expect("Could not create LogPool")
+			LogPool::create(&pool_set_file_path, pool_size, self.permissions).map_err(|pmdk_error| error(CreateFailed(pmdk_error)))
 		}
 	}
 }
@@ -33,17 +33,20 @@ impl LogPoolsConfiguration
 {
 	/// Opens a set of object pools.
 	/// Do not use this method directly unless only using object pools.
-	pub fn open(&self, pools_folder_path: &Path) -> HashMap<String, LogPool>
+	pub fn open(&self, pools_folder_path: &Path) -> Result<HashMap<String, LogPool>, PoolsOpenError>
 	{
 		let log_pool_sets_folder_path = pools_folder_path.join(&self.pool_sets_folder_name);
 		
 		if unlikely(!log_pool_sets_folder_path.exists())
 		{
-			return HashMap::new()
+			return Ok(HashMap::new())
 		}
 		
-		assert!(log_pool_sets_folder_path.is_dir(), "log_pool_sets_folder_path '{:?}' is not a folder", log_pool_sets_folder_path);
+		if unlikely(!log_pool_sets_folder_path.is_dir())
+		{
+			return Err(PoolsOpenError::pool_sets_folder(PoolKind::Log, &log_pool_sets_folder_path))
+		}
 		
-		self.log_pool_configurations.iter().map(|(file_name, log_pool_configuration)| (file_name.to_string(), log_pool_configuration.open_or_create(&log_pool_sets_folder_path, file_name)) ).collect()
+		self.log_pool_configurations.iter().map(|(file_name, log_pool_configuration)| log_pool_configuration.open_or_create(&log_pool_sets_folder_path, file_name).map(|log_pool| (file_name.to_string(), log_pool))).collect()
 	}
 }
@@ -0,0 +1,18 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// Encodes and decodes the payloads of records in a `RecordLog` using Serde.
+pub trait RecordCodec
+{
+	/// Error when encoding or decoding.
+	type Error: error::Error;
+	
+	/// Encode a value, appending it to `buffer`.
+	#[inline(always)]
+	fn encode<T: Serialize>(&self, value: &T, buffer: &mut Vec<u8>) -> Result<(), Self::Error>;
+	
+	/// Decode a value from a record's payload.
+	#[inline(always)]
+	fn decode<T: DeserializeOwned>(&self, payload: &[u8]) -> Result<T, Self::Error>;
+}
@@ -0,0 +1,116 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// Precedes every record appended by a `RecordLog`.
+/// Encoded as little-endian regardless of the CPU.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct RecordHeader
+{
+	payload_length: u32,
+	checksum: u32,
+}
+
+impl RecordHeader
+{
+	const Size: usize = 8;
+	
+	/// CRC-32C (Castagnoli) polynomial, reversed.
+	const Crc32CPolynomial: u32 = 0x82F6_3B78;
+	
+	#[inline(always)]
+	fn new(payload: &[u8]) -> Result<Self, RecordLogAppendError>
+	{
+		let length = payload.len();
+		if unlikely(length > ::std::u32::MAX as usize)
+		{
+			return Err(RecordLogAppendError::RecordTooLarge)
+		}
+		
+		let payload_length = length as u32;
+		
+		Ok
+		(
+			Self
+			{
+				payload_length,
+				checksum: Self::checksum(payload_length, payload),
+			}
+		)
+	}
+	
+	/// Returns `None` if there are too few bytes for a header.
+	#[inline(always)]
+	fn decode(bytes: &[u8]) -> Option<Self>
+	{
+		if unlikely(bytes.len() < Self::Size)
+		{
+			return None
+		}
+		
+		Some
+		(
+			Self
+			{
+				payload_length: Self::decode_u32(&bytes[0 .. 4]),
+				checksum: Self::decode_u32(&bytes[4 .. 8]),
+			}
+		)
+	}
+	
+	#[inline(always)]
+	fn encode(&self) -> [u8; 8]
+	{
+		let mut bytes = [0u8; RecordHeader::Size];
+		Self::encode_u32(self.payload_length, &mut bytes[0 .. 4]);
+		Self::encode_u32(self.checksum, &mut bytes[4 .. 8]);
+		bytes
+	}
+	
+	#[inline(always)]
+	fn is_valid_for(&self, payload: &[u8]) -> bool
+	{
+		self.checksum == Self::checksum(self.payload_length, payload)
+	}
+	
+	/// The checksum covers the payload length as well as the payload, so that a corrupted length is also detected.
+	#[inline(always)]
+	fn checksum(payload_length: u32, payload: &[u8]) -> u32
+	{
+		let mut length_bytes = [0u8; 4];
+		Self::encode_u32(payload_length, &mut length_bytes);
+		
+		!Self::crc32c(Self::crc32c(!0, &length_bytes), payload)
+	}
+	
+	/// Bitwise (table-less) CRC-32C.
+	#[inline(always)]
+	fn crc32c(mut crc: u32, bytes: &[u8]) -> u32
+	{
+		for byte in bytes.iter()
+		{
+			crc ^= *byte as u32;
+			for _ in 0 .. 8
+			{
+				let mask = (crc & 1).wrapping_neg();
+				crc = (crc >> 1) ^ (Self::Crc32CPolynomial & mask);
+			}
+		}
+		crc
+	}
+	
+	#[inline(always)]
+	fn decode_u32(bytes: &[u8]) -> u32
+	{
+		(bytes[0] as u32) | ((bytes[1] as u32) << 8) | ((bytes[2] as u32) << 16) | ((bytes[3] as u32) << 24)
+	}
+	
+	#[inline(always)]
+	fn encode_u32(value: u32, bytes: &mut [u8])
+	{
+		bytes[0] = value as u8;
+		bytes[1] = (value >> 8) as u8;
+		bytes[2] = (value >> 16) as u8;
+		bytes[3] = (value >> 24) as u8;
+	}
+}
@@ -0,0 +1,188 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// A log of length-prefixed, checksummed records on top of a `LogPool`; effectively a persistent write-ahead log.
+///
+/// Each record is appended atomically as a header (payload length and CRC-32C checksum) followed by the payload.
+/// Records are identified by their offset, which is the value of `LogPool.tell()` immediately before they were appended.
+///
+/// Only one `RecordLog` (or other writer) should append to a particular `LogPool` at a time, otherwise the offsets returned by `append()` may be wrong.
+///
+/// Values can be appended and read back using Serde with `append_serialized()` and `read_all()`; they are encoded using `Codec`.
+#[derive(Debug, Clone)]
+pub struct RecordLog<Codec: RecordCodec = BincodeRecordCodec>(LogPool, Codec);
+
+impl RecordLog<BincodeRecordCodec>
+{
+	/// Create a new record log wrapping a log pool.
+	/// The log pool should either be empty or have only ever been appended to by a `RecordLog`.
+	#[inline(always)]
+	pub fn new(log_pool: LogPool) -> Self
+	{
+		Self::with_codec(log_pool, BincodeRecordCodec)
+	}
+}
+
+impl<Codec: RecordCodec> RecordLog<Codec>
+{
+	/// Create a new record log wrapping a log pool, using `codec` to encode and decode serialized values.
+	/// The log pool should either be empty or have only ever been appended to by a `RecordLog` using the same codec.
+	#[inline(always)]
+	pub fn with_codec(log_pool: LogPool, codec: Codec) -> Self
+	{
+		RecordLog(log_pool, codec)
+	}
+	
+	/// The codec used to encode and decode serialized values.
+	#[inline(always)]
+	pub fn codec(&self) -> &Codec
+	{
+		&self.1
+	}
+	
+	/// The underlying log pool.
+	#[inline(always)]
+	pub fn log_pool(&self) -> &LogPool
+	{
+		&self.0
+	}
+	
+	/// Unwrap into the underlying log pool.
+	#[inline(always)]
+	pub fn into_log_pool(self) -> LogPool
+	{
+		self.0
+	}
+	
+	/// Atomically append a record.
+	/// Returns the offset of the record.
+	#[inline(always)]
+	pub fn append(&mut self, payload: &[u8]) -> Result<u64, RecordLogAppendError>
+	{
+		let header = RecordHeader::new(payload)?.encode();
+		
+		let offset = self.offset();
+		
+		let io_vectors =
+		[
+			iovec { iov_base: header.as_ptr() as *mut c_void, iov_len: header.len() },
+			iovec { iov_base: payload.as_ptr() as *mut c_void, iov_len: payload.len() },
+		];
+		let count = if unlikely(payload.is_empty())
+		{
+			1
+		}
+		else
+		{
+			2
+		};
+		self.0.append_vector_atomically(io_vectors.as_ptr(), count).map_err(RecordLogAppendError::Append)?;
+		
+		Ok(offset)
+	}
+	
+	/// Offset at which the next record will be appended.
+	#[inline(always)]
+	pub fn offset(&self) -> u64
+	{
+		let tell = self.0.tell();
+		debug_assert!(tell >= 0, "tell '{}' is negative", tell);
+		tell as u64
+	}
+	
+	/// Walk the records in the log, in the order they were appended, calling `callback` with each record's offset and payload until it returns `ControlFlow::Stop`.
+	/// Walking stops at the first torn or corrupt record; records after it, if any, are not walked.
+	#[inline(always)]
+	pub fn walk_records<Callback: FnMut(u64, &[u8]) -> ControlFlow>(&self, mut callback: Callback) -> RecordLogWalkOutcome
+	{
+		use self::RecordLogWalkOutcome::*;
+		
+		let mut outcome = Complete;
+		
+		self.0.walk_with(0, |mut remaining|
+		{
+			let mut offset = 0u64;
+			
+			while !remaining.is_empty()
+			{
+				let (payload, record_length) = match Self::decode_record(remaining, offset)
+				{
+					Err(damaged) =>
+					{
+						outcome = damaged;
+						break
+					}
+					Ok(record) => record,
+				};
+				
+				if callback(offset, payload) == ControlFlow::Stop
+				{
+					outcome = Stopped;
+					break
+				}
+				
+				offset += record_length as u64;
+				remaining = &remaining[record_length ..];
+			}
+			
+			ControlFlow::Stop
+		});
+		
+		outcome
+	}
+	
+	/// Serialize a value and atomically append it as a record.
+	/// Returns the offset of the record.
+	#[inline(always)]
+	pub fn append_serialized<T: Serialize>(&mut self, value: &T) -> Result<u64, SerializedAppendError<Codec::Error>>
+	{
+		let mut buffer = Vec::new();
+		self.1.encode(value, &mut buffer).map_err(SerializedAppendError::Encode)?;
+		self.append(&buffer).map_err(SerializedAppendError::Append)
+	}
+	
+	/// Lazily read and deserialize all records, in the order they were appended; each record is only read and decoded when the iterator is advanced.
+	/// Records appended after this is called are not read.
+	/// A record that can not be decoded produces an error but does not stop reading.
+	/// If the log ends with a torn or corrupt record, the last item is `Err(SerializedReadError::Damaged)`.
+	#[inline(always)]
+	pub fn read_all<T: DeserializeOwned>(&self) -> RecordLogIterator<T, Codec>
+	{
+		RecordLogIterator::new(self, self.offset())
+	}
+	
+	/// Decodes the record at the start of `remaining`, which is at `offset`, returning its payload and length including its header.
+	#[inline(always)]
+	fn decode_record(remaining: &[u8], offset: u64) -> Result<(&[u8], usize), RecordLogWalkOutcome>
+	{
+		use self::RecordLogWalkOutcome::*;
+		
+		let header = match RecordHeader::decode(remaining)
+		{
+			None => return Err(TornTail { offset }),
+			Some(header) => header,
+		};
+		
+		let record_length = RecordHeader::Size + header.payload_length as usize;
+		if unlikely(remaining.len() < record_length)
+		{
+			return Err(TornTail { offset })
+		}
+		
+		let payload = &remaining[RecordHeader::Size .. record_length];
+		if unlikely(!header.is_valid_for(payload))
+		{
+			return Err(CorruptRecord { offset })
+		}
+		
+		Ok((payload, record_length))
+	}
+	
+	/// Rewind the log, discarding all records.
+	#[inline(always)]
+	pub fn rewind(&mut self)
+	{
+		self.0.rewind()
+	}
+}
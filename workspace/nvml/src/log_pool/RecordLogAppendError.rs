@@ -0,0 +1,52 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// Represents a failure to append a record to a `RecordLog`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RecordLogAppendError
+{
+	/// The record is too large to be framed; its length must fit in a `u32`.
+	RecordTooLarge,
+	
+	/// Failed to append the framed record to the underlying `LogPool`.
+	Append(AppendError),
+}
+
+impl Display for RecordLogAppendError
+{
+	#[inline(always)]
+	fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error>
+	{
+		use self::RecordLogAppendError::*;
+		
+		match *self
+		{
+			RecordTooLarge => write!(formatter, "Record is too large to be framed in a persistent memory log pool"),
+			
+			Append(ref append_error) => Display::fmt(append_error, formatter),
+		}
+	}
+}
+
+impl error::Error for RecordLogAppendError
+{
+	#[inline(always)]
+	fn description(&self) -> &str
+	{
+		"Record Log Append Error"
+	}
+	
+	#[inline(always)]
+	fn cause(&self) -> Option<&error::Error>
+	{
+		use self::RecordLogAppendError::*;
+		
+		match *self
+		{
+			RecordTooLarge => None,
+			
+			Append(ref append_error) => Some(append_error),
+		}
+	}
+}
@@ -0,0 +1,100 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// A lazy iterator over the deserialized records of a `RecordLog`; obtain one with `RecordLog.read_all()`.
+/// Each call to `next()` reads and decodes only the next record, which starts at the offset of the previous one plus its length.
+/// Records appended after the iterator was created are not read.
+pub struct RecordLogIterator<'a, T: DeserializeOwned, Codec: 'a + RecordCodec>
+{
+	record_log: &'a RecordLog<Codec>,
+	offset: u64,
+	end_offset: u64,
+	finished: bool,
+	value: PhantomData<T>,
+}
+
+impl<'a, T: DeserializeOwned, Codec: 'a + RecordCodec> Debug for RecordLogIterator<'a, T, Codec>
+{
+	#[inline(always)]
+	fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error>
+	{
+		write!(formatter, "RecordLogIterator({}, {}, {})", self.offset, self.end_offset, self.finished)
+	}
+}
+
+impl<'a, T: DeserializeOwned, Codec: 'a + RecordCodec> Iterator for RecordLogIterator<'a, T, Codec>
+{
+	type Item = Result<T, SerializedReadError<Codec::Error>>;
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		if unlikely(self.finished || self.offset >= self.end_offset)
+		{
+			self.finished = true;
+			return None
+		}
+		
+		let offset = self.offset;
+		let end_offset = self.end_offset;
+		let codec = self.record_log.codec();
+		
+		let mut next = None;
+		self.record_log.log_pool().walk_with(0, |data|
+		{
+			let end_offset = min(end_offset as usize, data.len());
+			let remaining = if likely((offset as usize) < end_offset)
+			{
+				&data[offset as usize .. end_offset]
+			}
+			else
+			{
+				&[]
+			};
+			
+			next = Some(match RecordLog::<Codec>::decode_record(remaining, offset)
+			{
+				Err(damaged) => Err(damaged),
+				Ok((payload, record_length)) => Ok((codec.decode(payload).map_err(|cause| SerializedReadError::Decode { offset, cause }), record_length)),
+			});
+			
+			ControlFlow::Stop
+		});
+		
+		match next.unwrap_or(Err(RecordLogWalkOutcome::TornTail { offset }))
+		{
+			Err(damaged) =>
+			{
+				self.finished = true;
+				Some(Err(SerializedReadError::Damaged(damaged)))
+			}
+			
+			Ok((value, record_length)) =>
+			{
+				self.offset += record_length as u64;
+				Some(value)
+			}
+		}
+	}
+}
+
+impl<'a, T: DeserializeOwned, Codec: 'a + RecordCodec> FusedIterator for RecordLogIterator<'a, T, Codec>
+{
+}
+
+impl<'a, T: DeserializeOwned, Codec: 'a + RecordCodec> RecordLogIterator<'a, T, Codec>
+{
+	#[inline(always)]
+	fn new(record_log: &'a RecordLog<Codec>, end_offset: u64) -> Self
+	{
+		Self
+		{
+			record_log,
+			offset: 0,
+			end_offset,
+			finished: false,
+			value: PhantomData,
+		}
+	}
+}
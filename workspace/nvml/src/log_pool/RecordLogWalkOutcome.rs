@@ -0,0 +1,46 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// How walking the records in a `RecordLog` ended.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RecordLogWalkOutcome
+{
+	/// Every record in the log was walked.
+	Complete,
+	
+	/// The callback returned `ControlFlow::Stop`.
+	Stopped,
+	
+	/// The log ends with an incomplete record (a header or payload is cut short).
+	/// Walking stopped at this offset, which is where the incomplete record starts.
+	TornTail
+	{
+		/// Offset of the incomplete record.
+		offset: u64,
+	},
+	
+	/// A record's checksum does not match its contents.
+	/// Walking stopped at this offset, which is where the corrupt record starts.
+	CorruptRecord
+	{
+		/// Offset of the corrupt record.
+		offset: u64,
+	},
+}
+
+impl RecordLogWalkOutcome
+{
+	/// Did walking stop because of a torn or corrupt record?
+	#[inline(always)]
+	pub fn is_damaged(&self) -> bool
+	{
+		use self::RecordLogWalkOutcome::*;
+		
+		match *self
+		{
+			TornTail { .. } | CorruptRecord { .. } => true,
+			_ => false,
+		}
+	}
+}
@@ -0,0 +1,275 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// Manages a ring of log pools as one logical log.
+///
+/// When the log pool currently being appended to runs out of space, appending switches to an empty log pool.
+/// Once a consumer has read data, it should call `acknowledge()`; log pools whose contents have been entirely acknowledged are rewound so they can be reused.
+/// If there is no empty log pool to switch to, `append()` fails with `AppendError::OutOfSpace`.
+///
+/// Each log pool starts with an 8 byte generation number, written atomically with the first data appended to it; this is used to recover the order of the log pools when re-opened.
+/// A rewound log pool keeps an 8 byte header recording the highest generation number used, which precedes the generation number when it is reused; this keeps generation numbers increasing after re-opening even if every log pool is empty.
+/// The log pools must not be appended to other than by a `RotatingLog`.
+#[derive(Debug)]
+pub struct RotatingLog
+{
+	log_pools: Vec<LogPool>,
+	generations: Vec<Option<u64>>,
+	data_starts: Vec<u64>,
+	current: Option<usize>,
+	next_generation: u64,
+}
+
+impl RotatingLog
+{
+	const GenerationHeaderSize: u64 = 8;
+	
+	const HighWaterFlag: u64 = 1 << 63;
+	
+	/// Opens (or creates) all the log pools in `log_pools_configuration` and uses them as a rotating log.
+	/// Fails with `PoolOpenFailure::NoPoolsConfigured` if there are no log pools.
+	#[inline(always)]
+	pub fn open(log_pools_configuration: &LogPoolsConfiguration, pools_folder_path: &Path) -> Result<Self, PoolsOpenError>
+	{
+		let mut log_pools: Vec<(String, LogPool)> = log_pools_configuration.open(pools_folder_path)?.into_iter().collect();
+		if unlikely(log_pools.is_empty())
+		{
+			return Err(PoolsOpenError::no_pools_configured(PoolKind::Log, pools_folder_path))
+		}
+		
+		log_pools.sort_by(|left, right| left.0.cmp(&right.0));
+		Ok(Self::new(log_pools.into_iter().map(|(_, log_pool)| log_pool).collect()))
+	}
+	
+	/// Uses `log_pools` as a rotating log.
+	/// Panics if `log_pools` is empty.
+	pub fn new(log_pools: Vec<LogPool>) -> Self
+	{
+		assert!(!log_pools.is_empty(), "log_pools can not be empty");
+		
+		let headers: Vec<(Option<u64>, Option<u64>, u64)> = log_pools.iter().map(Self::read_headers).collect();
+		let generations: Vec<Option<u64>> = headers.iter().map(|&(_, generation, _)| generation).collect();
+		let data_starts: Vec<u64> = headers.iter().map(|&(_, _, data_start)| data_start).collect();
+		
+		let current = generations.iter().enumerate().filter_map(|(index, generation)| generation.map(|generation| (generation, index))).max().map(|(_, index)| index);
+		
+		let next_generation = match headers.iter().flat_map(|&(high_water, generation, _)| high_water.into_iter().chain(generation)).max()
+		{
+			None => 0,
+			Some(highest_generation) => highest_generation + 1,
+		};
+		
+		Self
+		{
+			log_pools,
+			generations,
+			data_starts,
+			current,
+			next_generation,
+		}
+	}
+	
+	/// Atomically append to the log.
+	/// Returns the position of the start of the appended data.
+	pub fn append(&mut self, buffer: &[u8]) -> Result<RotatingLogPosition, AppendError>
+	{
+		if let Some(index) = self.current
+		{
+			let generation = self.generations[index].unwrap();
+			let offset = self.data_length(index);
+			
+			match self.log_pools[index].append(buffer)
+			{
+				Ok(()) => return Ok(RotatingLogPosition { generation, offset }),
+				Err(OutOfSpace) => (),
+				Err(error) => return Err(error),
+			}
+		}
+		
+		let index = match self.generations.iter().position(Option::is_none)
+		{
+			None => return Err(OutOfSpace),
+			Some(index) => index,
+		};
+		
+		let generation = self.next_generation;
+		let generation_header = Self::encode_generation(generation);
+		let data_start = self.log_pools[index].tell() as u64 + Self::GenerationHeaderSize;
+		self.log_pools[index].append_all(&[&generation_header[..], buffer])?;
+		
+		self.generations[index] = Some(generation);
+		self.data_starts[index] = data_start;
+		self.current = Some(index);
+		self.next_generation = generation + 1;
+		
+		Ok(RotatingLogPosition { generation, offset: 0 })
+	}
+	
+	/// Acknowledge that all data before `up_to` has been consumed.
+	/// Log pools whose data is entirely before `up_to` are rewound so they can be reused.
+	/// Each rewound log pool keeps a header recording the highest generation used; they are rewound in generation order, so that (unless there is only one log pool) this survives a crash part way through.
+	/// Fails only if a rewound log pool is too small to hold this header.
+	pub fn acknowledge(&mut self, up_to: RotatingLogPosition) -> Result<(), AppendError>
+	{
+		let mut consumed: Vec<(u64, usize)> = (0 .. self.log_pools.len()).filter_map(|index| match self.generations[index]
+		{
+			None => None,
+			Some(generation) => if generation < up_to.generation || (generation == up_to.generation && up_to.offset >= self.data_length(index))
+			{
+				Some((generation, index))
+			}
+			else
+			{
+				None
+			},
+		}).collect();
+		consumed.sort();
+		
+		if consumed.is_empty()
+		{
+			return Ok(())
+		}
+		
+		let high_water_header = Self::encode_generation((self.next_generation - 1) | Self::HighWaterFlag);
+		for (_, index) in consumed
+		{
+			self.log_pools[index].rewind();
+			self.generations[index] = None;
+			if self.current == Some(index)
+			{
+				self.current = None;
+			}
+			
+			self.log_pools[index].append(&high_water_header)?;
+		}
+		
+		Ok(())
+	}
+	
+	/// Walk ('read') the entire log, in the order it was appended, across all log pools.
+	/// See `walk_from()`.
+	#[inline(always)]
+	pub fn walk_with<Callback: FnMut(RotatingLogPosition, &[u8]) -> ControlFlow>(&self, chunk_size: usize, callback: Callback)
+	{
+		self.walk_from(RotatingLogPosition::default(), chunk_size, callback)
+	}
+	
+	/// Walk ('read') the log from `from`, in the order it was appended, across all log pools, calling `callback` with the position of each chunk until it returns `ControlFlow::Stop`.
+	/// chunk_size may be zero, in which case `callback` is called once for each log pool's data.
+	/// Otherwise each chunk is `chunk_size` bytes long, except, possibly, the last of each log pool.
+	pub fn walk_from<Callback: FnMut(RotatingLogPosition, &[u8]) -> ControlFlow>(&self, from: RotatingLogPosition, chunk_size: usize, mut callback: Callback)
+	{
+		let mut indices_in_generation_order: Vec<(u64, usize)> = self.generations.iter().enumerate().filter_map(|(index, generation)| generation.map(|generation| (generation, index))).filter(|&(generation, _)| generation >= from.generation).collect();
+		indices_in_generation_order.sort();
+		
+		let mut stop = false;
+		for (generation, index) in indices_in_generation_order
+		{
+			let start_offset = if generation == from.generation
+			{
+				from.offset
+			}
+			else
+			{
+				0
+			};
+			
+			self.log_pools[index].walk_with(0, |data|
+			{
+				let data_start = (self.data_starts[index] + start_offset) as usize;
+				if unlikely(data.len() <= data_start)
+				{
+					return ControlFlow::Stop
+				}
+				let data = &data[data_start ..];
+				
+				let chunk_size = if chunk_size == 0
+				{
+					data.len()
+				}
+				else
+				{
+					chunk_size
+				};
+				
+				let mut offset = start_offset;
+				for chunk in data.chunks(chunk_size)
+				{
+					if callback(RotatingLogPosition { generation, offset }, chunk) == ControlFlow::Stop
+					{
+						stop = true;
+						break
+					}
+					offset += chunk.len() as u64;
+				}
+				
+				ControlFlow::Stop
+			});
+			
+			if stop
+			{
+				return
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn data_length(&self, index: usize) -> u64
+	{
+		let tell = self.log_pools[index].tell() as u64;
+		tell.saturating_sub(self.data_starts[index])
+	}
+	
+	/// Returns the high-water generation (if rewound), the generation (if in use) and the offset of the data.
+	#[inline(always)]
+	fn read_headers(log_pool: &LogPool) -> (Option<u64>, Option<u64>, u64)
+	{
+		if log_pool.tell() < Self::GenerationHeaderSize as i64
+		{
+			return (None, None, 0)
+		}
+		
+		let mut headers = [0u8; 16];
+		let mut length = 0;
+		log_pool.walk_with(headers.len(), |chunk|
+		{
+			length = chunk.len().min(headers.len());
+			headers[.. length].copy_from_slice(&chunk[.. length]);
+			ControlFlow::Stop
+		});
+		
+		let first = Self::decode_generation(&headers[0 .. 8]);
+		if first & Self::HighWaterFlag == 0
+		{
+			return (None, Some(first), Self::GenerationHeaderSize)
+		}
+		
+		let high_water = Some(first & !Self::HighWaterFlag);
+		if length < headers.len()
+		{
+			(high_water, None, Self::GenerationHeaderSize)
+		}
+		else
+		{
+			(high_water, Some(Self::decode_generation(&headers[8 .. 16])), 2 * Self::GenerationHeaderSize)
+		}
+	}
+	
+	#[inline(always)]
+	fn encode_generation(generation: u64) -> [u8; 8]
+	{
+		let mut bytes = [0u8; 8];
+		for (index, byte) in bytes.iter_mut().enumerate()
+		{
+			*byte = (generation >> (index * 8)) as u8;
+		}
+		bytes
+	}
+	
+	#[inline(always)]
+	fn decode_generation(bytes: &[u8]) -> u64
+	{
+		bytes.iter().take(8).enumerate().fold(0, |generation, (index, byte)| generation | ((*byte as u64) << (index * 8)))
+	}
+}
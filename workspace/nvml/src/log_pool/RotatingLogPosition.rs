@@ -0,0 +1,16 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// A position in a `RotatingLog`.
+/// Positions are ordered in the same order as the bytes they refer to were appended.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Deserialize, Serialize)]
+pub struct RotatingLogPosition
+{
+	/// Generation of the log pool; incremented every time the rotating log switches to a different log pool.
+	pub generation: u64,
+	
+	/// Offset in bytes within the log pool's data.
+	pub offset: u64,
+}
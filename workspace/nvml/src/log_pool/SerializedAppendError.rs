@@ -0,0 +1,52 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// Represents a failure to append a serialized value to a `RecordLog`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SerializedAppendError<EncodeError>
+{
+	/// Failed to encode the value.
+	Encode(EncodeError),
+	
+	/// Failed to append the encoded value.
+	Append(RecordLogAppendError),
+}
+
+impl<EncodeError: Display> Display for SerializedAppendError<EncodeError>
+{
+	#[inline(always)]
+	fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error>
+	{
+		use self::SerializedAppendError::*;
+		
+		match *self
+		{
+			Encode(ref encode_error) => Display::fmt(encode_error, formatter),
+			
+			Append(ref append_error) => Display::fmt(append_error, formatter),
+		}
+	}
+}
+
+impl<EncodeError: error::Error> error::Error for SerializedAppendError<EncodeError>
+{
+	#[inline(always)]
+	fn description(&self) -> &str
+	{
+		"Serialized Append Error"
+	}
+	
+	#[inline(always)]
+	fn cause(&self) -> Option<&error::Error>
+	{
+		use self::SerializedAppendError::*;
+		
+		match *self
+		{
+			Encode(ref encode_error) => Some(encode_error),
+			
+			Append(ref append_error) => Some(append_error),
+		}
+	}
+}
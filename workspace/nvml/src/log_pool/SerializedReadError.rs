@@ -0,0 +1,60 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+/// Represents a failure to read a serialized value from a `RecordLog`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SerializedReadError<DecodeError>
+{
+	/// Failed to decode the record at this offset.
+	Decode
+	{
+		/// Offset of the record.
+		offset: u64,
+		
+		/// Cause.
+		cause: DecodeError,
+	},
+	
+	/// The log ends with a torn or corrupt record; see `RecordLogWalkOutcome`.
+	/// Always the last item returned by the iterator from `RecordLog.read_all()`.
+	Damaged(RecordLogWalkOutcome),
+}
+
+impl<DecodeError: Display> Display for SerializedReadError<DecodeError>
+{
+	#[inline(always)]
+	fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error>
+	{
+		use self::SerializedReadError::*;
+		
+		match *self
+		{
+			Decode { offset, ref cause } => write!(formatter, "Could not decode record at offset '{}': {}", offset, cause),
+			
+			Damaged(outcome) => write!(formatter, "Record log is damaged: {:?}", outcome),
+		}
+	}
+}
+
+impl<DecodeError: error::Error> error::Error for SerializedReadError<DecodeError>
+{
+	#[inline(always)]
+	fn description(&self) -> &str
+	{
+		"Serialized Read Error"
+	}
+	
+	#[inline(always)]
+	fn cause(&self) -> Option<&error::Error>
+	{
+		use self::SerializedReadError::*;
+		
+		match *self
+		{
+			Decode { ref cause, .. } => Some(cause),
+			
+			Damaged(_) => None,
+		}
+	}
+}
@@ -0,0 +1,62 @@
+// This file is part of nvml. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT. No part of nvml, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of nvml. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/nvml/master/COPYRIGHT.
+
+
+struct WalkWith<Callback: FnMut(&[u8]) -> ControlFlow>
+{
+	callback: Callback,
+	panic: Option<Box<Any + Send + 'static>>,
+}
+
+impl<Callback: FnMut(&[u8]) -> ControlFlow> WalkWith<Callback>
+{
+	#[inline(always)]
+	fn new(callback: Callback) -> Self
+	{
+		Self
+		{
+			callback,
+			panic: None,
+		}
+	}
+	
+	/// Panics can not be allowed to unwind across the C stack frames of `pmemlog_walk()`; they are caught, walking is stopped and the panic is resumed once `pmemlog_walk()` has returned.
+	#[inline(always)]
+	fn walk(mut self, log_pool: *mut PMEMlogpool, chunk_size: usize)
+	{
+		log_pool.walk(chunk_size, Self::for_each_chunk_callback, &mut self as *mut Self as *mut c_void);
+		
+		if let Some(panic) = self.panic
+		{
+			resume_unwind(panic)
+		}
+	}
+	
+	unsafe extern "C" fn for_each_chunk_callback(chunk: *const c_void, length: usize, callback_argument: *mut c_void) -> WalkCallbackResult
+	{
+		debug_assert!(callback_argument.is_not_null(), "callback_argument can not be null");
+		
+		let this = &mut * (callback_argument as *mut Self);
+		
+		let chunk: &[u8] = if unlikely(length == 0)
+		{
+			&[]
+		}
+		else
+		{
+			debug_assert!(chunk.is_not_null(), "chunk can not be null");
+			from_raw_parts(chunk as *const u8, length)
+		};
+		
+		let callback = &mut this.callback;
+		match catch_unwind(AssertUnwindSafe(|| callback(chunk)))
+		{
+			Ok(control_flow) => control_flow.walk_callback_result(),
+			Err(panic) =>
+			{
+				this.panic = Some(panic);
+				<*mut PMEMlogpool as PMEMlogpoolExt>::StopWalking
+			}
+		}
+	}
+}
@@ -13,13 +13,31 @@ use ::libc::iovec;
 use ::libc::mode_t;
 use ::libc::size_t;
 use ::Configuration;
+use ::PoolKind;
+use ::PoolOpenFailure::*;
+use ::PoolsOpenError;
 use ::nvml_sys::*;
 use ::rust_extra::likely;
 use ::rust_extra::unlikely;
 use ::rust_extra::u31;
+use ::serde::Serialize;
+use ::serde::de::DeserializeOwned;
+use ::std::any::Any;
+use ::std::cmp::min;
 use ::std::collections::HashMap;
+use ::std::error;
+use ::std::fmt;
+use ::std::fmt::Debug;
+use ::std::fmt::Display;
+use ::std::fmt::Formatter;
+use ::std::iter::FusedIterator;
+use ::std::marker::PhantomData;
 #[cfg(unix)] use ::std::os::unix::ffi::OsStrExt;
+use ::std::panic::AssertUnwindSafe;
+use ::std::panic::catch_unwind;
+use ::std::panic::resume_unwind;
 use ::std::path::Path;
+use ::std::slice::from_raw_parts;
 use ::std::sync::Arc;
 use ::syscall_alt::constants::E::EDEADLK;
 use ::syscall_alt::constants::E::EINVAL;
@@ -28,6 +46,8 @@ use ::syscall_alt::constants::E::EROFS;
 
 
 include!("AppendError.rs");
+include!("BincodeRecordCodec.rs");
+include!("ControlFlow.rs");
 include!("ForEachChunkCallback.rs");
 include!("initialise_memory_functions.rs");
 include!("LogPool.rs");
@@ -36,4 +56,15 @@ include!("LogPoolDropWrapper.rs");
 include!("LogPoolPathExt.rs");
 include!("LogPoolsConfiguration.rs");
 include!("PMEMlogpoolExt.rs");
+include!("RecordCodec.rs");
+include!("RecordHeader.rs");
+include!("RecordLog.rs");
+include!("RecordLogAppendError.rs");
+include!("RecordLogIterator.rs");
+include!("RecordLogWalkOutcome.rs");
+include!("RotatingLog.rs");
+include!("RotatingLogPosition.rs");
+include!("SerializedAppendError.rs");
+include!("SerializedReadError.rs");
 include!("WalkCallbackResult.rs");
+include!("WalkWith.rs");
@@ -0,0 +1,35 @@
+// This file is part of dpdk. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/dpdk/master/COPYRIGHT. No part of dpdk, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of dpdk. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/dpdk/master/COPYRIGHT.
+
+
+/// Two different `Persistable` types were registered with the same `TypeNumber`.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct DuplicateTypeNumberError
+{
+	/// The duplicated type number.
+	pub type_number: TypeNumber,
+	
+	/// The name of the type registered first.
+	pub registered_type_name: &'static str,
+	
+	/// The name of the type whose registration failed.
+	pub duplicate_type_name: &'static str,
+}
+
+impl Display for DuplicateTypeNumberError
+{
+	#[inline(always)]
+	fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error>
+	{
+		write!(formatter, "Type number '{}' of '{}' is already used by '{}'", self.type_number, self.duplicate_type_name, self.registered_type_name)
+	}
+}
+
+impl error::Error for DuplicateTypeNumberError
+{
+	#[inline(always)]
+	fn description(&self) -> &str
+	{
+		"Duplicate Type Number Error"
+	}
+}
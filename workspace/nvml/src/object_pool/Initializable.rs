@@ -7,7 +7,6 @@ pub trait Initializable
 {
 	/// # Arguments
 	/// - pointer_to_uninitialized_memory_to_use_for_fields is always non-null
-	/// - object_pool is always non-null
 	#[inline(always)]
-	unsafe fn initialize(pointer_to_uninitialized_memory_to_use_for_fields: *mut Self, object_pool: *mut PMEMobjpool);
+	unsafe fn initialize(pointer_to_uninitialized_memory_to_use_for_fields: *mut Self, object_pool: &ObjectPool);
 }
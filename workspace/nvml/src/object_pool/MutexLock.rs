@@ -26,19 +26,6 @@ impl<'a, T: Persistable> MutexLock<'a, T>
 		}
 	}
 	
-	/// Obtain a mutex lock within a transaction.
-	#[allow(unused_variables)]
-	#[inline(always)]
-	pub fn mutex_in_transaction(self, transaction: Transaction)
-	{
-		let result = unsafe { pmemobj_tx_lock(pobj_tx_param_TX_PARAM_MUTEX, self.mutex as *mut c_void) };
-		if likely(result == 0)
-		{
-			return;
-		}
-		Self::lock_error_handling(result);
-	}
-	
 	/// Obtain a mutex lock.
 	#[inline(always)]
 	pub fn mutex(self) -> MutexUnlock<'a, T>
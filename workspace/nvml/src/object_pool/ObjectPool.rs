@@ -60,6 +60,15 @@ impl ObjectPool
 		self.0.write_bytes_then_persist(address, count, value)
 	}
 	
+	/// Runs `work` in a transaction on this pool.
+	/// The transaction commits if `work` returns `Ok`, and aborts if `work` returns `Err` or panics; a panic is resumed after the abort.
+	/// Transactions may be nested; aborting an inner transaction also aborts the outer ones.
+	#[inline(always)]
+	pub fn transaction<Value, WorkError, Work: FnOnce(&TransactionScope) -> Result<Value, WorkError>>(&self, work: Work) -> Result<Value, TransactionError<WorkError>>
+	{
+		TransactionScope::run(self, work)
+	}
+	
 	/// Obtain a persist on drop object to make sure persistence occurs after a number of write operations.
 	#[inline(always)]
 	pub fn persist_on_drop<'a>(&'a self, address: *mut c_void) -> ObjectPoolPersistOnDrop<'a>
@@ -79,34 +88,153 @@ impl ObjectPool
 	#[inline(always)]
 	pub fn first_of_type<T: Persistable>(&self) -> Option<PersistentObject<T>>
 	{
-		let first = self.first();
-		if unlikely(first.is_null())
+		// Safe, as only the first object is read; no object is held on to across an allocation or free.
+		unsafe { self.iter_of_type::<T>() }.next()
+	}
+	
+	/// Iterate over every persisted object of type in pool.
+	///
+	/// # Safety
+	///
+	/// Objects must not be allocated or freed in this pool, by any thread or through any clone of this `ObjectPool`, whilst iterating.
+	#[inline(always)]
+	pub unsafe fn iter_of_type<'a, T: Persistable>(&'a self) -> PersistentObjectIterator<'a, T>
+	{
+		PersistentObjectIterator::new(self)
+	}
+	
+	/// Iterate over every persisted object in pool, yielding its type number, OID and usable size in bytes.
+	///
+	/// # Safety
+	///
+	/// As for `iter_of_type()`.
+	#[inline(always)]
+	pub unsafe fn iter_all<'a>(&'a self) -> ObjectPoolIterator<'a>
+	{
+		ObjectPoolIterator::new(self)
+	}
+	
+	/// Number of persisted objects in pool for each type number present.
+	///
+	/// # Safety
+	///
+	/// As for `iter_of_type()`, whilst counting.
+	#[inline(always)]
+	pub unsafe fn count_by_type(&self) -> HashMap<TypeNumber, usize>
+	{
+		let mut count_by_type = HashMap::new();
+		for (type_number, _oid, _usable_size) in self.iter_all()
 		{
-			None
+			*count_by_type.entry(type_number).or_insert(0) += 1;
 		}
-		else if likely(first.type_number() == T::TypeNumber)
+		count_by_type
+	}
+	
+	/// The root object of this pool, allocating it and constructing it with `T::initialize()` if it does not yet exist.
+	/// `T::initialize()` is also called if `T` is now bigger than the existing root object, which is then grown.
+	/// `T::TypeNumber` must be zero.
+	#[inline(always)]
+	pub fn root<T: Persistable>(&self, arguments: &mut T::Arguments) -> Result<PersistentObject<T>, PmdkError>
+	{
+		let mut root = PersistentObject::null();
+		root.allocate_uninitialized_and_construct_object_root_object(self, arguments)?;
+		Ok(root)
+	}
+	
+	/// Allocate a persistent object and construct it with `T::initialize()`.
+	/// The persistent object is only valid whilst this pool, or a clone of it, is open.
+	#[inline(always)]
+	pub fn allocate<T: Persistable>(&self, arguments: &mut T::Arguments) -> Result<PersistentObject<T>, PmdkError>
+	{
+		let mut persistent_object = PersistentObject::null();
+		persistent_object.allocate_uninitialized_and_construct_object(self, arguments)?;
+		Ok(persistent_object)
+	}
+	
+	/// Allocate a zeroed persistent object.
+	/// The persistent object is only valid whilst this pool, or a clone of it, is open.
+	///
+	/// # Safety
+	///
+	/// `T::initialize()` is not called, so all-zero bytes must be a valid `T`.
+	#[inline(always)]
+	pub unsafe fn zalloc<T: Persistable>(&self) -> Result<PersistentObject<T>, PmdkError>
+	{
+		let mut oid = OID_NULL;
+		let result = pmemobj_zalloc(self.0, &mut oid, size::<T>(), T::TypeNumber);
+		debug_assert!(result == 0 || result == -1, "result was '{}'", result);
+		
+		if likely(result == 0)
 		{
-			Some(PersistentObject::new(first))
+			Ok(PersistentObject::new(oid))
 		}
 		else
 		{
-			let mut previous = first;
-			loop
-			{
-				let next = unsafe { pmemobj_next(previous) };
-				if unlikely(next.is_null())
-				{
-					return None
-				}
-				else if likely(next.type_number() == T::TypeNumber)
-				{
-					return Some(PersistentObject::new(next))
-				}
-				previous = next;
-			}
+			PmdkError::obj("pmemobj_zalloc")
 		}
 	}
 	
+	/// Reserve a persistent object and construct it with `T::initialize()`, without it becoming part of the pool until published.
+	/// Nothing else can see a reserved object, so it can be modified as volatile memory before being added to `ObjectPoolActions` and published with `publish()`.
+	/// Requires libpmemobj 1.4 or later.
+	#[inline(always)]
+	pub fn reserve<'a, T: Persistable>(&'a self, arguments: &mut T::Arguments) -> Result<ReservedObject<'a, T>, PmdkError>
+	{
+		ReservedObject::reserve(self, arguments)
+	}
+	
+	/// A new, empty batch of actions for `publish()`.
+	#[inline(always)]
+	pub fn actions<'a>(&'a self) -> ObjectPoolActions<'a>
+	{
+		ObjectPoolActions::new(self)
+	}
+	
+	/// Atomically publish a batch of reservations and deferred writes; either all take effect or none do.
+	/// If publishing fails, every reservation in `actions` is cancelled.
+	/// Requires libpmemobj 1.4 or later.
+	#[inline(always)]
+	pub fn publish<'a>(&'a self, actions: ObjectPoolActions<'a>) -> Result<(), PmdkError>
+	{
+		assert!(actions.object_pool.0 == self.0, "actions are for a different object pool");
+		
+		actions.publish()
+	}
+	
+	/// Resize a persistent object to `size` bytes, for a `T` with trailing variable-length data.
+	/// The persistent object may move; its contents are preserved up to the smaller of the old and new sizes.
+	/// `size` must be at least `T::size()`.
+	#[inline(always)]
+	pub fn realloc<T: Persistable>(&self, persistent_object: &mut PersistentObject<T>, size: size_t) -> Result<(), PmdkError>
+	{
+		assert!(persistent_object.is_not_null(), "persistent_object is null");
+		assert!(persistent_object.object_pool() == self.0, "persistent_object is not in this pool");
+		assert!(size >= T::size(), "size '{}' is less than T::size() '{}'", size, T::size());
+		debug_assert!(size <= PMEMOBJ_MAX_ALLOC_SIZE, "size '{}' exceeds PMEMOBJ_MAX_ALLOC_SIZE '{}'", size, PMEMOBJ_MAX_ALLOC_SIZE);
+		
+		let result = unsafe { pmemobj_realloc(self.0, &mut persistent_object.oid, size, T::TypeNumber) };
+		debug_assert!(result == 0 || result == -1, "result was '{}'", result);
+		
+		if likely(result == 0)
+		{
+			Ok(())
+		}
+		else
+		{
+			PmdkError::obj("pmemobj_realloc")
+		}
+	}
+	
+	/// Free a persistent object, which is then null.
+	/// Freeing a null persistent object does nothing.
+	#[inline(always)]
+	pub fn free<T: Persistable>(&self, persistent_object: &mut PersistentObject<T>)
+	{
+		debug_assert!(persistent_object.is_null() || persistent_object.object_pool() == self.0, "persistent_object is not in this pool");
+		
+		persistent_object.free()
+	}
+	
 	/// Size in bytes of root object.
 	/// Returns None if there is no root object.
 	/// Never returns Some(0).
@@ -0,0 +1,127 @@
+// This file is part of dpdk. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/dpdk/master/COPYRIGHT. No part of dpdk, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of dpdk. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/dpdk/master/COPYRIGHT.
+
+
+/// A batch of reservations and deferred writes to be made visible atomically by `ObjectPool::publish()`.
+/// Obtain one with `ObjectPool::actions()`.
+/// Dropping it without publishing it cancels every reservation it holds.
+/// Requires libpmemobj 1.4 or later.
+pub struct ObjectPoolActions<'a>
+{
+	object_pool: &'a ObjectPool,
+	actions: Vec<pobj_action>,
+}
+
+impl<'a> Drop for ObjectPoolActions<'a>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		if unlikely(!self.actions.is_empty())
+		{
+			unsafe { pmemobj_cancel(self.object_pool.0, self.actions.as_mut_ptr(), self.actions.len()) }
+		}
+	}
+}
+
+impl<'a> Debug for ObjectPoolActions<'a>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "ObjectPoolActions({:?}, {})", self.object_pool, self.actions.len())
+	}
+}
+
+impl<'a> ObjectPoolActions<'a>
+{
+	/// Number of actions in this batch.
+	#[inline(always)]
+	pub fn len(&self) -> usize
+	{
+		self.actions.len()
+	}
+	
+	/// Is this batch empty?
+	#[inline(always)]
+	pub fn is_empty(&self) -> bool
+	{
+		self.actions.is_empty()
+	}
+	
+	/// Adds a reserved object to this batch, persisting its contents.
+	/// Returns the persistent object it will become once published; it must not be used by other threads until then.
+	#[inline(always)]
+	pub fn reservation<T: Persistable>(&mut self, reserved_object: ReservedObject<'a, T>) -> PersistentObject<T>
+	{
+		assert!(reserved_object.object_pool.0 == self.object_pool.0, "reserved_object is from a different object pool");
+		
+		let persistent_object = reserved_object.persistent_object();
+		self.actions.push(reserved_object.into_action());
+		persistent_object
+	}
+	
+	/// Defers writing `value` to `address` until published.
+	///
+	/// # Safety
+	///
+	/// `address` must be a valid, aligned pointer inside an object in this pool, and that object must not be freed before this batch is published or dropped.
+	/// Nothing else may write to `address` until then.
+	#[inline(always)]
+	pub unsafe fn set_value(&mut self, address: *mut u64, value: u64)
+	{
+		debug_assert!(address.is_not_null(), "address is null");
+		debug_assert!(pmemobj_oid(address as *const c_void).is_not_null(), "address is not inside an object in this pool");
+		
+		self.actions.push(zeroed());
+		let action = self.actions.last_mut().unwrap();
+		pmemobj_set_value(self.object_pool.0, action, address, value)
+	}
+	
+	/// Defers writing `value` to the persistent object field at `address` until published.
+	///
+	/// # Safety
+	///
+	/// As for `set_value()`; `address` must point to a `PersistentObject<T>` field inside an object in this pool.
+	#[inline(always)]
+	pub unsafe fn set_persistent_object<T: Persistable>(&mut self, address: *mut PersistentObject<T>, value: PersistentObject<T>)
+	{
+		debug_assert!(address.is_not_null(), "address is null");
+		
+		let oid = &mut (*address).oid;
+		self.set_value(&mut oid.pool_uuid_lo, value.oid.pool_uuid_lo);
+		self.set_value(&mut oid.off, value.oid.off);
+	}
+	
+	#[inline(always)]
+	fn new(object_pool: &'a ObjectPool) -> Self
+	{
+		Self
+		{
+			object_pool,
+			actions: Vec::new(),
+		}
+	}
+	
+	#[inline(always)]
+	fn publish(mut self) -> Result<(), PmdkError>
+	{
+		if unlikely(self.actions.is_empty())
+		{
+			return Ok(())
+		}
+		
+		let result = unsafe { pmemobj_publish(self.object_pool.0, self.actions.as_mut_ptr(), self.actions.len()) };
+		debug_assert!(result == 0 || result == -1, "result was '{}'", result);
+		
+		if likely(result == 0)
+		{
+			self.actions.clear();
+			Ok(())
+		}
+		else
+		{
+			PmdkError::obj("pmemobj_publish")
+		}
+	}
+}
@@ -50,22 +50,38 @@ impl ObjectPoolConfiguration
 {
 	/// Open or create (if necessary) an object pool.
 	/// Do not use this method directly unless only using one object pool.
-	pub fn open_or_create(&self, object_pool_sets_folder_path: &Path, file_name: &str) -> ObjectPool
+	pub fn open_or_create(&self, object_pool_sets_folder_path: &Path, file_name: &str) -> Result<ObjectPool, PoolsOpenError>
 	{
+		let pool_set_file_path = object_pool_sets_folder_path.join(file_name);
+		let error = |failure| PoolsOpenError::pool(PoolKind::Object, file_name, &pool_set_file_path, failure);
+		
 		let layout_name = match self.layout_name
 		{
 			None => file_name,
 			Some(ref layout_name) => layout_name,
 		};
 		
-		assert!(layout_name.len() + 1 <= PMEMOBJ_MAX_LAYOUT, "layout_name '{}' can not be larger than PMEMOBJ_MAX_LAYOUT '{}' - 1", layout_name, PMEMOBJ_MAX_LAYOUT);
+		if unlikely(layout_name.len() + 1 > PMEMOBJ_MAX_LAYOUT || layout_name.as_bytes().contains(&0))
+		{
+			return Err(error(InvalidLayoutName))
+		}
 		
-		let pool_set_file_path = object_pool_sets_folder_path.join(file_name);
 		let layout_name = Some(layout_name);
 		let object_pool = if likely(pool_set_file_path.exists())
 		{
-			assert!(pool_set_file_path.is_file(), "pool_set_file_path '{:?}' is not a file", pool_set_file_path);
-			ObjectPool::open(&pool_set_file_path, layout_name).expect("Could not open ObjectPool")
+			if unlikely(!pool_set_file_path.is_file())
+			{
+				return Err(error(PoolSetFileIsNotAFile))
+			}
+			
+			match ObjectPool::validate(&pool_set_file_path, layout_name)
+			{
+				Err(pmdk_error) => return Err(error(ValidationFailed(pmdk_error))),
+				Ok(false) => return Err(error(Invalid)),
+				Ok(true) => (),
+			}
+			
+			ObjectPool::open(&pool_set_file_path, layout_name).map_err(|pmdk_error| error(OpenFailed(pmdk_error)))?
 		}
 		else
 		{
@@ -74,15 +90,18 @@ impl ObjectPoolConfiguration
 				None => 0,
 				Some(pool_size) =>
 				{
-					assert!(pool_size >= PMEMOBJ_MIN_POOL, "pool_size '{}' is smaller than PMEMOBJ_MIN_POOL '{}'", pool_size, PMEMOBJ_MIN_POOL);
+					if unlikely(pool_size < PMEMOBJ_MIN_POOL)
+					{
+						return Err(error(PoolSizeTooSmall { pool_size, minimum_pool_size: PMEMOBJ_MIN_POOL }))
+					}
 					pool_size
 				},
 			};
-			ObjectPool::create(&pool_set_file_path, layout_name, pool_size, self.permissions).expect("Could not create ObjectPool")
+			ObjectPool::create(&pool_set_file_path, layout_name, pool_size, self.permissions).map_err(|pmdk_error| error(CreateFailed(pmdk_error)))?
 		};
 		
 		object_pool.set_transaction_debug_skip_expensive_checks(self.skip_expensive_debug_checks);
 		object_pool.set_transaction_cache_size_and_threshold(self.transaction_cache_size, self.transaction_cache_threshold);
-		object_pool
+		Ok(object_pool)
 	}
 }
@@ -0,0 +1,36 @@
+// This file is part of dpdk. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/dpdk/master/COPYRIGHT. No part of dpdk, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of dpdk. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/dpdk/master/COPYRIGHT.
+
+
+/// An iterator over every object in an object pool, yielding its type number, OID and usable size in bytes.
+/// Objects must not be allocated or freed in the object pool whilst iterating, which is why obtaining one is `unsafe`.
+#[derive(Debug, Clone)]
+pub struct ObjectPoolIterator<'a>
+{
+	oids: PMEMoidIterator,
+	object_pool: PhantomData<&'a ObjectPool>,
+}
+
+impl<'a> Iterator for ObjectPoolIterator<'a>
+{
+	type Item = (TypeNumber, PMEMoid, size_t);
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		self.oids.next().map(|oid| (oid.type_number(), oid, oid.allocated_useful_size()))
+	}
+}
+
+impl<'a> ObjectPoolIterator<'a>
+{
+	#[inline(always)]
+	fn new(object_pool: &'a ObjectPool) -> Self
+	{
+		Self
+		{
+			oids: PMEMoidIterator::from(object_pool.first()),
+			object_pool: PhantomData,
+		}
+	}
+}
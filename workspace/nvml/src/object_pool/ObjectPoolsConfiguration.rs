@@ -41,20 +41,23 @@ impl ObjectPoolsConfiguration
 {
 	/// Opens a set of object pools.
 	/// Do not use this method directly unless only using object pools.
-	pub fn open(&self, pools_folder_path: &Path) -> HashMap<String, ObjectPool>
+	pub fn open(&self, pools_folder_path: &Path) -> Result<HashMap<String, ObjectPool>, PoolsOpenError>
 	{
 		let object_pool_sets_folder_path = pools_folder_path.join(&self.pools_sets_folder_name);
 		
 		if unlikely(!object_pool_sets_folder_path.exists())
 		{
-			return HashMap::new()
+			return Ok(HashMap::new())
 		}
 		
-		assert!(object_pool_sets_folder_path.is_dir(), "object_pool_sets_folder_path '{:?}' is not a folder", object_pool_sets_folder_path);
+		if unlikely(!object_pool_sets_folder_path.is_dir())
+		{
+			return Err(PoolsOpenError::pool_sets_folder(PoolKind::Object, &object_pool_sets_folder_path))
+		}
 		
 		ObjectPool::set_prefault_at_create(self.prefault_object_pool_memory_to_improve_performance_at_create);
 		ObjectPool::set_prefault_at_open(self.prefault_object_pool_memory_to_improve_performance_at_open);
 		
-		self.object_pool_configurations.iter().map(|(file_name, object_pool_configuration)| (file_name.to_string(), object_pool_configuration.open_or_create(&object_pool_sets_folder_path, file_name)) ).collect()
+		self.object_pool_configurations.iter().map(|(file_name, object_pool_configuration)| object_pool_configuration.open_or_create(&object_pool_sets_folder_path, file_name).map(|object_pool| (file_name.to_string(), object_pool))).collect()
 	}
 }
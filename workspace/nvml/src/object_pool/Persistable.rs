@@ -4,6 +4,7 @@
 
 /// A Persistable is the essential trait that a struct must implement in order to be persistent.
 /// Persistable MUST NOT implement Drop, Copy or Clone
+/// Prefer `#[derive(Persistable)]` from the `nvml-derive` crate to implementing this by hand.
 pub trait Persistable: Sized
 {
 	/// Each implementation must have an unique value of this, ideally starting at one (one-based)
@@ -14,9 +15,8 @@ pub trait Persistable: Sized
 	
 	/// # Arguments
 	/// - pointer_to_uninitialized_memory_to_use_for_fields is always non-null
-	/// - object_pool is always non-null
 	#[inline(always)]
-	unsafe fn initialize(pointer_to_uninitialized_memory_to_use_for_fields: *mut Self, object_pool: *mut PMEMobjpool, arguments: &mut Self::Arguments);
+	unsafe fn initialize(pointer_to_uninitialized_memory_to_use_for_fields: *mut Self, object_pool: &ObjectPool, arguments: &mut Self::Arguments);
 	
 	/// Size in bytes that pointer_to_uninitialized_memory_to_use_for_fields in initialize() points to.
 	/// ie the size of this 'struct'.
@@ -55,10 +55,9 @@ impl Persistable for root
 	
 	#[allow(unused_variables)]
 	#[inline(always)]
-	unsafe fn initialize(pointer_to_uninitialized_memory_to_use_for_fields: *mut Self, object_pool: *mut PMEMobjpool, arguments: &mut Self::Arguments)
+	unsafe fn initialize(pointer_to_uninitialized_memory_to_use_for_fields: *mut Self, object_pool: &ObjectPool, arguments: &mut Self::Arguments)
 	{
 		debug_assert!(pointer_to_uninitialized_memory_to_use_for_fields.is_not_null(), "pointer_to_uninitialized_memory_to_use_for_fields is null");
-		debug_assert!(object_pool.is_not_null(), "object_pool is null");
 		
 		let instance = &mut *pointer_to_uninitialized_memory_to_use_for_fields;
 		instance.node.allocate_uninitialized_and_construct_object(object_pool, &mut ()).expect("Allocation failed for node");
@@ -85,10 +84,9 @@ impl Persistable for node
 	
 	#[allow(unused_variables)]
 	#[inline(always)]
-	unsafe fn initialize(pointer_to_uninitialized_memory_to_use_for_fields: *mut Self, object_pool: *mut PMEMobjpool, arguments: &mut Self::Arguments)
+	unsafe fn initialize(pointer_to_uninitialized_memory_to_use_for_fields: *mut Self, object_pool: &ObjectPool, arguments: &mut Self::Arguments)
 	{
 		debug_assert!(pointer_to_uninitialized_memory_to_use_for_fields.is_not_null(), "pointer_to_uninitialized_memory_to_use_for_fields is null");
-		debug_assert!(object_pool.is_not_null(), "object_pool is null");
 		
 		let instance = &mut *pointer_to_uninitialized_memory_to_use_for_fields;
 		
@@ -158,11 +156,11 @@ impl Persistable for foo
 	
 	type Arguments = (u8);
 	
+	#[allow(unused_variables)]
 	#[inline(always)]
-	unsafe fn initialize(pointer_to_uninitialized_memory_to_use_for_fields: *mut Self, object_pool: *mut PMEMobjpool, arguments: &mut Self::Arguments)
+	unsafe fn initialize(pointer_to_uninitialized_memory_to_use_for_fields: *mut Self, object_pool: &ObjectPool, arguments: &mut Self::Arguments)
 	{
 		debug_assert!(pointer_to_uninitialized_memory_to_use_for_fields.is_not_null(), "pointer_to_uninitialized_memory_to_use_for_fields is null");
-		debug_assert!(object_pool.is_not_null(), "object_pool is null");
 		
 		let instance = &mut *pointer_to_uninitialized_memory_to_use_for_fields;
 		instance.address = *arguments;
@@ -0,0 +1,55 @@
+// This file is part of dpdk. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/dpdk/master/COPYRIGHT. No part of dpdk, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of dpdk. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/dpdk/master/COPYRIGHT.
+
+
+/// Records the `TypeNumber` of every `Persistable` used with an object pool.
+/// Register every type at startup to find duplicate type numbers (eg from colliding hashes made by `#[derive(Persistable)]`) before they corrupt a pool.
+#[derive(Debug, Default, Clone)]
+pub struct PersistableRegistry
+{
+	type_names: HashMap<TypeNumber, &'static str>,
+}
+
+impl PersistableRegistry
+{
+	/// Creates a new, empty registry.
+	#[inline(always)]
+	pub fn new() -> Self
+	{
+		Self::default()
+	}
+	
+	/// Registers `T`.
+	/// Fails if a different type has already been registered with the same `TypeNumber`; registering `T` more than once is permitted.
+	#[inline(always)]
+	pub fn register<T: Persistable>(&mut self) -> Result<(), DuplicateTypeNumberError>
+	{
+		let type_name = unsafe { type_name::<T>() };
+		
+		let registered_type_name = *self.type_names.entry(T::TypeNumber).or_insert(type_name);
+		if likely(registered_type_name == type_name)
+		{
+			Ok(())
+		}
+		else
+		{
+			Err
+			(
+				DuplicateTypeNumberError
+				{
+					type_number: T::TypeNumber,
+					registered_type_name,
+					duplicate_type_name: type_name,
+				}
+			)
+		}
+	}
+	
+	/// The name of the type registered with `type_number`, if any.
+	/// Useful for describing the results of `ObjectPool::iter_all()` and `ObjectPool::count_by_type()`.
+	#[inline(always)]
+	pub fn type_name(&self, type_number: TypeNumber) -> Option<&'static str>
+	{
+		self.type_names.get(&type_number).map(|type_name| *type_name)
+	}
+}
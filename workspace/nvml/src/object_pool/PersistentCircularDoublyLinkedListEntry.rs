@@ -13,11 +13,11 @@ pub struct PersistentCircularDoublyLinkedListEntry<T: ListEntryPersistable>
 
 impl<T: ListEntryPersistable> Initializable for PersistentCircularDoublyLinkedListEntry<T>
 {
+	#[allow(unused_variables)]
 	#[inline(always)]
-	unsafe fn initialize(pointer_to_uninitialized_memory_to_use_for_fields: *mut Self, object_pool: *mut PMEMobjpool)
+	unsafe fn initialize(pointer_to_uninitialized_memory_to_use_for_fields: *mut Self, object_pool: &ObjectPool)
 	{
 		debug_assert!(pointer_to_uninitialized_memory_to_use_for_fields.is_not_null(), "pointer_to_uninitialized_memory_to_use_for_fields is null");
-		debug_assert!(object_pool.is_not_null(), "object_pool is null");
 		
 		let instance = &mut *pointer_to_uninitialized_memory_to_use_for_fields;
 		instance.pe_next = PersistentObject::null();
@@ -73,10 +73,9 @@ impl Persistable for fooListEntry
 	type Arguments = (u32);
 	
 	#[inline(always)]
-	unsafe fn initialize(pointer_to_uninitialized_memory_to_use_for_fields: *mut Self, object_pool: *mut PMEMobjpool, arguments: &mut Self::Arguments)
+	unsafe fn initialize(pointer_to_uninitialized_memory_to_use_for_fields: *mut Self, object_pool: &ObjectPool, arguments: &mut Self::Arguments)
 	{
 		debug_assert!(pointer_to_uninitialized_memory_to_use_for_fields.is_not_null(), "pointer_to_uninitialized_memory_to_use_for_fields is null");
-		debug_assert!(object_pool.is_not_null(), "object_pool is null");
 		
 		let instance = &mut *pointer_to_uninitialized_memory_to_use_for_fields;
 		PersistentCircularDoublyLinkedListEntry::initialize(&mut instance.list_entry_field, object_pool);
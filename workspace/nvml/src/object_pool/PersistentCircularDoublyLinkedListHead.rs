@@ -15,10 +15,9 @@ pub struct PersistentCircularDoublyLinkedListHead<T: ListEntryPersistable>
 impl<T: ListEntryPersistable> Initializable for PersistentCircularDoublyLinkedListHead<T>
 {
 	#[inline(always)]
-	unsafe fn initialize(pointer_to_uninitialized_memory_to_use_for_fields: *mut Self, object_pool: *mut PMEMobjpool)
+	unsafe fn initialize(pointer_to_uninitialized_memory_to_use_for_fields: *mut Self, object_pool: &ObjectPool)
 	{
 		debug_assert!(pointer_to_uninitialized_memory_to_use_for_fields.is_not_null(), "pointer_to_uninitialized_memory_to_use_for_fields is null");
-		debug_assert!(object_pool.is_not_null(), "object_pool is null");
 		
 		let instance = &mut *pointer_to_uninitialized_memory_to_use_for_fields;
 		instance.pe_first = PersistentObject::null();
@@ -118,7 +117,10 @@ impl<T: ListEntryPersistable> PersistentCircularDoublyLinkedListHead<T>
 				debug_assert!(ptr.is_not_null(), "ptr is null");
 				debug_assert!(arg.is_not_null(), "arg is null");
 				
-				T::initialize(ptr as *mut T, object_pool, &mut *(arg as *mut T::Arguments))
+				let &mut (object_pool_handle, ref mut arguments) = &mut *(arg as *mut (&ObjectPool, &mut T::Arguments));
+				debug_assert!(object_pool_handle.0 == object_pool, "object_pool is not the one allocated from");
+				
+				T::initialize(ptr as *mut T, object_pool_handle, arguments)
 			}));
 			
 			match result
@@ -133,7 +135,8 @@ impl<T: ListEntryPersistable> PersistentCircularDoublyLinkedListHead<T>
 			}
 		}
 		
-		let result = unsafe { pmemobj_list_insert_new(object_pool.0, T::PersistentCircularDoublyLinkedListEntryFieldOffset, self as *mut _ as *mut c_void, index.oid, direction_towards, T::size(), T::TypeNumber, Some(constructor::<T>), arguments as *mut _ as * mut _) };
+		let mut constructor_arguments = (object_pool, arguments);
+		let result = unsafe { pmemobj_list_insert_new(object_pool.0, T::PersistentCircularDoublyLinkedListEntryFieldOffset, self as *mut _ as *mut c_void, index.oid, direction_towards, T::size(), T::TypeNumber, Some(constructor::<T>), &mut constructor_arguments as *mut _ as *mut _) };
 		
 		if unlikely(result.is_null())
 		{
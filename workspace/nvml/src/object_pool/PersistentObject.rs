@@ -54,6 +54,16 @@ impl<T: Persistable> Hash for PersistentObject<T>
 	}
 }
 
+/// A null persistent object.
+impl<T: Persistable> Default for PersistentObject<T>
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self::null()
+	}
+}
+
 impl<T: Persistable> Debug for PersistentObject<T>
 {
 	#[inline(always)]
@@ -233,11 +243,12 @@ where T: ReadWriteLockablePersistable
 		self.read_write_lock().timed_read(absolute_time_out)
 	}
 	
-	/// Obtain a write lock within a transaction.
+	/// Obtain a write lock within a transaction; it is released when the transaction ends.
+	/// If returns Err then the transaction will have been aborted; return immediately from work() function.
 	#[inline(always)]
-	pub fn write_in_transaction<'a>(&'a mut self, transaction: Transaction)
+	pub fn write_in_transaction(&mut self, transaction: &TransactionScope) -> Result<(), PmdkError>
 	{
-		self.read_write_lock().write_in_transaction(transaction)
+		transaction.write_lock(self.deref_mut().read_write_lock())
 	}
 	
 	/// Obtain a write lock.
@@ -273,11 +284,12 @@ where T: ReadWriteLockablePersistable
 impl<T: Persistable> PersistentObject<T>
 where T: MutexLockablePersistable
 {
-	/// Obtain a mutex lock within a transaction.
+	/// Obtain a mutex lock within a transaction; it is released when the transaction ends.
+	/// If returns Err then the transaction will have been aborted; return immediately from work() function.
 	#[inline(always)]
-	pub fn mutex_in_transaction<'a>(&'a mut self, transaction: Transaction)
+	pub fn mutex_in_transaction(&mut self, transaction: &TransactionScope) -> Result<(), PmdkError>
 	{
-		self.mutex_lock().mutex_in_transaction(transaction)
+		transaction.lock_mutex(self.deref_mut().mutex_lock())
 	}
 	
 	/// Obtain a mutex lock.
@@ -383,7 +395,7 @@ impl<T: Persistable> PersistentObject<T>
 	/// Allocate and construct the root persistent object.
 	/// At this point, self.oid can be garbage; it might also point to an existing object which hasn't been free'd
 	#[inline(always)]
-	pub fn allocate_uninitialized_and_construct_object_root_object(&mut self, object_pool: *mut PMEMobjpool, arguments: &mut T::Arguments) -> Result<(), PmdkError>
+	pub fn allocate_uninitialized_and_construct_object_root_object(&mut self, object_pool: &ObjectPool, arguments: &mut T::Arguments) -> Result<(), PmdkError>
 	{
 		debug_assert!(T::TypeNumber == 0, "This is not a root type, ie type number is '{}'");
 		
@@ -410,7 +422,7 @@ impl<T: Persistable> PersistentObject<T>
 	/// Allocate and construct a persistent object.
 	/// At this point, self.oid can be garbage; it might also point to an existing object which hasn't been free'd
 	#[inline(always)]
-	pub fn allocate_uninitialized_and_construct_object(&mut self, object_pool: *mut PMEMobjpool, arguments: &mut T::Arguments) -> Result<(), PmdkError>
+	pub fn allocate_uninitialized_and_construct_object(&mut self, object_pool: &ObjectPool, arguments: &mut T::Arguments) -> Result<(), PmdkError>
 	{
 		#[inline(always)]
 		fn allocate<T: Persistable>(object_pool: *mut PMEMobjpool, oid_pointer: &mut PMEMoid, constructor: pmemobj_constr, arguments: *mut c_void) -> bool
@@ -426,9 +438,9 @@ impl<T: Persistable> PersistentObject<T>
 	}
 	
 	#[inline(always)]
-	fn allocate_uninitialized_and_construct_object_internal<A: FnOnce(*mut PMEMobjpool, &mut PMEMoid, pmemobj_constr, *mut c_void) -> bool>(object_pool: *mut PMEMobjpool, oid: &mut PMEMoid, allocate: A, arguments: &mut T::Arguments) -> Result<(), PmdkError>
+	fn allocate_uninitialized_and_construct_object_internal<A: FnOnce(*mut PMEMobjpool, &mut PMEMoid, pmemobj_constr, *mut c_void) -> bool>(object_pool: &ObjectPool, oid: &mut PMEMoid, allocate: A, arguments: &mut T::Arguments) -> Result<(), PmdkError>
 	{
-		debug_assert!(object_pool.is_not_null(), "object_pool is null");
+		debug_assert!(object_pool.0.is_not_null(), "object_pool is null");
 		
 		#[thread_local] static mut CapturedPanic: Option<Box<Any + Send + 'static>> = None;
 		
@@ -440,7 +452,10 @@ impl<T: Persistable> PersistentObject<T>
 				debug_assert!(ptr.is_not_null(), "ptr is null");
 				debug_assert!(arg.is_not_null(), "arg is null");
 				
-				T::initialize(ptr as *mut T, object_pool, &mut *(arg as *mut T::Arguments))
+				let &mut (object_pool_handle, ref mut arguments) = &mut *(arg as *mut (&ObjectPool, &mut T::Arguments));
+				debug_assert!(object_pool_handle.0 == object_pool, "object_pool is not the one allocated from");
+				
+				T::initialize(ptr as *mut T, object_pool_handle, arguments)
 			}));
 			
 			match result
@@ -455,7 +470,9 @@ impl<T: Persistable> PersistentObject<T>
 			}
 		}
 		
-		if unlikely(allocate(object_pool, oid, Some(constructor::<T>), arguments as *mut _ as *mut _))
+		// The constructor is passed both the pool handle and the arguments so that `Persistable::initialize()` does not need a raw pool pointer.
+		let mut constructor_arguments = (object_pool, arguments);
+		if unlikely(allocate(object_pool.0, oid, Some(constructor::<T>), &mut constructor_arguments as *mut _ as *mut _))
 		{
 			let os_error_number = errno().0;
 			match os_error_number
@@ -495,110 +512,76 @@ impl<T: Persistable> PersistentObject<T>
 	
 	/// If returns Err then the transaction will have been aborted; return immediately from work() function.
 	/// At this point, self.oid can be garbage; it might also point to an existing object which hasn't been free'd.
-	#[allow(unused_variables)]
 	#[inline(always)]
-	pub fn allocate_uninitialized_and_construct_object_in_transaction(&mut self, transaction: Transaction, object_pool: *mut PMEMobjpool, arguments: &mut T::Arguments) -> Result<(), PmdkError>
+	pub fn allocate_uninitialized_and_construct_object_in_transaction(&mut self, transaction: &TransactionScope, arguments: &mut T::Arguments) -> Result<(), PmdkError>
 	{
-		self.construct_in_transaction(object_pool, arguments, unsafe { pmemobj_tx_alloc(size::<T>(), T::TypeNumber) })
+		let oid = transaction.alloc(size::<T>(), T::TypeNumber)?;
+		self.construct_in_transaction(transaction.object_pool(), arguments, oid);
+		Ok(())
 	}
 	
 	/// If returns Err then the transaction will have been aborted; return immediately from work() function.
 	/// At this point, self.oid can be garbage; it might also point to an existing object which hasn't been free'd.
-	#[allow(unused_variables)]
 	#[inline(always)]
-	pub fn allocate_uninitialized_and_construct_object_in_transaction_without_flush(&mut self, transaction: Transaction, object_pool: *mut PMEMobjpool, arguments: &mut T::Arguments) -> Result<(), PmdkError>
+	pub fn allocate_uninitialized_and_construct_object_in_transaction_without_flush(&mut self, transaction: &TransactionScope, arguments: &mut T::Arguments) -> Result<(), PmdkError>
 	{
-		self.construct_in_transaction(object_pool, arguments, unsafe { pmemobj_tx_xalloc(size::<T>(), T::TypeNumber, POBJ_XALLOC_NO_FLUSH) })
+		let oid = transaction.alloc_without_flush(size::<T>(), T::TypeNumber)?;
+		self.construct_in_transaction(transaction.object_pool(), arguments, oid);
+		Ok(())
 	}
 	
 	/// If returns Err then the transaction will have been aborted; return immediately from work() function.
-	#[allow(unused_variables)]
 	#[inline(always)]
-	pub fn free_in_transaction(&mut self, transaction: Transaction) -> Result<(), PmdkError>
+	pub fn free_in_transaction(&mut self, transaction: &TransactionScope) -> Result<(), PmdkError>
 	{
-		Self::failure_in_transaction(unsafe { pmemobj_tx_free(self.oid) })
+		transaction.free(self.oid)
 	}
 	
 	/// Adds range snapshot to transaction (implicitly flushes, which, if doing multiple things in a transaction, is inefficient).
 	/// If returns Err then the transaction will have been aborted; return immediately from work() function.
 	/// size can be zero.
-	#[allow(unused_variables)]
 	#[inline(always)]
-	pub fn add_range_snapshot_in_transaction(&self, transaction: Transaction, offset: u64, size: size_t) -> Result<(), PmdkError>
+	pub fn add_range_snapshot_in_transaction(&self, transaction: &TransactionScope, offset: u64, size: size_t) -> Result<(), PmdkError>
 	{
 		debug_assert!(!self.oid.is_null(), "oid is null");
 		debug_assert!(offset + size as u64 <= T::size() as u64, "offset '{}' + size '{}' is bigger than our size '{}'", offset, size, T::size());
 		debug_assert!(size <= PMEMOBJ_MAX_ALLOC_SIZE, "size '{}' exceeds PMEMOBJ_MAX_ALLOC_SIZE '{}'", size, PMEMOBJ_MAX_ALLOC_SIZE);
 		
-		if unlikely(size == 0)
-		{
-			return Ok(())
-		}
-		
-		Self::failure_in_transaction(unsafe { pmemobj_tx_add_range(self.oid, offset, size) })
+		transaction.add_range(self.oid, offset, size)
 	}
 	
 	/// Adds range snapshot to transaction without a flush.
 	/// If returns Err then the transaction will have been aborted; return immediately from work() function.
 	/// size can be zero.
-	#[allow(unused_variables)]
 	#[inline(always)]
-	pub fn add_range_snapshot_in_transaction_without_flush(&self, transaction: Transaction, offset: u64, size: size_t) -> Result<(), PmdkError>
+	pub fn add_range_snapshot_in_transaction_without_flush(&self, transaction: &TransactionScope, offset: u64, size: size_t) -> Result<(), PmdkError>
 	{
 		debug_assert!(!self.oid.is_null(), "oid is null");
 		debug_assert!(offset + size as u64 <= T::size() as u64, "offset '{}' + size '{}' is bigger than our size '{}'", offset, size, T::size());
 		debug_assert!(size <= PMEMOBJ_MAX_ALLOC_SIZE, "size '{}' exceeds PMEMOBJ_MAX_ALLOC_SIZE '{}'", size, PMEMOBJ_MAX_ALLOC_SIZE);
 		
-		if unlikely(size == 0)
-		{
-			return Ok(())
-		}
-		
-		Self::failure_in_transaction(unsafe { pmemobj_tx_xadd_range(self.oid, offset, size, POBJ_XADD_NO_FLUSH) })
+		transaction.add_range_without_flush(self.oid, offset, size)
 	}
 	
 	/// Adds self to transaction (implicitly flushes, which, if doing multiple things in a transaction, is inefficient).
 	#[inline(always)]
-	pub fn add_self_to_transaction(&self, transaction: Transaction) -> Result<(), PmdkError>
+	pub fn add_self_to_transaction(&self, transaction: &TransactionScope) -> Result<(), PmdkError>
 	{
 		self.add_range_snapshot_in_transaction(transaction, 0, T::size())
 	}
 	
 	/// Adds self to transaction without a flush.
 	#[inline(always)]
-	pub fn add_self_to_transaction_without_flush(&self, transaction: Transaction) -> Result<(), PmdkError>
+	pub fn add_self_to_transaction_without_flush(&self, transaction: &TransactionScope) -> Result<(), PmdkError>
 	{
 		self.add_range_snapshot_in_transaction_without_flush(transaction, 0, T::size())
 	}
 	
 	#[inline(always)]
-	fn construct_in_transaction(&mut self, object_pool: *mut PMEMobjpool, arguments: &mut T::Arguments, oid: PMEMoid) -> Result<(), PmdkError>
-	{
-		if unlikely(oid.is_null())
-		{
-			PmdkError::obj("pmemobj_tx_xalloc")
-		}
-		else
-		{
-			unsafe { T::initialize(oid.address() as *mut T, object_pool, arguments) };
-			self.oid = oid;
-			Ok(())
-		}
-	}
-	
-	#[inline(always)]
-	fn failure_in_transaction(result: c_int) -> Result<(), PmdkError>
+	fn construct_in_transaction(&mut self, object_pool: &ObjectPool, arguments: &mut T::Arguments, oid: PMEMoid)
 	{
-		debug_assert!(result == 0 || result == -1, "result was '{}'", result);
-		
-		if likely(result == 0)
-		{
-			Ok(())
-		}
-		else
-		{
-			PmdkError::obj("pmemobj_tx_*")
-		}
+		unsafe { T::initialize(oid.address() as *mut T, object_pool, arguments) };
+		self.oid = oid;
 	}
 	
 	#[inline(always)]
@@ -624,7 +607,7 @@ macro_rules! add_field_to_transaction
 	($self: ident, $transaction: ident, $selfType: ty, $field: ident) =>
 	{
 		{
-			$self.add_range_snapshot_in_transaction(offset_of!($selfType, $field), ::std::mem::size_of_val(&$self.$field))
+			$self.add_range_snapshot_in_transaction($transaction, offset_of!($selfType, $field), ::std::mem::size_of_val(&$self.$field))
 		}
 	}
 }
@@ -635,7 +618,7 @@ macro_rules! add_field_to_transaction_without_flush
 	($self: ident, $transaction: ident, $selfType: ty, $field: ident) =>
 	{
 		{
-			$self.add_range_snapshot_in_transaction_without_flush(offset_of!($selfType, $field), ::std::mem::size_of_val(&$self.$field))
+			$self.add_range_snapshot_in_transaction_without_flush($transaction, offset_of!($selfType, $field), ::std::mem::size_of_val(&$self.$field))
 		}
 	}
 }
@@ -646,7 +629,7 @@ macro_rules! add_contiguous_fields_to_transaction
 	($self: ident, $transaction: ident, $selfType: ty, $fromInclusiveField: ident, $toInclusiveField: ident) =>
 	{
 		{
-			$self.add_range_snapshot_in_transaction(offset_of!($selfType, $fromInclusiveField), (::std::mem::size_of_val(&$self.$toInclusiveField) + offset_of!($selfType, $toInclusiveField)) - offset_of!($selfType, $fromInclusiveField))
+			$self.add_range_snapshot_in_transaction($transaction, offset_of!($selfType, $fromInclusiveField), (::std::mem::size_of_val(&$self.$toInclusiveField) + offset_of!($selfType, $toInclusiveField)) - offset_of!($selfType, $fromInclusiveField))
 		}
 	}
 }
@@ -657,7 +640,7 @@ macro_rules! add_contiguous_fields_to_transaction_without_flush
 	($self: ident, $transaction: ident, $selfType: ty, $fromInclusiveField: ident, $toInclusiveField: ident) =>
 	{
 		{
-			$self.add_range_snapshot_in_transaction_without_flush(offset_of!($selfType, $fromInclusiveField), (::std::mem::size_of_val(&$self.$toInclusiveField) + offset_of!($selfType, $toInclusiveField)) - offset_of!($selfType, $fromInclusiveField))
+			$self.add_range_snapshot_in_transaction_without_flush($transaction, offset_of!($selfType, $fromInclusiveField), (::std::mem::size_of_val(&$self.$toInclusiveField) + offset_of!($selfType, $toInclusiveField)) - offset_of!($selfType, $fromInclusiveField))
 		}
 	}
 }
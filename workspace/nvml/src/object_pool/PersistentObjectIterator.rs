@@ -0,0 +1,45 @@
+// This file is part of dpdk. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/dpdk/master/COPYRIGHT. No part of dpdk, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of dpdk. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/dpdk/master/COPYRIGHT.
+
+
+/// An iterator over every object of type `T` in an object pool, ie those with a type number of `T::TypeNumber`.
+/// Objects must not be allocated or freed in the object pool whilst iterating, which is why obtaining one is `unsafe`.
+#[derive(Debug)]
+pub struct PersistentObjectIterator<'a, T: Persistable>
+{
+	oids: PMEMoidIterator,
+	object_pool: PhantomData<&'a ObjectPool>,
+	persistable: PhantomData<T>,
+}
+
+impl<'a, T: Persistable> Iterator for PersistentObjectIterator<'a, T>
+{
+	type Item = PersistentObject<T>;
+	
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		while let Some(oid) = self.oids.next()
+		{
+			if oid.type_number() == T::TypeNumber
+			{
+				return Some(PersistentObject::new(oid))
+			}
+		}
+		None
+	}
+}
+
+impl<'a, T: Persistable> PersistentObjectIterator<'a, T>
+{
+	#[inline(always)]
+	fn new(object_pool: &'a ObjectPool) -> Self
+	{
+		Self
+		{
+			oids: PMEMoidIterator::from(object_pool.first()),
+			object_pool: PhantomData,
+			persistable: PhantomData,
+		}
+	}
+}
@@ -91,19 +91,6 @@ impl<'a, T: Persistable> ReadWriteLock<'a, T>
 		}
 	}
 	
-	/// Obtain a write lock within a transaction.
-	#[allow(unused_variables)]
-	#[inline(always)]
-	pub fn write_in_transaction(self, transaction: Transaction)
-	{
-		let result = unsafe { pmemobj_tx_lock(pobj_tx_param_TX_PARAM_RWLOCK, self.read_write_lock as *mut c_void) };
-		if likely(result == 0)
-		{
-			return;
-		}
-		Self::write_lock_error_handling(result);
-	}
-	
 	/// Obtain a write lock.
 	#[inline(always)]
 	pub fn write(self) -> WriteLockUnlock<'a, T>
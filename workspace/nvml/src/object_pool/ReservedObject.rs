@@ -0,0 +1,108 @@
+// This file is part of dpdk. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/dpdk/master/COPYRIGHT. No part of dpdk, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of dpdk. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/dpdk/master/COPYRIGHT.
+
+
+/// A persistent object reserved by `ObjectPool::reserve()` but not yet published.
+/// It can be initialized, modified and persisted like volatile memory, as nothing refers to it until it is published by `ObjectPoolActions::reservation()` and `ObjectPool::publish()`.
+/// Dropping it without publishing it cancels the reservation.
+/// Requires libpmemobj 1.4 or later.
+pub struct ReservedObject<'a, T: Persistable>
+{
+	object_pool: &'a ObjectPool,
+	action: Option<pobj_action>,
+	oid: PMEMoid,
+	phantom_data: PhantomData<T>,
+}
+
+impl<'a, T: Persistable> Drop for ReservedObject<'a, T>
+{
+	#[inline(always)]
+	fn drop(&mut self)
+	{
+		if let Some(mut action) = self.action.take()
+		{
+			unsafe { pmemobj_cancel(self.object_pool.0, &mut action, 1) }
+		}
+	}
+}
+
+impl<'a, T: Persistable> Debug for ReservedObject<'a, T>
+{
+	#[inline(always)]
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result
+	{
+		write!(f, "ReservedObject({}, OID({}, {}))", T::TypeNumber, self.oid.pool_uuid_lo, self.oid.off)
+	}
+}
+
+/// It is possible to violate aliasing rules.
+impl<'a, T: Persistable> Deref for ReservedObject<'a, T>
+{
+	type Target = T;
+	
+	#[inline(always)]
+	fn deref(&self) -> &T
+	{
+		unsafe { & * (self.oid.address() as *const T) }
+	}
+}
+
+/// It is possible to violate aliasing rules.
+impl<'a, T: Persistable> DerefMut for ReservedObject<'a, T>
+{
+	#[inline(always)]
+	fn deref_mut(&mut self) -> &mut T
+	{
+		unsafe { &mut * (self.oid.address() as *mut T) }
+	}
+}
+
+impl<'a, T: Persistable> ReservedObject<'a, T>
+{
+	/// Persist (flush and drain) the contents of this reserved object.
+	/// `ObjectPoolActions::reservation()` also does this.
+	#[inline(always)]
+	pub fn persist(&self)
+	{
+		self.object_pool.persist(self.oid.address(), T::size())
+	}
+	
+	/// The persistent object this will become once published.
+	/// Use it to link reserved objects to each other, or with `ObjectPoolActions::set_persistent_object()`; it must not be used in any other way until published.
+	#[inline(always)]
+	pub fn persistent_object(&self) -> PersistentObject<T>
+	{
+		PersistentObject::new(self.oid)
+	}
+	
+	#[inline(always)]
+	fn reserve(object_pool: &'a ObjectPool, arguments: &mut T::Arguments) -> Result<Self, PmdkError>
+	{
+		let mut action: pobj_action = unsafe { zeroed() };
+		let oid = unsafe { pmemobj_reserve(object_pool.0, &mut action, size::<T>(), T::TypeNumber) };
+		if unlikely(oid.is_null())
+		{
+			return PmdkError::obj("pmemobj_reserve")
+		}
+		
+		// Created before initialization so that a panic in `T::initialize()` cancels the reservation.
+		let reserved_object = Self
+		{
+			object_pool,
+			action: Some(action),
+			oid,
+			phantom_data: PhantomData,
+		};
+		
+		unsafe { T::initialize(oid.address() as *mut T, object_pool, arguments) };
+		
+		Ok(reserved_object)
+	}
+	
+	#[inline(always)]
+	fn into_action(mut self) -> pobj_action
+	{
+		self.persist();
+		self.action.take().unwrap()
+	}
+}
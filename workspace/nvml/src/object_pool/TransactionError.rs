@@ -0,0 +1,60 @@
+// This file is part of dpdk. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/dpdk/master/COPYRIGHT. No part of dpdk, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of dpdk. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/dpdk/master/COPYRIGHT.
+
+
+/// Represents the failure of a transaction run by `ObjectPool::transaction()`.
+/// In all cases the transaction has been aborted and any snapshotted ranges restored.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum TransactionError<WorkError>
+{
+	/// The transaction could not be begun; the work was never run.
+	Begin(PmdkError),
+	
+	/// The work returned an error.
+	Work(WorkError),
+	
+	/// The work succeeded but the transaction was aborted, either because committing failed or because a failed operation on the `TransactionScope` was ignored.
+	Aborted(PmdkError),
+}
+
+impl<WorkError: Display> Display for TransactionError<WorkError>
+{
+	#[inline(always)]
+	fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error>
+	{
+		use self::TransactionError::*;
+		
+		match *self
+		{
+			Begin(ref pmdk_error) => Display::fmt(pmdk_error, formatter),
+			
+			Work(ref work_error) => Display::fmt(work_error, formatter),
+			
+			Aborted(ref pmdk_error) => Display::fmt(pmdk_error, formatter),
+		}
+	}
+}
+
+impl<WorkError: error::Error> error::Error for TransactionError<WorkError>
+{
+	#[inline(always)]
+	fn description(&self) -> &str
+	{
+		"Transaction Error"
+	}
+	
+	#[inline(always)]
+	fn cause(&self) -> Option<&error::Error>
+	{
+		use self::TransactionError::*;
+		
+		match *self
+		{
+			Begin(ref pmdk_error) => Some(pmdk_error),
+			
+			Work(ref work_error) => Some(work_error),
+			
+			Aborted(ref pmdk_error) => Some(pmdk_error),
+		}
+	}
+}
@@ -0,0 +1,249 @@
+// This file is part of dpdk. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/dpdk/master/COPYRIGHT. No part of dpdk, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of dpdk. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/dpdk/master/COPYRIGHT.
+
+
+/// A transaction in progress, scoped to the closure passed to `ObjectPool::transaction()`.
+/// Snapshots, allocations and frees are made through a borrow of this scope, so they can not outlive the transaction.
+/// The transaction is begun without a `setjmp` jump buffer, so failures are returned as `Err` rather than by `longjmp`.
+/// If any method returns `Err` then the transaction has already been aborted; return immediately from the closure.
+/// A transaction belongs to the thread that began it, so a `TransactionScope` is neither `Send` nor `Sync`.
+#[derive(Debug)]
+pub struct TransactionScope<'a>
+{
+	object_pool: &'a ObjectPool,
+	not_send_or_sync: PhantomData<*mut ()>,
+}
+
+impl<'a> TransactionScope<'a>
+{
+	/// The object pool this transaction is in.
+	#[inline(always)]
+	pub fn object_pool(&self) -> &'a ObjectPool
+	{
+		self.object_pool
+	}
+	
+	/// Snapshots `size` bytes at `offset` in the object `oid`, so that they are restored if the transaction aborts.
+	/// Implicitly flushes, which, if doing multiple things in a transaction, is inefficient.
+	/// size can be zero.
+	#[inline(always)]
+	pub fn add_range(&self, oid: PMEMoid, offset: u64, size: size_t) -> Result<(), PmdkError>
+	{
+		debug_assert!(!oid.is_null(), "oid is null");
+		debug_assert!(size <= PMEMOBJ_MAX_ALLOC_SIZE, "size '{}' exceeds PMEMOBJ_MAX_ALLOC_SIZE '{}'", size, PMEMOBJ_MAX_ALLOC_SIZE);
+		
+		if unlikely(size == 0)
+		{
+			return Ok(())
+		}
+		
+		Self::failure(unsafe { pmemobj_tx_add_range(oid, offset, size) }, "pmemobj_tx_add_range")
+	}
+	
+	/// As for `add_range()`, but without a flush.
+	#[inline(always)]
+	pub fn add_range_without_flush(&self, oid: PMEMoid, offset: u64, size: size_t) -> Result<(), PmdkError>
+	{
+		debug_assert!(!oid.is_null(), "oid is null");
+		debug_assert!(size <= PMEMOBJ_MAX_ALLOC_SIZE, "size '{}' exceeds PMEMOBJ_MAX_ALLOC_SIZE '{}'", size, PMEMOBJ_MAX_ALLOC_SIZE);
+		
+		if unlikely(size == 0)
+		{
+			return Ok(())
+		}
+		
+		Self::failure(unsafe { pmemobj_tx_xadd_range(oid, offset, size, POBJ_XADD_NO_FLUSH) }, "pmemobj_tx_xadd_range")
+	}
+	
+	/// Snapshots `size` bytes at `address`, which must be inside an object in this pool, so that they are restored if the transaction aborts.
+	/// size can be zero.
+	#[inline(always)]
+	pub fn add_range_direct(&self, address: *const c_void, size: size_t) -> Result<(), PmdkError>
+	{
+		debug_assert!(address.is_not_null(), "address is null");
+		debug_assert!(size <= PMEMOBJ_MAX_ALLOC_SIZE, "size '{}' exceeds PMEMOBJ_MAX_ALLOC_SIZE '{}'", size, PMEMOBJ_MAX_ALLOC_SIZE);
+		
+		if unlikely(size == 0)
+		{
+			return Ok(())
+		}
+		
+		Self::failure(unsafe { pmemobj_tx_add_range_direct(address, size) }, "pmemobj_tx_add_range_direct")
+	}
+	
+	/// Allocates `size` bytes of uninitialized memory; the allocation is undone if the transaction aborts.
+	#[inline(always)]
+	pub fn alloc(&self, size: size_t, type_number: TypeNumber) -> Result<PMEMoid, PmdkError>
+	{
+		debug_assert!(size != 0, "size can not be zero");
+		debug_assert!(size <= PMEMOBJ_MAX_ALLOC_SIZE, "size '{}' exceeds PMEMOBJ_MAX_ALLOC_SIZE '{}'", size, PMEMOBJ_MAX_ALLOC_SIZE);
+		
+		Self::null_failure(unsafe { pmemobj_tx_alloc(size, type_number) }, "pmemobj_tx_alloc")
+	}
+	
+	/// As for `alloc()`, but without a flush.
+	#[inline(always)]
+	pub fn alloc_without_flush(&self, size: size_t, type_number: TypeNumber) -> Result<PMEMoid, PmdkError>
+	{
+		debug_assert!(size != 0, "size can not be zero");
+		debug_assert!(size <= PMEMOBJ_MAX_ALLOC_SIZE, "size '{}' exceeds PMEMOBJ_MAX_ALLOC_SIZE '{}'", size, PMEMOBJ_MAX_ALLOC_SIZE);
+		
+		Self::null_failure(unsafe { pmemobj_tx_xalloc(size, type_number, POBJ_XALLOC_NO_FLUSH) }, "pmemobj_tx_xalloc")
+	}
+	
+	/// Allocates `size` bytes of zeroed memory; the allocation is undone if the transaction aborts.
+	#[inline(always)]
+	pub fn zalloc(&self, size: size_t, type_number: TypeNumber) -> Result<PMEMoid, PmdkError>
+	{
+		debug_assert!(size != 0, "size can not be zero");
+		debug_assert!(size <= PMEMOBJ_MAX_ALLOC_SIZE, "size '{}' exceeds PMEMOBJ_MAX_ALLOC_SIZE '{}'", size, PMEMOBJ_MAX_ALLOC_SIZE);
+		
+		Self::null_failure(unsafe { pmemobj_tx_zalloc(size, type_number) }, "pmemobj_tx_zalloc")
+	}
+	
+	/// Frees `oid` when the transaction commits; nothing is freed if the transaction aborts.
+	/// Freeing a null `oid` does nothing.
+	#[inline(always)]
+	pub fn free(&self, oid: PMEMoid) -> Result<(), PmdkError>
+	{
+		Self::failure(unsafe { pmemobj_tx_free(oid) }, "pmemobj_tx_free")
+	}
+	
+	/// Locks `mutex`, which must be inside an object in this pool; it is unlocked when the transaction ends.
+	#[inline(always)]
+	pub fn lock_mutex(&self, mutex: &mut PMEMmutex) -> Result<(), PmdkError>
+	{
+		Self::failure(unsafe { pmemobj_tx_lock(pobj_tx_param_TX_PARAM_MUTEX, mutex as *mut PMEMmutex as *mut c_void) }, "pmemobj_tx_lock")
+	}
+	
+	/// Write locks `read_write_lock`, which must be inside an object in this pool; it is unlocked when the transaction ends.
+	#[inline(always)]
+	pub fn write_lock(&self, read_write_lock: &mut PMEMrwlock) -> Result<(), PmdkError>
+	{
+		Self::failure(unsafe { pmemobj_tx_lock(pobj_tx_param_TX_PARAM_RWLOCK, read_write_lock as *mut PMEMrwlock as *mut c_void) }, "pmemobj_tx_lock")
+	}
+	
+	/// Begins a transaction on `object_pool`, runs `work` and then commits if `work` returned `Ok` or aborts if it returned `Err` or panicked.
+	/// A panic is resumed once the transaction has been aborted.
+	#[inline(always)]
+	pub(crate) fn run<Value, WorkError, Work: FnOnce(&TransactionScope<'a>) -> Result<Value, WorkError>>(object_pool: &'a ObjectPool, work: Work) -> Result<Value, TransactionError<WorkError>>
+	{
+		use self::TransactionError::*;
+		
+		let result = unsafe { pmemobj_tx_begin(object_pool.0, null_mut(), pobj_tx_param_TX_PARAM_NONE) };
+		if unlikely(result != 0)
+		{
+			set_error_number_if_necessary(result);
+			let pmdk_error = PmdkError::new(pmemobj_errormsg, "pmemobj_tx_begin");
+			
+			// A failed begin leaves the transaction in the `TX_STAGE_ONABORT` stage, which still has to be ended.
+			Self::end();
+			return Err(Begin(pmdk_error))
+		}
+		
+		let transaction_scope = TransactionScope
+		{
+			object_pool,
+			not_send_or_sync: PhantomData,
+		};
+		
+		match catch_unwind(AssertUnwindSafe(|| work(&transaction_scope)))
+		{
+			Ok(Ok(value)) =>
+			{
+				if likely(Self::is_in_work_stage())
+				{
+					unsafe { pmemobj_tx_commit() };
+				}
+				
+				let result = Self::end();
+				if likely(result == 0)
+				{
+					Ok(value)
+				}
+				else
+				{
+					set_error_number_if_necessary(result);
+					Err(Aborted(PmdkError::new(pmemobj_errormsg, "pmemobj_tx_end")))
+				}
+			},
+			
+			Ok(Err(work_error)) =>
+			{
+				Self::abort_if_in_work_stage();
+				Self::end();
+				Err(Work(work_error))
+			},
+			
+			Err(panic_payload) =>
+			{
+				Self::abort_if_in_work_stage();
+				Self::end();
+				resume_unwind(panic_payload)
+			},
+		}
+	}
+	
+	#[inline(always)]
+	fn is_in_work_stage() -> bool
+	{
+		unsafe { pmemobj_tx_stage() == pobj_tx_stage_TX_STAGE_WORK }
+	}
+	
+	#[inline(always)]
+	fn abort_if_in_work_stage()
+	{
+		if likely(Self::is_in_work_stage())
+		{
+			unsafe { pmemobj_tx_abort(ECANCELED) };
+		}
+	}
+	
+	/// Equivalent to the stage loop and `TX_END` of the C macros; returns the transaction's error number, or zero if it committed.
+	#[inline(always)]
+	fn end() -> c_int
+	{
+		while unsafe { pmemobj_tx_stage() } != pobj_tx_stage_TX_STAGE_NONE
+		{
+			unsafe { pmemobj_tx_process() };
+		}
+		
+		unsafe { pmemobj_tx_end() }
+	}
+	
+	#[inline(always)]
+	fn failure(result: c_int, function_name: &'static str) -> Result<(), PmdkError>
+	{
+		if likely(result == 0)
+		{
+			Ok(())
+		}
+		else
+		{
+			set_error_number_if_necessary(result);
+			PmdkError::obj(function_name)
+		}
+	}
+	
+	#[inline(always)]
+	fn null_failure(oid: PMEMoid, function_name: &'static str) -> Result<PMEMoid, PmdkError>
+	{
+		if unlikely(oid.is_null())
+		{
+			PmdkError::obj(function_name)
+		}
+		else
+		{
+			Ok(oid)
+		}
+	}
+}
+
+#[inline(always)]
+fn set_error_number_if_necessary(os_error_number: c_int)
+{
+	if unlikely(os_error_number > 0)
+	{
+		set_errno(Errno(os_error_number));
+	}
+}
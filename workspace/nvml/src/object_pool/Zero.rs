@@ -7,9 +7,8 @@ pub trait Zero: Sized
 {
 	/// # Arguments
 	/// - self is always non-null
-	/// - object_pool is always non-null
 	#[inline(always)]
-	unsafe fn zero(self, object_pool: *mut PMEMobjpool);
+	unsafe fn zero(self, object_pool: &ObjectPool);
 }
 
 macro_rules! zero_guard
@@ -18,7 +17,7 @@ macro_rules! zero_guard
 	{
 		{
 			debug_assert!($self.is_not_null(), "self is null");
-			debug_assert!($objectPool.is_not_null(), "objectPool is null");
+			debug_assert!($objectPool.0.is_not_null(), "objectPool is null");
 		}
 	}
 }
@@ -26,29 +25,29 @@ macro_rules! zero_guard
 impl Zero for *mut PMEMmutex
 {
 	#[inline(always)]
-	unsafe fn zero(self, object_pool: *mut PMEMobjpool)
+	unsafe fn zero(self, object_pool: &ObjectPool)
 	{
 		zero_guard!(self, object_pool);
-		pmemobj_mutex_zero(object_pool, self)
+		pmemobj_mutex_zero(object_pool.0, self)
 	}
 }
 
 impl Zero for *mut PMEMrwlock
 {
 	#[inline(always)]
-	unsafe fn zero(self, object_pool: *mut PMEMobjpool)
+	unsafe fn zero(self, object_pool: &ObjectPool)
 	{
 		zero_guard!(self, object_pool);
-		pmemobj_rwlock_zero(object_pool, self)
+		pmemobj_rwlock_zero(object_pool.0, self)
 	}
 }
 
 impl Zero for *mut PMEMcond
 {
 	#[inline(always)]
-	unsafe fn zero(self, object_pool: *mut PMEMobjpool)
+	unsafe fn zero(self, object_pool: &ObjectPool)
 	{
 		zero_guard!(self, object_pool);
-		pmemobj_cond_zero(object_pool, self)
+		pmemobj_cond_zero(object_pool.0, self)
 	}
 }
@@ -15,6 +15,9 @@ use ::libc::mode_t;
 use ::libc::size_t;
 use ::libc::timespec;
 use ::Configuration;
+use ::PoolKind;
+use ::PoolOpenFailure::*;
+use ::PoolsOpenError;
 use ::nvml_sys::*;
 use ::nvml_sys::pobj_tx_param_TX_PARAM_NONE;
 use ::rust_extra::likely;
@@ -22,6 +25,7 @@ use ::rust_extra::unlikely;
 use ::std::any::Any;
 use ::std::cmp::Ordering;
 use ::std::collections::HashMap;
+use ::std::error;
 use ::std::fmt;
 use ::std::fmt::Debug;
 use ::std::fmt::Display;
@@ -29,6 +33,7 @@ use ::std::fmt::Formatter;
 use ::std::ffi::CString;
 use ::std::hash::Hash;
 use ::std::hash::Hasher;
+use ::std::intrinsics::type_name;
 use ::std::marker::PhantomData;
 use ::std::mem::replace;
 use ::std::mem::size_of;
@@ -58,6 +63,7 @@ use ::syscall_alt::constants::E::ETIMEDOUT;
 
 include!("ConditionVariable.rs");
 include!("ConditionVariableMutexLockablePersistable.rs");
+include!("DuplicateTypeNumberError.rs");
 include!("initialise_memory_functions.rs");
 include!("Initializable.rs");
 include!("ListEntryPersistable.rs");
@@ -65,15 +71,19 @@ include!("MutexLock.rs");
 include!("MutexLockablePersistable.rs");
 include!("MutexUnlock.rs");
 include!("ObjectPool.rs");
+include!("ObjectPoolActions.rs");
 include!("ObjectPoolConfiguration.rs");
 include!("ObjectPoolControl.rs");
 include!("ObjectPoolDropWrapper.rs");
+include!("ObjectPoolIterator.rs");
 include!("ObjectPoolPersistOnDrop.rs");
 include!("ObjectPoolPathExt.rs");
 include!("ObjectPoolsConfiguration.rs");
 include!("OID.rs");
 include!("PersistentObject.rs");
+include!("PersistentObjectIterator.rs");
 include!("Persistable.rs");
+include!("PersistableRegistry.rs");
 include!("PersistentCircularDoublyLinkedListEntry.rs");
 include!("PersistentCircularDoublyLinkedListHead.rs");
 include!("PMEMobjpoolExt.rs");
@@ -81,7 +91,9 @@ include!("PMEMoidIterator.rs");
 include!("ReadLockUnlock.rs");
 include!("ReadWriteLock.rs");
 include!("ReadWriteLockablePersistable.rs");
+include!("ReservedObject.rs");
 include!("TypeNumber.rs");
-include!("Transaction.rs");
+include!("TransactionError.rs");
+include!("TransactionScope.rs");
 include!("WriteLockUnlock.rs");
 include!("Zero.rs");